@@ -7,9 +7,19 @@ use crate::components::{command, Refreshable};
 use crate::config::{Config, KeyConfig, MouseConfig};
 use crate::components::{
     cpu::CPUComponent,
+    temp::TempComponent,
     memory::MemoryComponent,
     network::NetworkComponent,
+    disk_io::DiskIoComponent,
     process::ProcessComponent,
+    units::UnitComponent,
+    limits::LimitComponent,
+    connections::ConnectionComponent,
+    disks::DiskComponent,
+    summary::SummaryComponent,
+    pressure::PressureComponent,
+    process_detail::ProcessDetailComponent,
+    column_chooser::ColumnChooserComponent,
     error::ErrorComponent,
     EventState,
     Component,
@@ -22,22 +32,39 @@ use crate::services::sysinfo_service::SysInfoService;
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum MainFocus {
     CPU,
+    Pressure,
+    Temp,
     Process,
     Memory,
     Network,
+    DiskIo,
+    Units,
+    Limits,
+    Connections,
+    Disks,
 }
 
 pub struct App {
     focus: MainFocus,
     focus_rects: HashMap<MainFocus, Rect>,
+    table_slot: MainFocus,                      // table panel (Process, Units, Limits, Connections or Disks) shown in the bottom of the layout, the most recently focused one
     expand: bool,
     service: SysInfoService,
     process: ProcessComponent,
     cpu: CPUComponent,
+    pressure: PressureComponent,
     memory: MemoryComponent,
     network: NetworkComponent,
-    //temp: TempComponent,
+    disk_io: DiskIoComponent,
+    units: UnitComponent,
+    limits: LimitComponent,
+    connections: ConnectionComponent,
+    disks: DiskComponent,
+    temp: TempComponent,
+    summary: SummaryComponent,
     help: HelpComponent,
+    column_chooser: ColumnChooserComponent,
+    process_detail: ProcessDetailComponent,
     pub error: ErrorComponent,
     pub config: Config,
 }
@@ -50,8 +77,15 @@ impl App {
         let process = ProcessComponent::new(config.clone(), &service);
         let memory = MemoryComponent::new(config.clone(), &service);
         let cpu = CPUComponent::new(config.clone(), &service);
+        let pressure = PressureComponent::new(config.clone(), &service);
         let network = NetworkComponent::new(config.clone(), &service);
-        //let temp = TempComponent::new(config.clone(), &service);
+        let disk_io = DiskIoComponent::new(config.clone(), &service);
+        let units = UnitComponent::new(config.clone(), &service);
+        let limits = LimitComponent::new(config.clone(), &service);
+        let connections = ConnectionComponent::new(config.clone(), &service);
+        let disks = DiskComponent::new(config.clone(), &service);
+        let temp = TempComponent::new(config.clone(), &service);
+        let summary = SummaryComponent::new(config.clone(), &service);
 
         let help_config = config.clone();
         let mut help = HelpComponent::new(help_config.clone());
@@ -63,14 +97,24 @@ impl App {
         Self {
             focus,
             focus_rects,
+            table_slot: focus,
             expand: false,
             service,
             process,
             cpu,
+            pressure,
             memory,
             network,
-            //temp,
+            disk_io,
+            units,
+            limits,
+            connections,
+            disks,
+            temp,
+            summary,
             help,
+            column_chooser: ColumnChooserComponent::new(config.clone()),
+            process_detail: ProcessDetailComponent::new(config.clone()),
             error: ErrorComponent::new(config.clone()),
             config: config.clone(),
         }
@@ -82,7 +126,21 @@ impl App {
         self.process.refresh(&self.service);
         self.memory.refresh(&self.service);
         self.cpu.update(&self.service);
+        self.pressure.refresh(&self.service);
+        self.temp.refresh(&self.service);
         self.network.refresh(&self.service);
+        self.disk_io.refresh(&self.service);
+        self.units.refresh(&self.service);
+        self.limits.refresh(&self.service);
+        self.connections.refresh(&self.service);
+        self.disks.refresh(&self.service);
+        self.summary.refresh(&self.service);
+
+        if self.process_detail.is_visible() {
+            let process = self.process_detail.pid().and_then(|pid| self.process.item(pid)).cloned();
+            self.process_detail.set_process(process);
+            self.process_detail.refresh(&self.service);
+        }
 
         Ok(EventState::Consumed)
     }
@@ -97,6 +155,15 @@ impl App {
             return Ok(EventState::Consumed)
         }
 
+        if self.column_chooser.is_visible() {
+            return self.column_chooser_key_event(key);
+        }
+
+        if self.process_detail.is_visible() {
+            let _ = self.process_detail.key_event(key)?.is_consumed();
+            return Ok(EventState::Consumed)
+        }
+
         if self.key_component_event(key)?.is_consumed() {
             return Ok(EventState::Consumed);
         }
@@ -107,10 +174,53 @@ impl App {
             self.toggle_expand();
             return Ok(EventState::Consumed);
         }
+        // the units are persisted like the columns
+        if key == self.config.key_config.unit_system_toggle {
+            self.config.set_unit_system(self.config.unit_system().next());
+            self.set_units();
+            self.config.save()?;
+            return Ok(EventState::Consumed);
+        }
+        if key == self.config.key_config.network_unit_toggle {
+            self.config.set_network_unit(self.config.network_unit().next());
+            self.set_units();
+            self.config.save()?;
+            return Ok(EventState::Consumed);
+        }
 
         Ok(EventState::NotConsumed)
     }
 
+    // every component formats with its own copy of the config
+    fn set_units(&mut self) {
+        let unit_system = self.config.unit_system();
+
+        self.process.set_unit_system(unit_system);
+        self.cpu.set_unit_system(unit_system);
+        self.memory.set_unit_system(unit_system);
+        self.network.set_unit_system(unit_system);
+        self.network.set_network_unit(self.config.network_unit());
+        self.units.set_unit_system(unit_system);
+        self.limits.set_unit_system(unit_system);
+        self.disks.set_unit_system(unit_system);
+        self.disk_io.set_unit_system(unit_system);
+        self.pressure.set_unit_system(unit_system);
+        self.process_detail.set_unit_system(unit_system);
+    }
+
+    // column changes are applied to the process list immediately and persisted once the chooser is closed
+    fn column_chooser_key_event(&mut self, key: Key) -> Result<EventState> {
+        let _ = self.column_chooser.key_event(key)?.is_consumed();
+        self.process.set_columns(self.column_chooser.columns().to_vec());
+
+        if !self.column_chooser.is_visible() {
+            self.config.process_columns = self.column_chooser.columns().to_vec();
+            self.config.save()?;
+        }
+
+        Ok(EventState::Consumed)
+    }
+
     fn key_component_event(&mut self, key: Key) -> Result<EventState> {
         if self.error.key_event(key)?.is_consumed() {
             return Ok(EventState::Consumed)
@@ -125,6 +235,16 @@ impl App {
                     return Ok(EventState::Consumed)
                 }
             }
+            MainFocus::Pressure => {
+                if self.pressure.key_event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Temp => {
+                if self.temp.key_event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
             MainFocus::Memory => {
                 if self.memory.key_event(key)?.is_consumed() {
                     return Ok(EventState::Consumed)
@@ -135,10 +255,56 @@ impl App {
                     return Ok(EventState::Consumed)
                 }
             }
+            MainFocus::DiskIo => {
+                if self.disk_io.key_event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Units => {
+                if self.units.key_event(key)?.is_consumed() {
+                    self.handle_drill_in();
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Limits => {
+                if self.limits.key_event(key)?.is_consumed() {
+                    self.handle_drill_in();
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Connections => {
+                if self.connections.key_event(key)?.is_consumed() {
+                    self.handle_jump()?;
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Disks => {
+                if self.disks.key_event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
             MainFocus::Process => {
                 if self.process.key_event(key)?.is_consumed() {
                     return Ok(EventState::Consumed)
                 }
+                if key == self.config.key_config.column_chooser {
+                    self.column_chooser.show(self.process.columns().to_vec());
+                    return Ok(EventState::Consumed)
+                }
+                // the cpu mode is persisted like the columns
+                if key == self.config.key_config.cpu_mode_toggle {
+                    self.config.set_cpu_mode(self.config.cpu_mode().next());
+                    self.process.set_cpu_mode(self.config.cpu_mode());
+                    self.units.set_cpu_mode(self.config.cpu_mode());
+                    self.config.save()?;
+                    return Ok(EventState::Consumed)
+                }
+                if key == self.config.key_config.enter {
+                    if let Some(item) = self.process.selected_item() {
+                        self.process_detail.show(item.clone(), &self.service);
+                        return Ok(EventState::Consumed)
+                    }
+                }
                 // terminate case
                 if key == self.config.key_config.terminate {
                     
@@ -150,20 +316,80 @@ impl App {
         Ok(EventState::NotConsumed)
     }
 
+    // scopes the process list to the unit or cgroup drilled into and moves focus to it
+    fn handle_drill_in(&mut self) {
+        let unit = if let Some(unit) = self.units.take_drill_in() {
+            Some((unit.name().to_string(), unit.cgroup().to_string()))
+        }
+        else {
+            self.limits.take_drill_in().map(|limit| (limit.name().to_string(), limit.cgroup().to_string()))
+        };
+
+        if unit.is_some() {
+            self.process.set_unit(unit);
+            self.set_focus(MainFocus::Process);
+        }
+    }
+
+    // selects the process owning the connection jumped from and moves focus to it
+    fn handle_jump(&mut self) -> Result<()> {
+        let Some(pid) = self.connections.take_jump()
+        else {
+            return Ok(())
+        };
+
+        if self.process.select_pid(pid) {
+            self.set_focus(MainFocus::Process);
+        }
+        else {
+            self.error.set(format!("Process {} is not in the process list, it may have exited", pid))?;
+        }
+
+        Ok(())
+    }
+
+    fn set_focus(&mut self, focus: MainFocus) {
+        self.focus = focus;
+        if matches!(focus, MainFocus::Process | MainFocus::Units | MainFocus::Limits | MainFocus::Connections | MainFocus::Disks) {
+            self.table_slot = focus;
+        }
+    }
+
     fn move_focus_key(&mut self, key: Key) -> Result<EventState> {
         if key == self.config.key_config.tab {
             match self.focus {
                 MainFocus::CPU => {
-                    self.focus = MainFocus::Memory
+                    self.set_focus(MainFocus::Pressure)
+                }
+                MainFocus::Pressure => {
+                    self.set_focus(MainFocus::Temp)
+                }
+                MainFocus::Temp => {
+                    self.set_focus(MainFocus::Memory)
                 }
                 MainFocus::Memory => {
-                    self.focus = MainFocus::Network
+                    self.set_focus(MainFocus::Network)
                 }
                 MainFocus::Network => {
-                    self.focus = MainFocus::Process
+                    self.set_focus(MainFocus::DiskIo)
+                }
+                MainFocus::DiskIo => {
+                    self.set_focus(MainFocus::Process)
                 }
                 MainFocus::Process => {
-                    self.focus = MainFocus::CPU
+                    self.set_focus(MainFocus::Units)
+                }
+                MainFocus::Units => {
+                    self.set_focus(MainFocus::Limits)
+                }
+                MainFocus::Limits => {
+                    self.set_focus(MainFocus::Connections)
+                }
+                MainFocus::Connections => {
+                    self.set_focus(MainFocus::Disks)
+                }
+                MainFocus::Disks => {
+                    self.set_focus(MainFocus::CPU)
                 }
             }
             return Ok(EventState::Consumed)
@@ -183,6 +409,14 @@ impl App {
             let _ = self.help.mouse_event(mouse)?.is_consumed();
             return Ok(EventState::Consumed)
         }
+        if self.column_chooser.is_visible() {
+            let _ = self.column_chooser.mouse_event(mouse)?.is_consumed();
+            return Ok(EventState::Consumed)
+        }
+        if self.process_detail.is_visible() {
+            let _ = self.process_detail.mouse_event(mouse)?.is_consumed();
+            return Ok(EventState::Consumed)
+        }
 
         let move_focus_res = self.move_focus_mouse(mouse)?.is_consumed();
 
@@ -197,6 +431,16 @@ impl App {
                     return Ok(EventState::Consumed)
                 }
             }
+            MainFocus::Pressure => {
+                if self.pressure.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Temp => {
+                if self.temp.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
             MainFocus::Memory => {
                 if self.memory.mouse_event(mouse)?.is_consumed() {
                     return Ok(EventState::Consumed)
@@ -206,7 +450,32 @@ impl App {
                 if self.network.mouse_event(mouse)?.is_consumed() {
                     return Ok(EventState::Consumed)
                 }
-           }
+            }
+            MainFocus::DiskIo => {
+                if self.disk_io.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Units => {
+                if self.units.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Limits => {
+                if self.limits.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Connections => {
+                if self.connections.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            MainFocus::Disks => {
+                if self.disks.mouse_event(mouse)?.is_consumed() {
+                    return Ok(EventState::Consumed)
+                }
+            }
         }
 
         if move_focus_res {
@@ -221,11 +490,14 @@ impl App {
             let col = mouse.column;
             let row = mouse.row;
 
-            for (focus, rect) in &self.focus_rects {
-                if rect.contains(col, row) {
-                    self.focus = *focus;
-                    return Ok(EventState::Consumed)
-                }
+            let focus = self.focus_rects
+                .iter()
+                .find(|(_focus, rect)| rect.contains(col, row))
+                .map(|(focus, _rect)| *focus);
+
+            if let Some(focus) = focus {
+                self.set_focus(focus);
+                return Ok(EventState::Consumed)
             }
         }
         
@@ -238,14 +510,17 @@ impl App {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(2),                  // summary
                 Constraint::Min(1),
             ])
             .split(f.size());
 
-        self.error.draw(f, chunks[0], false)?;
+        self.summary.draw(f, chunks[0], false)?;
+
+        self.error.draw(f, chunks[1], false)?;
         
         if self.help.is_visible() {
-            self.help.draw(f, chunks[0], false)?;
+            self.help.draw(f, chunks[1], false)?;
             return Ok(())
         }
 
@@ -253,37 +528,101 @@ impl App {
             if matches!(self.focus, MainFocus::Process) {
                 self.process.draw(
                     f,
-                    chunks[0],
+                    chunks[1],
                     true,
                 )?;
-                self.focus_rects.insert(MainFocus::Process, chunks[0]);
+                self.focus_rects.insert(MainFocus::Process, chunks[1]);
             }
 
             if matches!(self.focus, MainFocus::CPU) {
+                self.cpu.set_expanded(true);
                 self.cpu.draw(
                     f,
-                    chunks[0],
+                    chunks[1],
                     true,
                 )?;
-                self.focus_rects.insert(MainFocus::CPU, chunks[0]);
+                self.focus_rects.insert(MainFocus::CPU, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Pressure) {
+                self.pressure.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Pressure, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Temp) {
+                self.temp.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Temp, chunks[1]);
             }
 
             if matches!(self.focus, MainFocus::Memory) {
                 self.memory.draw(
                     f,
-                    chunks[0],
+                    chunks[1],
                     true,
                 )?;
-                self.focus_rects.insert(MainFocus::Memory, chunks[0]);
+                self.focus_rects.insert(MainFocus::Memory, chunks[1]);
             }
 
             if matches!(self.focus, MainFocus::Network) {
                 self.network.draw(
                     f,
-                    chunks[0],
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Network, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::DiskIo) {
+                self.disk_io.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::DiskIo, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Units) {
+                self.units.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Units, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Limits) {
+                self.limits.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Limits, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Connections) {
+                self.connections.draw(
+                    f,
+                    chunks[1],
+                    true,
+                )?;
+                self.focus_rects.insert(MainFocus::Connections, chunks[1]);
+            }
+
+            if matches!(self.focus, MainFocus::Disks) {
+                self.disks.draw(
+                    f,
+                    chunks[1],
                     true,
                 )?;
-                self.focus_rects.insert(MainFocus::Network, chunks[0]);
+                self.focus_rects.insert(MainFocus::Disks, chunks[1]);
             }
         }
         else {
@@ -294,51 +633,116 @@ impl App {
                     Constraint::Percentage(24),
                     Constraint::Percentage(52),
                 ].as_ref())
-                .split(chunks[0]);
+                .split(chunks[1]);
             
-            let mut horizontal_chunks = Vec::new();
-
-            for chunk in vertical_chunks.iter() {
-                let horizontal_chunk = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([
-                        Constraint::Percentage(50),
-                        Constraint::Percentage(50),
-                    ])
-                    .split(*chunk);
-
-                horizontal_chunks.push(horizontal_chunk);
+            // cpu, pressure and temperatures
+            let top_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(25),
+                ])
+                .split(vertical_chunks[0]);
+
+            // memory, network and disk i/o
+            let middle_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(30),
+                ])
+                .split(vertical_chunks[1]);
+
+            if matches!(self.table_slot, MainFocus::Units) {
+                self.units.draw(
+                    f,
+                    vertical_chunks[2],
+                    matches!(self.focus, MainFocus::Units)
+                )?;
+                self.focus_rects.insert(MainFocus::Units, vertical_chunks[2]);
             }
+            else if matches!(self.table_slot, MainFocus::Limits) {
+                self.limits.draw(
+                    f,
+                    vertical_chunks[2],
+                    matches!(self.focus, MainFocus::Limits)
+                )?;
+                self.focus_rects.insert(MainFocus::Limits, vertical_chunks[2]);
+            }
+            else if matches!(self.table_slot, MainFocus::Connections) {
+                self.connections.draw(
+                    f,
+                    vertical_chunks[2],
+                    matches!(self.focus, MainFocus::Connections)
+                )?;
+                self.focus_rects.insert(MainFocus::Connections, vertical_chunks[2]);
+            }
+            else if matches!(self.table_slot, MainFocus::Disks) {
+                self.disks.draw(
+                    f,
+                    vertical_chunks[2],
+                    matches!(self.focus, MainFocus::Disks)
+                )?;
+                self.focus_rects.insert(MainFocus::Disks, vertical_chunks[2]);
+            }
+            else {
+                self.process.draw(
+                    f,
+                    vertical_chunks[2],
+                    matches!(self.focus, MainFocus::Process)
+                )?;
+                self.focus_rects.insert(MainFocus::Process, vertical_chunks[2]);
+            }
+
+            self.cpu.set_expanded(false);
+            self.cpu.draw(
+                f,
+                top_chunks[0],
+                matches!(self.focus, MainFocus::CPU)
+            )?;
+            self.focus_rects.insert(MainFocus::CPU, top_chunks[0]);
 
-            self.process.draw(
+            self.pressure.draw(
                 f,
-                vertical_chunks[2],
-                matches!(self.focus, MainFocus::Process)
+                top_chunks[1],
+                matches!(self.focus, MainFocus::Pressure)
             )?;
-            self.focus_rects.insert(MainFocus::Process, vertical_chunks[2]);
+            self.focus_rects.insert(MainFocus::Pressure, top_chunks[1]);
 
-            self.cpu.draw(
+            self.temp.draw(
                 f,
-                vertical_chunks[0],
-                matches!(self.focus, MainFocus::CPU)
+                top_chunks[2],
+                matches!(self.focus, MainFocus::Temp)
             )?;
-            self.focus_rects.insert(MainFocus::CPU, vertical_chunks[0]);
+            self.focus_rects.insert(MainFocus::Temp, top_chunks[2]);
 
             self.memory.draw(
                 f,
-                horizontal_chunks[1][0],
+                middle_chunks[0],
                 matches!(self.focus, MainFocus::Memory)
             )?;
-            self.focus_rects.insert(MainFocus::Memory, horizontal_chunks[1][0]);
+            self.focus_rects.insert(MainFocus::Memory, middle_chunks[0]);
 
             self.network.draw(
                 f,
-                horizontal_chunks[1][1],
+                middle_chunks[1],
                 matches!(self.focus, MainFocus::Network)
             )?;
-            self.focus_rects.insert(MainFocus::Network, horizontal_chunks[1][1]);
+            self.focus_rects.insert(MainFocus::Network, middle_chunks[1]);
+
+            self.disk_io.draw(
+                f,
+                middle_chunks[2],
+                matches!(self.focus, MainFocus::DiskIo)
+            )?;
+            self.focus_rects.insert(MainFocus::DiskIo, middle_chunks[2]);
         }
 
+        self.column_chooser.draw(f, chunks[1], false)?;
+        self.process_detail.draw(f, chunks[1], false)?;
+
         return Ok(())
     }
 }
@@ -350,12 +754,31 @@ fn commands(key_config: &KeyConfig, mouse_config: &MouseConfig) -> Vec<CommandIn
         //CommandInfo::new(command::change_tab(&self.config.key_config)),
         CommandInfo::new(command::move_selection(key_config)),
         CommandInfo::new(command::selection_to_top_bottom(key_config)),
+        CommandInfo::new(command::units(key_config)),
         CommandInfo::new(command::sort_list_by_name(key_config, mouse_config)),
         CommandInfo::new(command::sort_list_by_pid(key_config, mouse_config)),
         CommandInfo::new(command::sort_list_by_cpu_usage(key_config, mouse_config)),
         CommandInfo::new(command::sort_list_by_memory_usage(key_config, mouse_config)),
+        CommandInfo::new(command::sort_list_by_user(key_config)),
+        CommandInfo::new(command::sort_list_by_column(mouse_config)),
+        CommandInfo::new(command::sort_list_secondary(key_config, mouse_config)),
+        CommandInfo::new(command::only_mine(key_config)),
+        CommandInfo::new(command::group_processes(key_config)),
+        CommandInfo::new(command::column_chooser(key_config)),
+        CommandInfo::new(command::cpu_mode(key_config)),
         CommandInfo::new(command::filter_submit(key_config)),
         CommandInfo::new(command::terminate_process(key_config)),
+        CommandInfo::new(command::process_detail(key_config)),
+        CommandInfo::new(command::unit_processes(key_config)),
+        CommandInfo::new(command::sort_limits(key_config)),
+        CommandInfo::new(command::sort_connections(key_config)),
+        CommandInfo::new(command::network_interface(key_config)),
+        CommandInfo::new(command::cpu_breakdown(key_config)),
+        CommandInfo::new(command::pressure(key_config)),
+        CommandInfo::new(command::temp_sensor(key_config)),
+        CommandInfo::new(command::jump_to_process(key_config)),
+        CommandInfo::new(command::sort_disks(key_config)),
+        CommandInfo::new(command::disk_io(key_config)),
     ];
 
     res
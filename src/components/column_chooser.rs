@@ -0,0 +1,237 @@
+use anyhow::{Ok, Result};
+use crate::input::*;
+use ratatui::{
+    Frame,
+    prelude::*,
+    widgets::*,
+};
+use crate::config::Config;
+use crate::models::process_column::{ProcessColumnConfig, MAX_COLUMN_WIDTH, MIN_COLUMN_WIDTH};
+use super::{Component, DrawableComponent, EventState};
+
+// popup for toggling, reordering and resizing the process list columns
+pub struct ColumnChooserComponent {
+    columns: Vec<ProcessColumnConfig>,
+    selection: usize,
+    visible: bool,
+    pub config: Config,
+}
+
+impl ColumnChooserComponent {
+    pub fn new(config: Config) -> Self {
+        Self {
+            columns: config.process_columns.clone(),
+            selection: 0,
+            visible: false,
+            config,
+        }
+    }
+
+    pub fn show(&mut self, columns: Vec<ProcessColumnConfig>) {
+        self.columns = columns;
+        self.selection = self.selection.min(self.columns.len().saturating_sub(1));
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn columns(&self) -> &[ProcessColumnConfig] {
+        &self.columns
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        let max_idx = self.columns.len().saturating_sub(1);
+
+        self.selection = if down {
+            self.selection.saturating_add(1).min(max_idx)
+        }
+        else {
+            self.selection.saturating_sub(1)
+        };
+    }
+
+    // toggles visibility of the selected column, the last visible column cannot be hidden
+    fn toggle_selected(&mut self) {
+        let visible_count = self.columns.iter().filter(|c| c.visible).count();
+
+        if let Some(column) = self.columns.get_mut(self.selection) {
+            if column.visible && visible_count <= 1 {
+                return;
+            }
+            column.visible = !column.visible;
+        }
+    }
+
+    // moves the selected column one position up/down, the selection follows the column
+    fn move_selected(&mut self, down: bool) {
+        let idx = self.selection;
+
+        if down && idx + 1 < self.columns.len() {
+            self.columns.swap(idx, idx + 1);
+            self.selection = idx + 1;
+        }
+        else if !down && idx > 0 {
+            self.columns.swap(idx, idx - 1);
+            self.selection = idx - 1;
+        }
+    }
+
+    fn resize_selected(&mut self, grow: bool) {
+        if let Some(column) = self.columns.get_mut(self.selection) {
+            column.width = if grow {
+                column.width.saturating_add(1).min(MAX_COLUMN_WIDTH)
+            }
+            else {
+                column.width.saturating_sub(1).max(MIN_COLUMN_WIDTH)
+            };
+        }
+    }
+}
+
+impl Component for ColumnChooserComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed)
+        }
+
+        let key_config = &self.config.key_config;
+
+        if key == key_config.exit || key == key_config.column_chooser {
+            self.hide();
+        }
+        else if key == key_config.move_down {
+            self.move_selection(true);
+        }
+        else if key == key_config.move_up {
+            self.move_selection(false);
+        }
+        else if key == key_config.enter {
+            self.toggle_selected();
+        }
+        else if key == key_config.move_column_down {
+            self.move_selected(true);
+        }
+        else if key == key_config.move_column_up {
+            self.move_selected(false);
+        }
+        else if key == key_config.grow_column {
+            self.resize_selected(true);
+        }
+        else if key == key_config.shrink_column {
+            self.resize_selected(false);
+        }
+        else {
+            return Ok(EventState::NotConsumed)
+        }
+
+        Ok(EventState::Consumed)
+    }
+
+    fn mouse_event(&mut self, _mouse: Mouse) -> Result<EventState> {
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for ColumnChooserComponent {
+    fn draw(&mut self, f: &mut Frame, _area: Rect, _focused: bool) -> Result<()> {
+        if self.visible {
+            const SIZE: (u16, u16) = (44, 23);
+
+            let area = Rect::new(
+                (f.size().width.saturating_sub(SIZE.0)) / 2,
+                (f.size().height.saturating_sub(SIZE.1)) / 2,
+                SIZE.0.min(f.size().width),
+                SIZE.1.min(f.size().height),
+            );
+
+            let key_config = &self.config.key_config;
+            let hint = format!(
+                " [{:?}] show [{:?}/{:?}] move [{:?}/{:?}] width ",
+                key_config.enter,
+                key_config.move_column_up,
+                key_config.move_column_down,
+                key_config.shrink_column,
+                key_config.grow_column,
+            );
+
+            let items: Vec<ListItem> = self.columns
+                .iter()
+                .map(|column| {
+                    let checkbox = if column.visible { "[x]" } else { "[ ]" };
+                    ListItem::new(format!(" {} {:<20} {:>3}", checkbox, column.column.title(), column.width))
+                })
+                .collect();
+
+            let mut list_state = ListState::default();
+            list_state.select(Some(self.selection));
+
+            let list = List::new(items)
+                .highlight_style(Style::default().bg(Color::Blue))
+                .block(
+                    Block::default()
+                        .title(" Columns ")
+                        .title_bottom(hint)
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Thick)
+                );
+
+            f.render_widget(Clear, area);
+            f.render_stateful_widget(list, area, &mut list_state);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::process_column::ProcessColumn;
+
+    #[test]
+    fn test_toggle_move_resize() {
+        let config = Config::default();
+        let mut component = ColumnChooserComponent::new(config.clone());
+        component.show(config.process_columns.clone());
+        assert!(component.is_visible());
+
+        // PID is the first column and visible by default
+        let key_config = &config.key_config;
+        component.key_event(key_config.enter).unwrap();
+        assert!(!component.columns()[0].visible);
+
+        // moving PID down, selection follows the column
+        component.key_event(key_config.move_column_down).unwrap();
+        assert_eq!(component.columns()[1].column, ProcessColumn::Pid);
+        assert_eq!(component.selection, 1);
+        component.key_event(key_config.move_column_up).unwrap();
+        component.key_event(key_config.move_column_up).unwrap();
+        assert_eq!(component.columns()[0].column, ProcessColumn::Pid);
+
+        let width = component.columns()[0].width;
+        component.key_event(key_config.grow_column).unwrap();
+        assert_eq!(component.columns()[0].width, width + 1);
+
+        component.key_event(key_config.exit).unwrap();
+        assert!(!component.is_visible());
+    }
+
+    #[test]
+    fn test_last_visible_column() {
+        let config = Config::default();
+        let mut component = ColumnChooserComponent::new(config.clone());
+        let mut columns = config.process_columns.clone();
+        columns.iter_mut().for_each(|c| c.visible = false);
+        columns[0].visible = true;
+        component.show(columns);
+
+        component.key_event(config.key_config.enter).unwrap();
+        assert!(component.columns()[0].visible);
+    }
+}
@@ -5,6 +5,11 @@ static CMD_GROUP_PROCESS: &str = "-- Process --";
 static CMD_GROUP_CPU: &str = "-- CPU --";
 static CMD_GROUP_MEMORY: &str = "-- Memory --";
 static CMD_GROUP_NETWORK: &str = "-- Network --";
+static CMD_GROUP_TEMP: &str = "-- Temperatures --";
+static CMD_GROUP_UNITS: &str = "-- Units/Limits --";
+static CMD_GROUP_CONNECTIONS: &str = "-- Connections --";
+static CMD_GROUP_DISKS: &str = "-- Disks --";
+static CMD_GROUP_PRESSURE: &str = "-- Pressure --";
 
 #[derive(Clone, PartialEq, PartialOrd, Ord, Eq)]
 pub struct CommandText {
@@ -84,6 +89,17 @@ pub fn help(key: &KeyConfig) -> CommandText {
     )
 }
 
+pub fn units(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Toggle units: SI (kB)/IEC (KiB), network rates in bytes/bits [{:?}] [{:?}]",
+            key.unit_system_toggle,
+            key.network_unit_toggle,
+        ),
+        CMD_GROUP_GENERAL
+    )
+}
+
 pub fn terminate_process(key: &KeyConfig) -> CommandText {
     CommandText::new(
         format!(
@@ -94,7 +110,63 @@ pub fn terminate_process(key: &KeyConfig) -> CommandText {
     )
 }
 
+pub fn network_interface(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Select network interface/switch to chart time scale [{:?}/{:?}, {:?}]",
+            key.move_up,
+            key.move_down,
+            key.filter,
+        ),
+        CMD_GROUP_NETWORK
+    )
+}
+
+pub fn temp_sensor(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Select temperature sensor/switch to chart time scale [{:?}/{:?}, {:?}]",
+            key.move_up,
+            key.move_down,
+            key.filter,
+        ),
+        CMD_GROUP_TEMP
+    )
+}
+
+pub fn cpu_breakdown(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Chart cpu usage/time by state/per-core heatmap, toggle iowait/steal [{:?}, {:?}/{:?}]",
+            key.enter,
+            key.toggle_iowait,
+            key.toggle_steal,
+        ),
+        CMD_GROUP_CPU
+    )
+}
+
 // Process specific::begin
+pub fn cpu_mode(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Cycle process and unit cpu usage: per cpu (irix)/of all cpus (solaris)/of physical cores [{:?}]",
+            key.cpu_mode_toggle,
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn process_detail(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Show details and open files of selected process [{:?}]",
+            key.enter,
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
 pub fn sort_list_by_name(key: &KeyConfig, mouse: &MouseConfig) -> CommandText {
     CommandText::new(
         format!(
@@ -135,6 +207,74 @@ pub fn sort_list_by_memory_usage(key: &KeyConfig, mouse: &MouseConfig) -> Comman
     )
 }
 
+pub fn sort_list_by_column(mouse: &MouseConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Sort by column toggle [{:?}] on header",
+            mouse.left_click
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn sort_list_secondary(key: &KeyConfig, mouse: &MouseConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Add/remove secondary sort key [{:?} then sort key] [{:?}] on header | [{:?}]",
+            key.sort_secondary,
+            mouse.middle_click,
+            key.sort_remove_secondary,
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn sort_list_by_user(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Sort by user toggle [{:?}]",
+            key.sort_user_toggle
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn only_mine(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Only show my processes toggle [{:?}]",
+            key.only_mine_toggle
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn group_processes(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Group by name/executable/cgroup toggle, expand group [{:?}] [{:?}]",
+            key.group_toggle,
+            key.enter,
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
+pub fn column_chooser(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Choose columns: show/move/width [{:?}] [{:?}] [{:?}/{:?}] [{:?}/{:?}]",
+            key.column_chooser,
+            key.enter,
+            key.move_column_up,
+            key.move_column_down,
+            key.shrink_column,
+            key.grow_column,
+        ),
+        CMD_GROUP_PROCESS
+    )
+}
+
 pub fn select_process(key: &KeyConfig, mouse: &MouseConfig) -> CommandText {
     CommandText::new(
         format!(
@@ -144,4 +284,84 @@ pub fn select_process(key: &KeyConfig, mouse: &MouseConfig) -> CommandText {
         CMD_GROUP_PROCESS
     )
 }
-// Process specific::end
\ No newline at end of file
+// Process specific::end
+
+pub fn unit_processes(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Show processes of selected unit or cgroup/show all processes [{:?}/{:?}]",
+            key.enter,
+            key.exit,
+        ),
+        CMD_GROUP_UNITS
+    )
+}
+
+pub fn sort_limits(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Sort limits by name/% of cpu quota/% of memory limit [{:?}/{:?}/{:?}]",
+            key.sort_name_toggle,
+            key.sort_cpu_toggle,
+            key.sort_memory_toggle,
+        ),
+        CMD_GROUP_UNITS
+    )
+}
+
+pub fn sort_connections(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Sort connections by pid/process name [{:?}/{:?}]",
+            key.sort_pid_toggle,
+            key.sort_name_toggle,
+        ),
+        CMD_GROUP_CONNECTIONS
+    )
+}
+
+pub fn jump_to_process(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Select the process owning the selected connection [{:?}]",
+            key.enter,
+        ),
+        CMD_GROUP_CONNECTIONS
+    )
+}
+
+pub fn sort_disks(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Sort disks by mount point/% used [{:?}/{:?}]",
+            key.sort_name_toggle,
+            key.sort_memory_toggle,
+        ),
+        CMD_GROUP_DISKS
+    )
+}
+
+pub fn disk_io(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Select block device/switch to chart time scale/chart throughput, IOPS or utilization [{:?}/{:?}, {:?}, {:?}]",
+            key.move_up,
+            key.move_down,
+            key.filter,
+            key.enter,
+        ),
+        CMD_GROUP_DISKS
+    )
+}
+
+pub fn pressure(key: &KeyConfig) -> CommandText {
+    CommandText::new(
+        format!(
+            "Chart cpu, memory or io stalls/change chart time scale [{:?}, {:?}/{:?}]",
+            key.enter,
+            key.move_up,
+            key.move_down,
+        ),
+        CMD_GROUP_PRESSURE
+    )
+}
@@ -0,0 +1,265 @@
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::config::*;
+use crate::input::{Key, Mouse, MouseKind};
+use crate::services::VecProvider;
+use crate::components::utils::sortable_table::{SortableColumn, SortableTable};
+use crate::components::filter::FilterComponent;
+use crate::components::process::Focus;
+use crate::components::*;
+use crate::models::items::connection_item::{ConnectionItem, ConnectionItemSortOrder};
+
+// columns of the connection list
+const CONNECTION_COLUMNS: [SortableColumn<ConnectionItemSortOrder>; 6] = [
+    SortableColumn::sorted("PROTO", ConnectionItemSortOrder::ProtocolInc, ConnectionItemSortOrder::ProtocolDec, 7),
+    SortableColumn::sorted("LOCAL", ConnectionItemSortOrder::LocalInc, ConnectionItemSortOrder::LocalDec, 30),
+    SortableColumn::sorted("REMOTE", ConnectionItemSortOrder::RemoteInc, ConnectionItemSortOrder::RemoteDec, 30),
+    SortableColumn::sorted("STATE", ConnectionItemSortOrder::StateInc, ConnectionItemSortOrder::StateDec, 11),
+    SortableColumn::sorted("PID(p)", ConnectionItemSortOrder::PidInc, ConnectionItemSortOrder::PidDec, 9),
+    SortableColumn::sorted("PROCESS(n)", ConnectionItemSortOrder::NameInc, ConnectionItemSortOrder::NameDec, 20),
+];
+
+// ConnectionComponent lists the tcp, udp and unix sockets of the host with the process owning
+// them, similar to `ss -tuxap`. The filter matches ports with ":<port>", e.g. ":8080" lists what
+// is listening on or connected to port 8080. Pressing enter on a connection requests a jump, App
+// then selects the owning process in the process list, see take_jump.
+pub struct ConnectionComponent {
+    table: SortableTable<ConnectionItem, ConnectionItemSortOrder>,
+    filter_area: Option<Rect>,
+    filter_component: FilterComponent,
+    focus: Focus,
+    jump: Option<u32>,                          // pid of the process to select in the process list, taken by App
+    pub config: Config,
+}
+
+impl ConnectionComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<ConnectionItem>
+    {
+        Self {
+            table: SortableTable::new(&CONNECTION_COLUMNS, service.fetch_items(), ConnectionItemSortOrder::LocalInc)
+                .with_increasing_first(),
+            filter_area: None,
+            filter_component: FilterComponent::new(config.clone()),
+            focus: Focus::List,
+            jump: None,
+            config,
+        }
+    }
+
+    pub fn take_jump(&mut self) -> Option<u32> {
+        self.jump.take()
+    }
+
+    // maps a sort key to its column, returns true if key is a sort key
+    fn handle_sort(&mut self, key: Key) -> bool {
+        let key_config = &self.config.key_config;
+
+        let column = if key == key_config.sort_pid_toggle {
+            4
+        }
+        else if key == key_config.sort_name_toggle {
+            5
+        }
+        else {
+            return false;
+        };
+
+        self.table.sort_column(column);
+        true
+    }
+
+    fn handle_mouse_click_on_filter(&mut self, click_y: u16) -> bool {
+        let Some(filter_area) = self.filter_area
+        else {
+            return false;
+        };
+
+        if click_y < filter_area.top() || click_y >= filter_area.bottom() {
+            return false;
+        }
+
+        self.focus = Focus::Filter;
+        true
+    }
+}
+
+impl<S> Refreshable<S> for ConnectionComponent
+where
+    S: VecProvider<ConnectionItem>
+{
+    fn refresh(&mut self, service: &S) {
+        self.table.replace(service.fetch_items(), |connection| connection.clone());
+    }
+}
+
+impl Component for ConnectionComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if key == self.config.key_config.filter && matches!(self.focus, Focus::List) {
+            self.focus = Focus::Filter;
+            return Ok(EventState::Consumed)
+        }
+
+        if matches!(self.focus, Focus::Filter) {
+            if self.filter_component.key_event(key)?.is_consumed() {
+                self.table.set_filter(self.filter_component.filter_contents());
+                return Ok(EventState::Consumed)
+            }
+
+            if key == self.config.key_config.enter {
+                self.focus = Focus::List;
+                return Ok(EventState::Consumed)
+            }
+
+            return Ok(EventState::NotConsumed)
+        }
+
+        if let Some(move_dir) = common_nav(key, &self.config.key_config) {
+            self.table.move_selection(move_dir);
+            return Ok(EventState::Consumed)
+        }
+
+        if self.handle_sort(key) {
+            return Ok(EventState::Consumed)
+        }
+
+        if key == self.config.key_config.enter {
+            self.jump = self.table.selected().and_then(|connection| connection.pid());
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match mouse.kind {
+            MouseKind::ScrollDown => {
+                self.table.move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::ScrollUp => {
+                self.table.move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::LeftClick => {
+                if self.table.handle_mouse_click(mouse.column, mouse.row) {
+                    self.focus = Focus::List;
+                    return Ok(EventState::Consumed)
+                }
+                if self.handle_mouse_click_on_filter(mouse.row) {
+                    return Ok(EventState::Consumed)
+                }
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for ConnectionComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),        // table
+                Constraint::Length(3),      // filter
+            ])
+            .split(area);
+        self.filter_area = Some(chunks[1]);
+
+        let title = format!(" Connections ({}) ", self.table.row_count());
+        let list_focused = focused && matches!(self.focus, Focus::List);
+
+        self.table.draw(f, chunks[0], list_focused, title, &self.config.theme_config, |connection, style| {
+            let socket = connection.socket();
+
+            let cells = vec![
+                Cell::from(socket.protocol().to_string()),
+                Cell::from(socket.local().to_string()),
+                Cell::from(socket.remote().to_string()),
+                Cell::from(socket.state().to_string()),
+                Cell::from(connection.pid().map_or_else(|| String::from("-"), |pid| pid.to_string())),
+                Cell::from(connection.name().unwrap_or("-").to_string()),
+            ];
+            (cells, style)
+        })?;
+        self.filter_component.draw(f, chunks[1], focused && matches!(self.focus, Focus::Filter))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::items::socket_item::SocketItem;
+
+    struct DummyService {}
+
+    impl VecProvider<ConnectionItem> for DummyService {
+        fn fetch_items(&self) -> Vec<ConnectionItem> {
+            let socket = |protocol: &str, local: &str, remote: &str, state: &str, inode: u64| {
+                SocketItem::new(protocol.to_string(), local.to_string(), remote.to_string(), state.to_string(), inode)
+            };
+
+            vec![
+                ConnectionItem::new(socket("tcp6", "[::1]:8080", "*:*", "LISTEN", 52114), Some(812), Some(String::from("gunicorn"))),
+                ConnectionItem::new(socket("tcp", "0.0.0.0:22", "*:*", "LISTEN", 18342), Some(640), Some(String::from("sshd"))),
+                ConnectionItem::new(socket("tcp", "10.0.2.15:22", "10.0.2.2:54178", "ESTAB", 40771), None, None),
+                ConnectionItem::new(socket("u_str", "/run/systemd/private", "*", "LISTEN", 17823), Some(1), Some(String::from("systemd"))),
+            ]
+        }
+    }
+
+    fn inodes(component: &ConnectionComponent) -> Vec<u64> {
+        component.table.vec_state().iter_with_selection().map(|(_, connection, _)| connection.socket().inode()).collect()
+    }
+
+    #[test]
+    fn test_sort() {
+        let config = Config::default();
+        let mut component = ConnectionComponent::new(config.clone(), &DummyService {});
+
+        // local port increasing, unix sockets last
+        assert_eq!(inodes(&component), vec![18342, 40771, 52114, 17823]);
+
+        component.key_event(config.key_config.sort_pid_toggle).unwrap();
+        assert_eq!(inodes(&component), vec![40771, 17823, 18342, 52114]);
+        component.key_event(config.key_config.sort_pid_toggle).unwrap();
+        assert_eq!(inodes(&component), vec![52114, 18342, 17823, 40771]);
+    }
+
+    #[test]
+    fn test_filter_port() {
+        let config = Config::default();
+        let mut component = ConnectionComponent::new(config.clone(), &DummyService {});
+
+        component.key_event(config.key_config.filter).unwrap();
+        for c in ":22".chars() {
+            component.key_event(Key::Char(c)).unwrap();
+        }
+        assert_eq!(inodes(&component), vec![18342, 40771]);
+        assert_eq!(component.table.ui_selection(), Some(0));
+
+        // submitting the filter, the list keeps it
+        component.key_event(config.key_config.enter).unwrap();
+        assert_eq!(component.focus, Focus::List);
+        assert_eq!(inodes(&component), vec![18342, 40771]);
+    }
+
+    #[test]
+    fn test_jump() {
+        let config = Config::default();
+        let mut component = ConnectionComponent::new(config.clone(), &DummyService {});
+
+        component.key_event(config.key_config.enter).unwrap();
+        assert_eq!(component.take_jump(), Some(640));
+        assert_eq!(component.take_jump(), None);
+
+        // connections without a known owner do not jump
+        component.key_event(config.key_config.move_down).unwrap();
+        component.key_event(config.key_config.enter).unwrap();
+        assert_eq!(component.take_jump(), None);
+    }
+}
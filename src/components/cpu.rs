@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use ratatui::Frame;
 use ratatui::layout::Position;
 use ratatui::prelude::*;
-use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState, Paragraph, Wrap};
 use std::str::FromStr;
 use anyhow::{Ok, Result};
 use crate::input::*;
@@ -13,7 +13,10 @@ use crate::services::sysinfo_service::SysInfoService;
 use crate::components::utils::selection::UISelection;
 use crate::components::*;
 use crate::models::bounded_queue_model::BoundedQueueModel;
-use crate::models::items::cpu_item::CpuItem;
+use crate::models::items::cpu_item::{CpuBreakdown, CpuItem};
+use crate::models::items::topology_item::TopologyItem;
+use crate::components::utils::history_chart::{series, HistoryChart};
+use crate::models::units::{format_bytes, format_value, UnitSystem};
 use crate::config::Config;
 use crate::config::*;
 
@@ -78,6 +81,55 @@ pub enum Focus {
     CPUList,
 }
 
+// what the chart area shows, cycled with enter
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CpuChart {
+    Usage,
+    Breakdown,                              // time spent in each state
+    Heatmap,                                // every core as a cell colored by its usage
+}
+
+impl CpuChart {
+    fn next(self) -> Self {
+        match self {
+            Self::Usage => Self::Breakdown,
+            Self::Breakdown => Self::Heatmap,
+            Self::Heatmap => Self::Usage,
+        }
+    }
+}
+
+// color of a heatmap cell, idle cores fade out and pegged ones stand out in red
+fn heat_color(usage: f32) -> Color {
+    match usage {
+        usage if usage < 10_f32 => Color::DarkGray,
+        usage if usage < 30_f32 => Color::Green,
+        usage if usage < 50_f32 => Color::LightGreen,
+        usage if usage < 70_f32 => Color::Yellow,
+        usage if usage < 90_f32 => Color::LightRed,
+        _ => Color::Red,
+    }
+}
+
+// widest cell (usage and a separator) that fits count cells in the area, 1 draws a block per core
+fn heatmap_cell_width(count: usize, width: u16, height: u16) -> u16 {
+    [4, 3, 2]
+        .into_iter()
+        .find(|cell_width| {
+            let per_row = (width / cell_width) as usize;
+            per_row > 0 && count.div_ceil(per_row) <= height as usize
+        })
+        .unwrap_or(1)
+}
+
+// a layer of the cpu time breakdown chart
+struct BreakdownLayer {
+    name: &'static str,
+    color: Color,
+    percent: fn(&CpuBreakdown) -> f32,
+    shown: bool,
+}
+
 pub struct CPUComponent {
     cpus: BTreeMap<usize, BoundedQueueModel<CpuItem>>,
     selection_state: UISelection,
@@ -86,6 +138,11 @@ pub struct CPUComponent {
     chart_area: Option<Rect>,
     list_area: Option<Rect>,
     focus: Focus,
+    chart: CpuChart,
+    topology: TopologyItem,
+    expanded: bool,                         // the topology is shown in the expanded view
+    show_iowait: bool,
+    show_steal: bool,
     config: Config,
 }
 
@@ -125,10 +182,325 @@ impl CPUComponent {
             chart_area,
             list_area,
             focus,
+            chart: CpuChart::Usage,
+            topology: sysinfo.get_topology().clone(),
+            expanded: false,
+            show_iowait: true,
+            show_steal: true,
             config,
         }       
     }
 
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+    }
+
+    // cpu ids in the order of the list after All: the global usage, then the cpus grouped by socket,
+    // NUMA node and core, cpus missing from the topology last
+    fn list_ids(&self) -> Vec<usize> {
+        let mut ids: Vec<usize> = std::iter::once(0)
+            .chain(self.topology.ordered_cpus().into_iter().map(|cpu| cpu + 1))
+            .filter(|id| self.cpus.contains_key(id))
+            .collect();
+
+        for id in self.cpus.keys() {
+            if !ids.contains(id) {
+                ids.push(*id);
+            }
+        }
+
+        ids
+    }
+
+    // None for All
+    fn selected_id(&self) -> Option<usize> {
+        match self.selection_state.selection? {
+            0 => None,
+            selection => self.list_ids().get(selection.saturating_sub(self.selection_offset)).copied(),
+        }
+    }
+
+    // draws the sockets, NUMA nodes and cores with their SMT threads (e.g. 0+64), and the caches
+    fn draw_topology(&self, f: &mut Frame, area: Rect) {
+        let topology = &self.topology;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Topology ")
+            .style(self.config.theme_config.style_border_not_focused);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let horizontal_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Fill(1),                    // tree
+                Constraint::Length(36),                 // caches
+            ]).split(inner);
+
+        let text_style = self.config.theme_config.style_item_not_focused;
+        let mut tree = vec![
+            Line::styled(format!("{} {}", topology.vendor(), topology.brand()), text_style.add_modifier(Modifier::BOLD)),
+            Line::styled(
+                format!(
+                    "{} sockets, {} NUMA nodes, {} cores, {} threads",
+                    topology.packages().len(),
+                    topology.nodes().len(),
+                    topology.physical_core_count(),
+                    topology.cpus().len(),
+                ),
+                text_style,
+            ),
+        ];
+        if topology.cpus().is_empty() {
+            tree.push(Line::styled("topology not available", text_style));
+        }
+
+        for package in topology.packages() {
+            tree.push(Line::styled(format!("Socket {}", package), text_style.fg(Color::LightCyan)));
+
+            let placements: Vec<_> = topology.cpus().iter().filter(|placement| placement.package == package).collect();
+            let mut nodes: Vec<Option<usize>> = placements.iter().map(|placement| placement.node).collect();
+            nodes.sort();
+            nodes.dedup();
+
+            for node in nodes {
+                // every core once, as its threads joined by +
+                let mut cores: Vec<_> = placements
+                    .iter()
+                    .filter(|placement| placement.node == node && !placement.is_smt_sibling())
+                    .collect();
+                cores.sort_by_key(|placement| placement.core);
+                let cores = cores
+                    .iter()
+                    .map(|placement| placement.siblings.iter().map(|cpu| cpu.to_string()).collect::<Vec<_>>().join("+"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let node = node.map_or_else(|| String::from("-"), |node| node.to_string());
+                tree.push(Line::styled(format!("  Node {}: {}", node, cores), text_style));
+            }
+        }
+
+        // identical caches once with their count, e.g. L2 1.3 MiB x 4
+        let mut caches: Vec<(String, u64, usize, usize)> = Vec::new();
+        for cache in topology.caches() {
+            match caches.iter_mut().find(|(name, size, _, _)| *name == cache.name() && *size == cache.size) {
+                Some((_, _, count, _)) => *count += 1,
+                None => caches.push((cache.name(), cache.size, 1, cache.shared_cpus.len())),
+            }
+        }
+        let caches: Vec<Line> = std::iter::once(Line::styled("Caches", text_style.fg(Color::LightCyan)))
+            .chain(caches.into_iter().map(|(name, size, count, shared)| {
+                Line::styled(
+                    format!("{:<4}{:>9} x {:<3} {} CPUs each", name, format_bytes(size, self.config.unit_system()), count, shared),
+                    text_style,
+                )
+            }))
+            .collect();
+
+        f.render_widget(Paragraph::new(tree).wrap(Wrap { trim: false }), horizontal_chunks[0]);
+        f.render_widget(Paragraph::new(caches), horizontal_chunks[1]);
+    }
+
+    // layers of the breakdown chart from the bottom up, irq and softirq are shown together
+    fn breakdown_layers(&self) -> Vec<BreakdownLayer> {
+        vec![
+            BreakdownLayer { name: "USR", color: Color::LightGreen, percent: |b| b.user, shown: true },
+            BreakdownLayer { name: "NICE", color: Color::Blue, percent: |b| b.nice, shown: true },
+            BreakdownLayer { name: "SYS", color: Color::LightRed, percent: |b| b.system, shown: true },
+            BreakdownLayer { name: "IRQ", color: Color::LightYellow, percent: |b| b.irq + b.softirq, shown: true },
+            BreakdownLayer { name: "IOWAIT", color: Color::LightMagenta, percent: |b| b.iowait, shown: self.show_iowait },
+            BreakdownLayer { name: "STEAL", color: Color::Red, percent: |b| b.steal, shown: self.show_steal },
+            BreakdownLayer { name: "GUEST", color: Color::Cyan, percent: |b| b.guest, shown: true },
+        ]
+    }
+
+    // draws the cpu time of the selected cpu (the global time for All) stacked by state, each line
+    // is the sum of its layer and the layers below it
+    fn draw_breakdown(&self, f: &mut Frame, area: Rect, id: usize, style: Style) {
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),                  // legend
+                Constraint::Min(0),                     // chart
+            ]).split(area);
+
+        let time_scale = self.data_window_time_scale;
+        let data_window = (time_scale / self.config.refresh_rate()) as usize;
+        let queue = self.cpus.get(&id);
+        let breakdown = queue
+            .and_then(|queue| queue.back())
+            .and_then(|cpu| cpu.breakdown().copied());
+
+        let mut chart_series = Vec::new();
+        let mut legend = Vec::new();
+        let mut shown_layers: Vec<fn(&CpuBreakdown) -> f32> = Vec::new();
+
+        for layer in self.breakdown_layers() {
+            let percent = breakdown.as_ref().map_or_else(|| String::from("-"), |b| format!("{:.1}%", (layer.percent)(b)));
+
+            if !layer.shown {
+                legend.push(Span::styled(format!("{} off ", layer.name), Style::default().fg(Color::DarkGray)));
+                continue;
+            }
+            legend.push(Span::styled(format!("{} {} ", layer.name, percent), Style::default().fg(layer.color)));
+
+            shown_layers.push(layer.percent);
+            if let Some(queue) = queue {
+                let stacked = shown_layers.clone();
+                chart_series.push((
+                    series(queue, data_window, |cpu| {
+                        cpu.breakdown().map_or(0_f64, |b| stacked.iter().map(|percent| percent(b) as f64).sum())
+                    }),
+                    layer.color,
+                ));
+            }
+        }
+
+        let name = if id == 0 { String::from("Global") } else { format!("CPU {}", id.saturating_sub(1)) };
+        let legend = Paragraph::new(Line::from(legend).right_aligned())
+            .block(Block::new()
+                .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
+                .style(style)
+                .title(format!(" CPU time ({}) ", name))
+            );
+        f.render_widget(legend, vertical_chunks[0]);
+
+        let chart = HistoryChart {
+            series: chart_series,
+            unit_system: self.config.unit_system(),
+            unit: String::from("%"),
+            data_window,
+            time_scale,
+            upper: Some(100_f64),
+        };
+        chart.draw(f, vertical_chunks[1], Borders::LEFT|Borders::BOTTOM|Borders::RIGHT, style);
+    }
+
+    // draws the frequency history of the selected cpu (the average over all cpus for All) against the
+    // highest frequency the governor may scale to, which drops when the cpu is throttled
+    fn draw_frequency(&self, f: &mut Frame, area: Rect, id: usize, style: Style) {
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),                  // legend
+                Constraint::Min(0),                     // chart
+            ]).split(area);
+
+        let time_scale = self.data_window_time_scale;
+        let data_window = (time_scale / self.config.refresh_rate()) as usize;
+        let queue = self.cpus.get(&id);
+        let cpu = queue.and_then(|queue| queue.back());
+        let hz = |mhz: u64| mhz as f64 * 1_000_000_f64;
+
+        let mut legend = vec![
+            Span::styled(
+                format_value(hz(cpu.map_or(0, |cpu| cpu.frequency())), UnitSystem::Si, "Hz"),
+                Style::default().fg(Color::LightCyan),
+            ),
+        ];
+        if let Some(scaling) = cpu.and_then(|cpu| cpu.scaling()) {
+            let governor_style = if scaling.is_power_saving() {
+                Style::default().fg(Color::LightYellow)
+            }
+            else {
+                Style::default()
+            };
+
+            legend.push(Span::raw(" :: "));
+            legend.push(Span::styled(
+                format!("{}-{}", format_value(hz(scaling.min), UnitSystem::Si, "Hz"), format_value(hz(scaling.max), UnitSystem::Si, "Hz")),
+                Style::default().fg(Color::LightRed),
+            ));
+            legend.push(Span::raw(" :: "));
+            legend.push(Span::styled(scaling.governor.clone(), governor_style));
+        }
+
+        let name = if id == 0 { String::from("avg") } else { format!("CPU {}", id.saturating_sub(1)) };
+        let legend = Paragraph::new(Line::from(legend).right_aligned())
+            .block(Block::new()
+                .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
+                .style(style)
+                .title(format!(" Frequency ({}) ", name))
+            );
+        f.render_widget(legend, vertical_chunks[0]);
+
+        let mut chart_series = Vec::new();
+        if let Some(queue) = queue {
+            chart_series.push((series(queue, data_window, |cpu| hz(cpu.frequency())), Color::LightCyan));
+            if cpu.and_then(|cpu| cpu.scaling()).is_some() {
+                chart_series.push((series(queue, data_window, |cpu| cpu.scaling().map_or(0_f64, |scaling| hz(scaling.max))), Color::LightRed));
+            }
+        }
+
+        // frequencies always use decimal prefixes
+        let chart = HistoryChart {
+            series: chart_series,
+            unit_system: UnitSystem::Si,
+            unit: String::from("Hz"),
+            data_window,
+            time_scale,
+            upper: None,
+        };
+        chart.draw(f, vertical_chunks[1], Borders::LEFT|Borders::BOTTOM|Borders::RIGHT, style);
+    }
+
+    // draws the current usage of every core as a cell of a grid, the selected core is underlined
+    fn draw_heatmap(&self, f: &mut Frame, area: Rect, selection: Option<usize>, style: Style) {
+        let cores: Vec<(usize, f32)> = self.cpus
+            .iter()
+            .filter(|(id, _)| **id > 0)
+            .filter_map(|(id, queue)| Some((*id, queue.back()?.usage())))
+            .collect();
+
+        let title = match cores.iter().max_by(|a, b| a.1.total_cmp(&b.1)) {
+            Some((id, usage)) => format!(" CPU heatmap ({} cores, max {:.0}% CPU {}) ", cores.len(), usage, id.saturating_sub(1)),
+            None => String::from(" CPU heatmap "),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(style);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let cell_width = heatmap_cell_width(cores.len(), inner.width, inner.height);
+        let per_row = (inner.width / cell_width).max(1) as usize;
+
+        let lines: Vec<Line> = cores
+            .chunks(per_row)
+            .map(|row| {
+                let mut spans = Vec::new();
+                for (id, usage) in row {
+                    let mut cell_style = Style::default();
+                    if Some(*id) == selection {
+                        cell_style = cell_style.add_modifier(Modifier::BOLD|Modifier::UNDERLINED);
+                    }
+
+                    // wide cells show the usage on the color, narrow ones only the color
+                    if cell_width == 1 {
+                        spans.push(Span::styled("█", cell_style.fg(heat_color(*usage))));
+                        continue;
+                    }
+                    let text = match cell_width {
+                        4 => format!("{:>3.0}", usage),
+                        3 => format!("{:>2.0}", usage.min(99_f32)),
+                        _ => String::from(" "),
+                    };
+                    spans.push(Span::styled(text, cell_style.bg(heat_color(*usage)).fg(Color::Black)));
+                    spans.push(Span::raw(" "));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+
     fn handle_mouse_click_on_chart(&mut self, click_x: u16, click_y: u16) -> bool {
         if self.chart_area.is_none() { return false; }
         let chart_area = self.chart_area.unwrap();
@@ -200,6 +572,20 @@ impl Component for CPUComponent {
             }
         }
 
+        // key events to switch between the usage, the cpu time breakdown and its layers, and the heatmap
+        if key == key_config.enter {
+            self.chart = self.chart.next();
+            return Ok(EventState::Consumed)
+        }
+        if self.chart == CpuChart::Breakdown && key == key_config.toggle_iowait {
+            self.show_iowait = !self.show_iowait;
+            return Ok(EventState::Consumed)
+        }
+        if self.chart == CpuChart::Breakdown && key == key_config.toggle_steal {
+            self.show_steal = !self.show_steal;
+            return Ok(EventState::Consumed)
+        }
+
         // key event to move focus
         if key == key_config.filter {
             match self.focus {
@@ -255,11 +641,28 @@ impl Component for CPUComponent {
 impl DrawableComponent for CPUComponent {
     // draw function has some magic numbers relating to render position: TODO-research a fix/better approach
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        // the expanded view has the topology below the chart and list
+        let area = if self.expanded {
+            let vertical_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Fill(1),                // chart and list
+                    Constraint::Length(12),             // topology
+                ]).split(area);
+
+            self.draw_topology(f, vertical_chunks[1]);
+            vertical_chunks[0]
+        }
+        else {
+            area
+        };
+
+        let list_width = if self.topology.is_grouped() { 30 } else { 26 };
         let horizontal_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Fill(1),                    // chart
-                Constraint::Length(20),                 // list
+                Constraint::Length(list_width),         // list
             ]).split(area);
         
         // saving chart and list area for mouse clicks
@@ -286,17 +689,10 @@ impl DrawableComponent for CPUComponent {
         // All              ui_selection=0      cpu_selection=None
         // Global Usage     ui_selection=1      cpu_selection=0
         // CPU 0            ui_selection=2      cpu_selection=1
-        // CPU 1            ui_selection=3      cpu_selection=2
+        // CPU 4+           ui_selection=3      cpu_selection=5     (SMT sibling of CPU 0)
         // ...              ...
-        // This means len(UICPUList) = 1 + len(cpus)
-
-        // set cpu selection
-        let cpu_selection = if let Some(selection) = self.selection_state.selection {
-            if selection == 0 { None }
-            else {Some(selection.saturating_sub(self.selection_offset))}
-  
-        }
-        else { None };
+        // This means len(UICPUList) = 1 + len(cpus), see list_ids for the order of the cpus
+        let cpu_selection = self.selected_id();
 
         // iterate over cpus
         if let Some(selection) = self.selection_state.selection {
@@ -317,9 +713,7 @@ impl DrawableComponent for CPUComponent {
                     }
             }
             else {
-                let id = cpu_selection.unwrap();            // unwrap should be safe here
-
-                if let Some(queue) = self.cpus.get(&id) {
+                if let Some((id, queue)) = cpu_selection.and_then(|id| Some((id, self.cpus.get(&id)?))) {
                     let data: Vec<(f64, f64)> = queue
                         .iter()
                         .rev()
@@ -344,25 +738,37 @@ impl DrawableComponent for CPUComponent {
             );
         }
 
-        // populate names for UIList to draw cpu list
-        let mut names: Vec<(usize, String)> = self.cpus
-            .iter()
-            .map(|(key, queue)| {
-                let usage = queue.back().unwrap().usage();
-
-                let label = if *key == 0 {
+        // populate names for UIList to draw cpu list, prefixed with the socket/NUMA node on multi-socket or NUMA
+        // machines. SMT siblings are marked with a + and follow their core's first thread
+        let grouped = self.topology.is_grouped();
+        let mut names: Vec<(usize, String)> = self.list_ids()
+            .into_iter()
+            .filter_map(|key| {
+                let cpu = self.cpus.get(&key)?.back()?;
+                let frequency = cpu.frequency() as f64 / 1000_f64;     // GHz
+                let placement = self.topology.placement(key.saturating_sub(1)).filter(|_| key > 0);
+
+                let label = if key == 0 {
                     String::from("Global")
                 }
+                else if placement.is_some_and(|placement| placement.is_smt_sibling()) {
+                    format!("CPU {}+", key.saturating_sub(1))
+                }
                 else {
                     format!("CPU {}", key.saturating_sub(1))
                 };
+                let group = match placement {
+                    Some(placement) if grouped => format!("{:<4}", format!("{}/{}", placement.package, placement.node.map_or_else(|| String::from("-"), |node| node.to_string()))),
+                    _ if grouped => String::from("    "),
+                    _ => String::new(),
+                };
 
-                (*key, format!("{:<7} {:.2}", label, usage))
+                Some((key, format!("{}{:<8} {:>6.2} {:>4.1}", group, label, cpu.usage(), frequency)))
                 //ListItem::new(title).style(Color::from_str(ColorWheel::from_index(*key).as_str()).unwrap())
             })
             .collect();
         // insert All option into UI list
-        let all_option = format!("{:<7} {}", String::from("All"), String::from("%"));
+        let all_option = format!("{}{:<8} {:>6} {:>4}", if grouped { "S/N " } else { "" }, String::from("All"), String::from("%"), String::from("GHz"));
         // assigning random key to all for coloring, colorwheel::7783 => magenta
         names.insert(0, (7783, all_option));
 
@@ -379,42 +785,66 @@ impl DrawableComponent for CPUComponent {
                 }
             }).collect();
 
-        // render chart
-        let chart = Chart::new(datasets)
-            .block(
-                {
-                    if focused && matches!(cpu_focus, Focus::Chart) {
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(" CPU ")
-                            .style(self.config.theme_config.style_border_focused)
-                    }
-                    else {
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(" CPU ")
-                            .style(self.config.theme_config.style_border_not_focused)
+        let chart_style = if focused && matches!(cpu_focus, Focus::Chart) {
+            self.config.theme_config.style_border_focused
+        }
+        else {
+            self.config.theme_config.style_border_not_focused
+        };
+
+        if self.chart == CpuChart::Breakdown {
+            self.draw_breakdown(f, horizontal_chunks[0], cpu_selection.unwrap_or(0), chart_style);
+        }
+        else if self.chart == CpuChart::Heatmap {
+            self.draw_heatmap(f, horizontal_chunks[0], cpu_selection, chart_style);
+        }
+        else {
+            // render chart
+            let chart = Chart::new(datasets)
+                .block(
+                    {
+                        if focused && matches!(cpu_focus, Focus::Chart) {
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .title(" CPU ")
+                                .style(self.config.theme_config.style_border_focused)
+                        }
+                        else {
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .title(" CPU ")
+                                .style(self.config.theme_config.style_border_not_focused)
+                        }
                     }
-                }
-            )
-            .x_axis(
-                Axis::default()
-                    .bounds([0.0,  max_idx as f64])
-                    .labels(vec![Span::raw(format!("{}s", ms_to_s(time_scale))), Span::raw("now")])
-                    .labels_alignment(Alignment::Right),
-            )
-            .y_axis(
-                Axis::default()
-                    .bounds([0.0, 100.0])
-                    .labels(vec![
-                        Span::raw("0%"),
-                        Span::raw("50"),
-                        Span::raw("100"),
-                    ])
-                    .labels_alignment(Alignment::Right),
-            );
-            
-        f.render_widget(chart, horizontal_chunks[0]);
+                )
+                .x_axis(
+                    Axis::default()
+                        .bounds([0.0,  max_idx as f64])
+                        .labels(vec![Span::raw(format!("{}s", ms_to_s(time_scale))), Span::raw("now")])
+                        .labels_alignment(Alignment::Right),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, 100.0])
+                        .labels(vec![
+                            Span::raw("0%"),
+                            Span::raw("50"),
+                            Span::raw("100"),
+                        ])
+                        .labels_alignment(Alignment::Right),
+                );
+
+            // usage with the frequency next to it
+            let usage_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(65),
+                    Constraint::Percentage(35),
+                ]).split(horizontal_chunks[0]);
+
+            f.render_widget(chart, usage_chunks[0]);
+            self.draw_frequency(f, usage_chunks[1], cpu_selection.unwrap_or(0), chart_style);
+        }
 
         // render cpu list
         let mut list_state = ListState::default();
@@ -435,4 +865,24 @@ impl DrawableComponent for CPUComponent {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_heatmap() {
+        assert_eq!(heat_color(0_f32), Color::DarkGray);
+        assert_eq!(heat_color(55_f32), Color::Yellow);
+        assert_eq!(heat_color(100_f32), Color::Red);
+
+        // cells shrink from the usage to only the color as the cores stop fitting
+        assert_eq!(heatmap_cell_width(128, 80, 8), 4);
+        assert_eq!(heatmap_cell_width(128, 80, 5), 3);
+        assert_eq!(heatmap_cell_width(128, 80, 4), 2);
+        assert_eq!(heatmap_cell_width(128, 40, 2), 1);
+
+        assert_eq!(CpuChart::Heatmap.next(), CpuChart::Usage);
+    }
+}
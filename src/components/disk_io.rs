@@ -0,0 +1,415 @@
+use std::cmp::{max, min};
+use std::collections::BTreeMap;
+
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use ratatui::layout::Position;
+
+use crate::components::Refreshable;
+use crate::components::utils::selection::UISelection;
+use crate::components::utils::history_chart::{series, HistoryChart};
+use crate::input::MouseKind;
+use crate::services::VecProvider;
+use crate::models::bounded_queue_model::BoundedQueueModel;
+use crate::models::items::disk_io_item::DiskIoItem;
+use crate::models::units::{format_value, UnitSystem};
+use crate::config::Config;
+use crate::components::*;
+
+// utilization from which a device is considered saturated
+const SATURATED_UTILIZATION: f32 = 90_f32;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Focus {
+    Chart,
+    DeviceList,
+}
+
+// the metric charted, cycled through with enter
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiskIoChart {
+    Throughput,
+    Iops,
+    Utilization,
+}
+
+impl DiskIoChart {
+    fn next(self) -> Self {
+        match self {
+            Self::Throughput => Self::Iops,
+            Self::Iops => Self::Utilization,
+            Self::Utilization => Self::Throughput,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Throughput => "throughput",
+            Self::Iops => "IOPS",
+            Self::Utilization => "utilization",
+        }
+    }
+}
+
+// DiskIoComponent charts the i/o of the block devices from /proc/diskstats: read/write
+// throughput, IOPS and utilization (the percent of time the device was busy, like iostat %util).
+// A device near 100% utilization with requests in flight is the bottleneck of an i/o bound box.
+// A side list selects the device, like the interface list of NetworkComponent, and devices
+// that disappear are dropped along with their history.
+pub struct DiskIoComponent {
+    config: Config,
+    devices: BTreeMap<String, BoundedQueueModel<DiskIoItem>>,
+    selection_state: UISelection,
+    data_window_time_scale: u64,
+    chart: DiskIoChart,
+    chart_area: Option<Rect>,
+    list_area: Option<Rect>,
+    focus: Focus,
+}
+
+impl DiskIoComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<DiskIoItem>
+    {
+        let data_window_time_scale = config.min_time_scale();
+
+        let mut component = Self {
+            config,
+            devices: BTreeMap::new(),
+            selection_state: UISelection::new(None),
+            data_window_time_scale,
+            chart: DiskIoChart::Throughput,
+            chart_area: None,
+            list_area: None,
+            focus: Focus::DeviceList,
+        };
+        component.refresh(service);
+        component
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    fn selected_device(&self) -> Option<&str> {
+        self.devices
+            .keys()
+            .nth(self.selection_state.selection?)
+            .map(|device| device.as_str())
+    }
+
+    fn selected_queue(&self) -> Option<&BoundedQueueModel<DiskIoItem>> {
+        self.devices.get(self.selected_device()?)
+    }
+
+    fn handle_move_selection(&mut self, dir: MoveSelection) {
+        let len = self.devices.len();
+        self.selection_state.move_selection(dir, len);
+    }
+
+    fn handle_time_scale(&mut self, dir: MoveSelection) {
+        self.data_window_time_scale = match dir {
+            MoveSelection::Down => min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale()),
+            _ => max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale()),
+        };
+    }
+
+    fn handle_mouse_click(&mut self, click_x: u16, click_y: u16) -> bool {
+        let position = Position { x: click_x, y: click_y };
+
+        if self.chart_area.is_some_and(|area| area.contains(position)) {
+            self.focus = Focus::Chart;
+            return true
+        }
+        if self.list_area.is_some_and(|area| area.contains(position)) {
+            self.focus = Focus::DeviceList;
+            return true
+        }
+
+        false
+    }
+}
+
+impl<S> Refreshable<S> for DiskIoComponent
+where
+    S: VecProvider<DiskIoItem>
+{
+    fn refresh(&mut self, service: &S) {
+        let capacity = ( self.config.max_time_scale() / self.config.refresh_rate() ) as usize;
+        let selected = self.selected_device().map(String::from);
+
+        let disks: Vec<DiskIoItem> = service.fetch_items();
+        self.devices.retain(|device, _| disks.iter().any(|disk| disk.device() == device));
+        for disk in disks {
+            self.devices
+                .entry(disk.device().to_string())
+                .or_insert_with(|| BoundedQueueModel::new(capacity))
+                .add_item(disk);
+        }
+
+        // keeping the selected device selected as devices come and go, the first one if it is gone
+        let selection = selected
+            .and_then(|selected| self.devices.keys().position(|device| *device == selected))
+            .or(if self.devices.is_empty() { None } else { Some(0) });
+        self.selection_state.set_selection(selection);
+    }
+}
+
+impl Component for DiskIoComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        let key_config = &self.config.key_config;
+
+        match self.focus {
+            Focus::DeviceList => {
+                if let Some(dir) = common_nav(key, key_config) {
+                    self.handle_move_selection(dir);
+                    return Ok(EventState::Consumed)
+                }
+            }
+            Focus::Chart => {
+                if key == key_config.move_down {
+                    self.handle_time_scale(MoveSelection::Down);
+                    return Ok(EventState::Consumed)
+                }
+                if key == key_config.move_up {
+                    self.handle_time_scale(MoveSelection::Up);
+                    return Ok(EventState::Consumed)
+                }
+            }
+        }
+
+        if key == key_config.enter {
+            self.chart = self.chart.next();
+            return Ok(EventState::Consumed)
+        }
+
+        // key event to move focus
+        if key == key_config.filter {
+            match self.focus {
+                Focus::DeviceList => { self.focus = Focus::Chart }
+                Focus::Chart => { self.focus = Focus::DeviceList }
+            }
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match (self.focus, mouse.kind) {
+            (Focus::DeviceList, MouseKind::ScrollDown) => {
+                self.handle_move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::DeviceList, MouseKind::ScrollUp) => {
+                self.handle_move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::Chart, MouseKind::ScrollDown) => {
+                self.handle_time_scale(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::Chart, MouseKind::ScrollUp) => {
+                self.handle_time_scale(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (_, MouseKind::LeftClick) if self.handle_mouse_click(mouse.column, mouse.row) => {
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for DiskIoComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let horizontal_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Fill(1),                    // legend and chart
+                Constraint::Length(14),                 // device list
+            ]).split(area);
+
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(4),
+                Constraint::Min(0),
+            ]).split(horizontal_chunks[0]);
+
+        // saving chart and list area for mouse clicks
+        self.chart_area = Some(horizontal_chunks[0]);
+        self.list_area = Some(horizontal_chunks[1]);
+
+        let refresh_rate = self.config.refresh_rate();              // default = 2,000 ms
+        let time_scale = self.data_window_time_scale;               // default = 60,000 ms
+        let data_window = (time_scale / refresh_rate) as usize;     // default = 30
+        let unit_system = self.config.unit_system();
+
+        // set block style
+        let (chart_style, list_style) = match (focused, self.focus) {
+            (true, Focus::Chart) => (self.config.theme_config.style_border_focused, self.config.theme_config.style_border_not_focused),
+            (true, Focus::DeviceList) => (self.config.theme_config.style_border_not_focused, self.config.theme_config.style_border_focused),
+            _ => (self.config.theme_config.style_border_not_focused, self.config.theme_config.style_border_not_focused),
+        };
+
+        let default_queue = BoundedQueueModel::new(0);
+        let queue = self.selected_queue().unwrap_or(&default_queue);
+
+        let chart = match self.chart {
+            DiskIoChart::Throughput => HistoryChart {
+                series: vec![
+                    (series(queue, data_window, |item| item.read_bytes_per_s()), Color::LightGreen),
+                    (series(queue, data_window, |item| item.written_bytes_per_s()), Color::LightMagenta),
+                ],
+                unit_system,
+                unit: String::from("B/s"),
+                data_window,
+                time_scale,
+                upper: None,
+            },
+            DiskIoChart::Iops => HistoryChart {
+                series: vec![
+                    (series(queue, data_window, |item| item.read_iops()), Color::LightGreen),
+                    (series(queue, data_window, |item| item.write_iops()), Color::LightMagenta),
+                ],
+                unit_system: UnitSystem::Si,
+                unit: String::from("IOPS"),
+                data_window,
+                time_scale,
+                upper: None,
+            },
+            DiskIoChart::Utilization => HistoryChart {
+                series: vec![
+                    (series(queue, data_window, |item| item.utilization() as f64), Color::LightRed),
+                ],
+                unit_system: UnitSystem::Si,
+                unit: String::from("%"),
+                data_window,
+                time_scale,
+                upper: Some(100_f64),
+            },
+        };
+        chart.draw(f, vertical_chunks[1], Borders::LEFT|Borders::BOTTOM|Borders::RIGHT, chart_style);
+
+        // building legend
+        let default_item = DiskIoItem::default();
+        let disk = queue.back().unwrap_or(&default_item);
+
+        let throughput_legend = vec![
+            Span::styled(format!("R {}", format_value(disk.read_bytes_per_s(), unit_system, "B/s")), Style::default().fg(Color::LightGreen)),
+            Span::raw("  "),
+            Span::styled(format!("W {}", format_value(disk.written_bytes_per_s(), unit_system, "B/s")), Style::default().fg(Color::LightMagenta)),
+        ];
+        let iops_legend = format!("IOPS R {:.0} W {:.0}", disk.read_iops(), disk.write_iops());
+        let utilization_legend = format!("UTIL {:.0}% :: IN FLIGHT {}", disk.utilization(), disk.in_flight());
+
+        // saturated devices are highlighted
+        let utilization_style = if disk.utilization() >= SATURATED_UTILIZATION {
+            Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)
+        }
+        else {
+            Style::default()
+        };
+
+        let legend = Paragraph::new(vec![
+            Line::from(throughput_legend).right_aligned(),
+            Line::from(iops_legend).right_aligned(),
+            Line::from(Span::styled(utilization_legend, utilization_style)).right_aligned(),
+        ])
+            .block(Block::new()
+                .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
+                .style(chart_style)
+                .title(format!(" Disk I/O ({}) :: {} ", self.selected_device().unwrap_or("-"), self.chart.title()))
+            );
+
+        f.render_widget(legend, vertical_chunks[0]);
+
+        // render device list, saturated devices are highlighted
+        let names: Vec<ListItem> = self.devices
+            .iter()
+            .enumerate()
+            .map(|(idx, (device, queue))| {
+                let style = if queue.back().is_some_and(|item| item.utilization() >= SATURATED_UTILIZATION) {
+                    Style::default().fg(Color::LightRed)
+                }
+                else {
+                    Style::default()
+                };
+
+                if Some(idx) == self.selection_state.selection {
+                    ListItem::new(format!("-> {}", device)).style(style)
+                }
+                else {
+                    ListItem::new(format!("   {}", device)).style(style)
+                }
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        list_state.select(self.selection_state.selection);
+
+        let device_list = List::new(names)
+            .scroll_padding(horizontal_chunks[1].height as usize / 2)
+            .block(Block::default().borders(Borders::ALL).style(list_style));
+
+        f.render_stateful_widget(device_list, horizontal_chunks[1], &mut list_state);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService {
+        devices: Vec<&'static str>,
+    }
+
+    impl VecProvider<DiskIoItem> for DummyService {
+        fn fetch_items(&self) -> Vec<DiskIoItem> {
+            self.devices
+                .iter()
+                .map(|device| DiskIoItem::new(device.to_string(), 1).with_utilization(42_f32))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_device_selection() {
+        let config = Config::default();
+        let mut component = DiskIoComponent::new(config.clone(), &DummyService { devices: vec!["nvme0n1", "sda"] });
+        assert_eq!(component.selected_device(), Some("nvme0n1"));
+
+        component.key_event(config.key_config.move_bottom).unwrap();
+        assert_eq!(component.selected_device(), Some("sda"));
+
+        // sda stays selected as dm-0 appears before it
+        component.refresh(&DummyService { devices: vec!["dm-0", "nvme0n1", "sda"] });
+        assert_eq!(component.selected_device(), Some("sda"));
+        assert_eq!(component.selected_queue().map(|queue| queue.items().len()), Some(2));
+
+        // the first device is selected once the selected device disappears
+        component.refresh(&DummyService { devices: vec!["dm-0", "nvme0n1"] });
+        assert_eq!(component.selected_device(), Some("dm-0"));
+
+        component.refresh(&DummyService { devices: vec![] });
+        assert_eq!(component.selected_device(), None);
+    }
+
+    #[test]
+    fn test_chart_cycle() {
+        let config = Config::default();
+        let mut component = DiskIoComponent::new(config.clone(), &DummyService { devices: vec!["sda"] });
+        assert_eq!(component.chart, DiskIoChart::Throughput);
+
+        component.key_event(config.key_config.enter).unwrap();
+        assert_eq!(component.chart, DiskIoChart::Iops);
+        component.key_event(config.key_config.enter).unwrap();
+        component.key_event(config.key_config.enter).unwrap();
+        assert_eq!(component.chart, DiskIoChart::Throughput);
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::config::*;
+use crate::input::{Key, Mouse, MouseKind};
+use crate::services::VecProvider;
+use crate::components::utils::sortable_table::{SortableColumn, SortableTable};
+use crate::components::*;
+use crate::models::units::{format_bytes, UnitSystem};
+use crate::models::items::disk_item::{DiskItem, DiskItemSortOrder};
+
+// columns of the disk list
+const DISK_COLUMNS: [SortableColumn<DiskItemSortOrder>; 9] = [
+    SortableColumn::sorted("MOUNT(n)", DiskItemSortOrder::MountInc, DiskItemSortOrder::MountDec, 24),
+    SortableColumn::unsorted("DEVICE", 18),
+    SortableColumn::unsorted("TYPE", 8),
+    SortableColumn::sorted("SIZE", DiskItemSortOrder::SizeInc, DiskItemSortOrder::SizeDec, 10),
+    SortableColumn::unsorted("USED", 10),
+    SortableColumn::sorted("AVAIL", DiskItemSortOrder::AvailableInc, DiskItemSortOrder::AvailableDec, 10),
+    SortableColumn::sorted("USE(%)(m)", DiskItemSortOrder::UsageInc, DiskItemSortOrder::UsageDec, 11),
+    SortableColumn::unsorted("", USAGE_BAR_WIDTH),
+    SortableColumn::sorted("INODES(%)", DiskItemSortOrder::InodeUsageInc, DiskItemSortOrder::InodeUsageDec, 11),
+];
+
+const USAGE_BAR_WIDTH: u16 = 12;
+
+// DiskComponent lists the mounted filesystems with their space and inode usage, like `df -h`
+// and `df -i` combined. Mounts where either the space or the inodes used reach the configured
+// threshold (90% by default) are flagged red, as a full filesystem (e.g. /var) stops services
+// from writing logs or state.
+pub struct DiskComponent {
+    table: SortableTable<DiskItem, DiskItemSortOrder>,
+    pub config: Config,
+}
+
+impl DiskComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<DiskItem>
+    {
+        Self {
+            table: SortableTable::new(&DISK_COLUMNS, service.fetch_items(), DiskItemSortOrder::UsageDec),
+            config,
+        }
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    // maps a sort key to its column, returns true if key is a sort key
+    fn handle_sort(&mut self, key: Key) -> bool {
+        let key_config = &self.config.key_config;
+
+        let column = if key == key_config.sort_name_toggle {
+            0
+        }
+        else if key == key_config.sort_memory_toggle {
+            6
+        }
+        else {
+            return false;
+        };
+
+        self.table.sort_column(column);
+        true
+    }
+}
+
+impl<S> Refreshable<S> for DiskComponent
+where
+    S: VecProvider<DiskItem>
+{
+    fn refresh(&mut self, service: &S) {
+        self.table.replace(service.fetch_items(), |disk| disk.mount_point().to_string());
+    }
+}
+
+impl Component for DiskComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if let Some(move_dir) = common_nav(key, &self.config.key_config) {
+            self.table.move_selection(move_dir);
+            return Ok(EventState::Consumed)
+        }
+
+        if self.handle_sort(key) {
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match mouse.kind {
+            MouseKind::ScrollDown => {
+                self.table.move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::ScrollUp => {
+                self.table.move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::LeftClick if self.table.handle_mouse_click(mouse.column, mouse.row) => {
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for DiskComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let unit_system = self.config.unit_system();
+        let threshold = self.config.disk_usage_threshold();
+
+        let len = self.table.row_count();
+        let full = self.table
+            .vec_state()
+            .list()
+            .iter()
+            .filter(|disk| disk.is_above(threshold))
+            .count();
+
+        let title = if full == 0 {
+            format!(" Disks ({}) ", len)
+        }
+        else {
+            format!(" Disks ({}, {} above {:.0}%) ", len, full, threshold)
+        };
+
+        self.table.draw(f, area, focused, title, &self.config.theme_config, |disk, style| {
+            // flagging mounts running out of space or inodes
+            let style = if disk.is_above(threshold) {
+                style.fg(Color::Red).add_modifier(Modifier::BOLD)
+            }
+            else {
+                style
+            };
+
+            let cells = vec![
+                Cell::from(disk.mount_point().to_string()),
+                Cell::from(disk.device().to_string()),
+                Cell::from(disk.fs_type().to_string()),
+                Cell::from(format_bytes(disk.total_space(), unit_system)),
+                Cell::from(format_bytes(disk.used_space(), unit_system)),
+                Cell::from(format_bytes(disk.available_space(), unit_system)),
+                Cell::from(format!("{:.1}", disk.usage())),
+                Cell::from(usage_bar(disk.usage(), USAGE_BAR_WIDTH as usize)),
+                Cell::from(disk.inode_usage().map_or_else(|| String::from("-"), |usage| format!("{:.1}", usage))),
+            ];
+            (cells, style)
+        })
+    }
+}
+
+// helper function for drawing a usage in percent as a bar, e.g. "█████░░░░░"
+fn usage_bar(usage: f32, width: usize) -> String {
+    let filled = ((usage.clamp(0_f32, 100_f32) / 100_f32) * width as f32).round() as usize;
+
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService;
+
+    impl VecProvider<DiskItem> for DummyService {
+        fn fetch_items(&self) -> Vec<DiskItem> {
+            vec![
+                DiskItem::new(String::from("/"), String::from("/dev/sda2"), String::from("ext4"), 1000, 600)
+                    .with_inodes(100, 90),
+                DiskItem::new(String::from("/var"), String::from("/dev/sda3"), String::from("ext4"), 2000, 100)
+                    .with_inodes(100, 80),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let config = Config::default();
+        let mut component = DiskComponent::new(config.clone(), &DummyService);
+        let mounts = |component: &DiskComponent| -> Vec<String> {
+            component.table.vec_state().iter_with_selection().map(|(_, disk, _)| disk.mount_point().to_string()).collect()
+        };
+
+        // usage decreasing
+        assert_eq!(mounts(&component), vec!["/var", "/"]);
+
+        component.key_event(config.key_config.sort_name_toggle).unwrap();
+        assert_eq!(mounts(&component), vec!["/var", "/"]);
+        component.key_event(config.key_config.sort_name_toggle).unwrap();
+        assert_eq!(mounts(&component), vec!["/", "/var"]);
+
+        // columns without sort orders are ignored
+        component.table.sort_column(1);
+        assert_eq!(component.table.sort(), DiskItemSortOrder::MountInc);
+    }
+
+    #[test]
+    fn test_usage_bar() {
+        assert_eq!(usage_bar(0_f32, 4), "░░░░");
+        assert_eq!(usage_bar(50_f32, 4), "██░░");
+        assert_eq!(usage_bar(120_f32, 4), "████");
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::config::*;
+use crate::input::{Key, Mouse, MouseKind};
+use crate::services::VecProvider;
+use crate::components::utils::sortable_table::{SortableColumn, SortableTable};
+use crate::components::*;
+use crate::models::units::{format_bytes, UnitSystem};
+use crate::models::items::limit_item::{LimitItem, LimitItemSortOrder};
+
+// columns of the limit list
+const LIMIT_COLUMNS: [SortableColumn<LimitItemSortOrder>; 12] = [
+    SortableColumn::sorted("CGROUP(n)", LimitItemSortOrder::NameInc, LimitItemSortOrder::NameDec, 30),
+    SortableColumn::unsorted("CPU(%)", 8),
+    SortableColumn::unsorted("QUOTA(%)", 9),
+    SortableColumn::sorted("%QUOTA(c)", LimitItemSortOrder::CpuQuotaUsageInc, LimitItemSortOrder::CpuQuotaUsageDec, 11),
+    SortableColumn::sorted("THROTTLED(%)", LimitItemSortOrder::ThrottledInc, LimitItemSortOrder::ThrottledDec, 13),
+    SortableColumn::unsorted("THROTTLED(ms/s)", 16),
+    SortableColumn::unsorted("MEM", 9),
+    SortableColumn::unsorted("LIMIT", 10),
+    SortableColumn::sorted("%LIMIT(m)", LimitItemSortOrder::MemoryLimitUsageInc, LimitItemSortOrder::MemoryLimitUsageDec, 11),
+    SortableColumn::unsorted("OOMS", 6),
+    SortableColumn::sorted("OOM KILLS", LimitItemSortOrder::OomKillsInc, LimitItemSortOrder::OomKillsDec, 11),
+    SortableColumn::unsorted("STATUS", 20),
+];
+
+// LimitComponent lists the cgroups that have a cpu quota or memory limit (or had processes oom
+// killed) with their usage in percent of the limits. This shows containers that are starved by
+// their quota while host cpu usage looks idle. Rows are flagged yellow when the cgroup was
+// throttled and red when processes were oom killed since the previous refresh.
+pub struct LimitComponent {
+    table: SortableTable<LimitItem, LimitItemSortOrder>,
+    drill_in: Option<LimitItem>,                // cgroup to show the member processes of, taken by App
+    pub config: Config,
+}
+
+impl LimitComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<LimitItem>
+    {
+        Self {
+            table: SortableTable::new(&LIMIT_COLUMNS, service.fetch_items(), LimitItemSortOrder::CpuQuotaUsageDec),
+            drill_in: None,
+            config,
+        }
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    pub fn take_drill_in(&mut self) -> Option<LimitItem> {
+        self.drill_in.take()
+    }
+
+    // maps a sort key to its column, returns true if key is a sort key
+    fn handle_sort(&mut self, key: Key) -> bool {
+        let key_config = &self.config.key_config;
+
+        let column = if key == key_config.sort_name_toggle {
+            0
+        }
+        else if key == key_config.sort_cpu_toggle {
+            3
+        }
+        else if key == key_config.sort_memory_toggle {
+            8
+        }
+        else {
+            return false;
+        };
+
+        self.table.sort_column(column);
+        true
+    }
+}
+
+impl<S> Refreshable<S> for LimitComponent
+where
+    S: VecProvider<LimitItem>
+{
+    fn refresh(&mut self, service: &S) {
+        self.table.replace(service.fetch_items(), |limit| limit.cgroup().to_string());
+    }
+}
+
+impl Component for LimitComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if let Some(move_dir) = common_nav(key, &self.config.key_config) {
+            self.table.move_selection(move_dir);
+            return Ok(EventState::Consumed)
+        }
+
+        if self.handle_sort(key) {
+            return Ok(EventState::Consumed)
+        }
+
+        if key == self.config.key_config.enter {
+            self.drill_in = self.table.selected().cloned();
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match mouse.kind {
+            MouseKind::ScrollDown => {
+                self.table.move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::ScrollUp => {
+                self.table.move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::LeftClick if self.table.handle_mouse_click(mouse.column, mouse.row) => {
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for LimitComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let len = self.table.row_count();
+        let title = if len == 0 {
+            String::from(" Limits (no limited cgroups) ")
+        }
+        else {
+            format!(" Limits ({}) ", len)
+        };
+
+        let unit_system = self.config.unit_system();
+
+        self.table.draw(f, area, focused, title, &self.config.theme_config, |limit, style| {
+            // flagging throttled and oom killed cgroups
+            let style = if limit.recent_ooms() > 0 || limit.recent_oom_kills() > 0 {
+                style.fg(Color::Red).add_modifier(Modifier::BOLD)
+            }
+            else if limit.is_throttled() {
+                style.fg(Color::Yellow)
+            }
+            else {
+                style
+            };
+
+            let cells = vec![
+                Cell::from(limit.name().to_string()),
+                Cell::from(format_or_na(limit.cpu_usage(), |cpu_usage| format!("{:.1}", cpu_usage))),
+                Cell::from(format_or_na(limit.cpu_quota(), |quota| format!("{:.0}", quota))),
+                Cell::from(format_or_na(limit.cpu_quota_usage(), |usage| format!("{:.1}", usage))),
+                Cell::from(format_or_na(limit.throttled_percent(), |throttled| format!("{:.1}", throttled))),
+                Cell::from(format_or_na(limit.throttled_ms_per_s(), |throttled| format!("{:.1}", throttled))),
+                Cell::from(format_or_na(limit.memory_usage(), |memory| format_bytes(memory, unit_system))),
+                Cell::from(format_or_na(limit.memory_max().or(limit.memory_high()), |limit| format_bytes(limit, unit_system))),
+                Cell::from(format_or_na(limit.memory_limit_usage(), |usage| format!("{:.1}", usage))),
+                Cell::from(limit.ooms().to_string()),
+                Cell::from(limit.oom_kills().to_string()),
+                Cell::from(status(limit)),
+            ];
+            (cells, style)
+        })
+    }
+}
+
+fn format_or_na<T>(value: Option<T>, format: impl Fn(T) -> String) -> String {
+    value.map_or_else(|| String::from("-"), format)
+}
+
+// helper function for describing why a cgroup is flagged
+fn status(limit: &LimitItem) -> String {
+    let mut flags: Vec<String> = Vec::new();
+
+    if limit.is_throttled() {
+        flags.push(String::from("throttled"));
+    }
+    if limit.recent_oom_kills() > 0 {
+        flags.push(format!("oom killed {}", limit.recent_oom_kills()));
+    }
+    else if limit.recent_ooms() > 0 {
+        flags.push(format!("out of memory {}", limit.recent_ooms()));
+    }
+
+    flags.join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService;
+
+    impl VecProvider<LimitItem> for DummyService {
+        fn fetch_items(&self) -> Vec<LimitItem> {
+            vec![
+                LimitItem::new(String::from("docker:4f1c2a9b8e7d"), String::from("/system.slice/docker-4f1c2a9b8e7d.scope"))
+                    .with_cpu(Some(50_f32), Some(50_f32), Some(30_f32))
+                    .with_memory(Some(100), Some(1000), None),
+                LimitItem::new(String::from("batch.service"), String::from("/system.slice/batch.service"))
+                    .with_cpu(Some(10_f32), Some(200_f32), Some(0_f32))
+                    .with_memory(Some(900), Some(1000), None)
+                    .with_ooms(4, 1)
+                    .with_oom_kills(3, 1),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let config = Config::default();
+        let mut component = LimitComponent::new(config.clone(), &DummyService);
+        let names = |component: &LimitComponent| -> Vec<String> {
+            component.table.vec_state().iter_with_selection().map(|(_, limit, _)| limit.name().to_string()).collect()
+        };
+
+        // cpu quota usage decreasing
+        assert_eq!(names(&component), vec!["docker:4f1c2a9b8e7d", "batch.service"]);
+
+        component.key_event(config.key_config.sort_memory_toggle).unwrap();
+        assert_eq!(names(&component), vec!["batch.service", "docker:4f1c2a9b8e7d"]);
+
+        // columns without sort orders are ignored
+        component.table.sort_column(1);
+        assert_eq!(component.table.sort(), LimitItemSortOrder::MemoryLimitUsageDec);
+    }
+
+    #[test]
+    fn test_status() {
+        let limits = DummyService.fetch_items();
+
+        assert_eq!(status(&limits[0]), "throttled");
+        assert_eq!(status(&limits[1]), "oom killed 1");
+
+        // ran out of memory without a process being killed, e.g. reclaim succeeded on retry
+        let limit = limits[1].clone().with_oom_kills(3, 0);
+        assert_eq!(status(&limit), "out of memory 1");
+    }
+}
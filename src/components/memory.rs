@@ -3,7 +3,9 @@ use anyhow::{Ok, Result};
 use ratatui::{Frame, prelude::*, widgets::*};
 use crate::{components::Refreshable, input::*, services::ItemProvider, states::bounded_queue_state::BoundedQueueState};
 use crate::components::DrawableComponent;
-use crate::models::items::memory_item::MemoryItem;
+use crate::models::items::memory_item::{MemoryBreakdown, MemoryItem};
+use crate::models::units::{format_bytes, format_data_rate, format_value, DataUnit, UnitSystem};
+use crate::components::utils::history_chart::HistoryChart;
 use crate::config::Config;
 use super::Component;
 use super::EventState;
@@ -35,6 +37,10 @@ impl MemoryComponent {
             data_window_time_scale,
         }
     }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
 }
 
 impl<S> Refreshable<S> for MemoryComponent
@@ -79,15 +85,120 @@ impl Component for MemoryComponent {
     }
 }
 
+impl MemoryComponent {
+    // stacked bar of where the memory goes, apps then kernel caches then free, filling the width
+    fn breakdown_bar(memory_item: &MemoryItem, breakdown: &MemoryBreakdown, width: usize) -> Line<'static> {
+        let segments = [
+            (memory_item.app_memory(), Color::LightRed),
+            (breakdown.buffers, Color::LightBlue),
+            (breakdown.cached, Color::LightYellow),
+            (breakdown.sreclaimable, Color::Cyan),
+            (memory_item.free_memory(), Color::DarkGray),
+        ];
+        let values: Vec<u64> = segments.iter().map(|(value, _)| *value).collect();
+
+        let spans: Vec<Span> = segment_widths(&values, width)
+            .into_iter()
+            .zip(segments)
+            .map(|(cells, (_, color))| Span::styled("\u{2588}".repeat(cells), Style::default().fg(color)))
+            .collect();
+
+        Line::from(spans)
+    }
+
+    // swap and page fault rates, swapping in and out at once is flagged as thrashing
+    fn activity_legend(memory_item: &MemoryItem, unit_system: UnitSystem, thrashing_threshold: u64) -> Line<'static> {
+        let activity = memory_item.activity();
+        let mut spans = Vec::new();
+
+        if activity.is_thrashing(thrashing_threshold as f64) {
+            spans.push(Span::styled(" THRASHING", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)));
+        }
+        spans.push(Span::styled(
+            format!(" SWAP IN {}", format_data_rate(activity.swap_in_bytes_per_s, unit_system, DataUnit::Bytes)),
+            Style::default().fg(Color::LightCyan),
+        ));
+        spans.push(Span::styled(
+            format!(" OUT {}", format_data_rate(activity.swap_out_bytes_per_s, unit_system, DataUnit::Bytes)),
+            Style::default().fg(Color::Magenta),
+        ));
+        // counts always use decimal prefixes
+        spans.push(Span::raw(format!(
+            " :: {}faults/s ({} major)",
+            format_value(activity.faults_per_s.round(), UnitSystem::Si, ""),
+            format_value(activity.major_faults_per_s.round(), UnitSystem::Si, "").trim_end(),
+        )));
+
+        Line::from(spans).right_aligned()
+    }
+
+    fn breakdown_legend(memory_item: &MemoryItem, breakdown: &MemoryBreakdown, unit_system: UnitSystem) -> Line<'static> {
+        let entry = |name: &str, bytes: u64, color: Color| {
+            Span::styled(format!(" {} {}", name, format_bytes(bytes, unit_system)), Style::default().fg(color))
+        };
+
+        Line::from(vec![
+            entry("apps", memory_item.app_memory(), Color::LightRed),
+            entry("buf", breakdown.buffers, Color::LightBlue),
+            entry("cache", breakdown.cached, Color::LightYellow),
+            entry("slab", breakdown.sreclaimable, Color::Cyan),
+            entry("avail", memory_item.available_memory(), Color::LightGreen),
+            entry("shmem", breakdown.shared, Color::DarkGray),
+            entry("dirty", breakdown.dirty, Color::DarkGray),
+            entry("wb", breakdown.writeback, Color::DarkGray),
+        ])
+    }
+}
+
+// splits width into cells proportional to values, rounding at the running total so the
+// cells always add up to width
+fn segment_widths(values: &[u64], width: usize) -> Vec<usize> {
+    let total: u64 = values.iter().sum();
+    if total == 0 {
+        return vec![0; values.len()];
+    }
+
+    let mut sum = 0;
+    let mut prev_boundary = 0;
+    values
+        .iter()
+        .map(|value| {
+            sum += value;
+            let boundary = (sum as f64 / total as f64 * width as f64).round() as usize;
+            let cells = boundary - prev_boundary;
+            prev_boundary = boundary;
+            cells
+        })
+        .collect()
+}
+
 impl DrawableComponent for MemoryComponent {
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let memory_item = if let Some(item) = self.queue_state.back() {
+            item.clone()
+        }
+        else {
+            MemoryItem::default()
+        };
+        let breakdown = memory_item.breakdown().copied();
+
+        // legend, plus the breakdown bar and its legend when /proc/meminfo is available
+        let legend_height = if breakdown.is_some() { 5 } else { 3 };
         let vertical_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2),
+                Constraint::Length(legend_height),
                 Constraint::Min(0),
             ]).split(area);
 
+        // usage in percent and swap activity in bytes/s
+        let chart_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(60),
+                Constraint::Percentage(40),
+            ]).split(vertical_chunks[1]);
+
         let refresh_rate = self.config.refresh_rate();              // default = 2,000 ms
         let time_scale = self.data_window_time_scale;               // default = 60,000 ms
         let data_window = (time_scale / refresh_rate) as usize;     // default = 30
@@ -95,45 +206,58 @@ impl DrawableComponent for MemoryComponent {
 
 
         // building data sets
-        let ram_percent_usage_data: Vec<(f64, f64)> = self.queue_state
-            .iter()
-            .rev()
-            .take(data_window)
-            .enumerate()
-            .map(|(idx, memory_item)| {
-                (
-                    max_idx.saturating_sub(idx) as f64, 
-                    memory_item.percent_memory_usage(),
-                )
-            })
-            .collect();
+        let history = |value: fn(&MemoryItem) -> f64| -> Vec<(f64, f64)> {
+            self.queue_state
+                .iter()
+                .rev()
+                .take(data_window)
+                .enumerate()
+                .map(|(idx, memory_item)| {
+                    (
+                        max_idx.saturating_sub(idx) as f64,
+                        value(memory_item),
+                    )
+                })
+                .collect()
+        };
 
-        let swap_percent_usage_data: Vec<(f64, f64)> = self.queue_state
-            .iter()
-            .rev()
-            .take(data_window)
-            .enumerate()
-            .map(|(idx, memory_item)| {
-                (
-                    max_idx.saturating_sub(idx) as f64, 
-                    memory_item.percent_swap_usage(),
-                )
-            })
-            .collect();
+        let ram_percent_usage_data = history(MemoryItem::percent_memory_usage);
+        let swap_percent_usage_data = history(MemoryItem::percent_swap_usage);
+        let available_percent_data = history(MemoryItem::percent_available);
+        let cache_percent_data = history(MemoryItem::percent_cache);
 
-        let datasets = vec![
+        let mut datasets = vec![
+            Dataset::default()
+                .data(&available_percent_data)
+                .graph_type(GraphType::Line)
+                .marker(symbols::Marker::Braille)
+                .style(Style::new().light_green()),
+        ];
+
+        if breakdown.is_some() {
+            datasets.push(
+                Dataset::default()
+                    .data(&cache_percent_data)
+                    .graph_type(GraphType::Line)
+                    .marker(symbols::Marker::Braille)
+                    .style(Style::new().light_yellow())
+            );
+        }
+
+        datasets.push(
             Dataset::default()
                 .data(&ram_percent_usage_data)
                 .graph_type(GraphType::Line)
                 .marker(symbols::Marker::Braille)
-                .style(Style::new().light_red()),
-            
+                .style(Style::new().light_red())
+        );
+        datasets.push(
             Dataset::default()
                 .data(&swap_percent_usage_data)
                 .graph_type(GraphType::Line)
                 .marker(symbols::Marker::Braille)
-                .style(Style::new().light_magenta()),
-        ];
+                .style(Style::new().light_magenta())
+        );
 
         // set block style
         let block_style = if focused {
@@ -161,40 +285,58 @@ impl DrawableComponent for MemoryComponent {
         let chart_title = format!(" Memory ");
         let chart = Chart::new(datasets)
             .block(Block::default()
-                .borders(Borders::LEFT|Borders::BOTTOM|Borders::RIGHT)
+                .borders(Borders::LEFT|Borders::BOTTOM)
                 .style(block_style)
         )
         .x_axis(x_axis)
         .y_axis(y_axis);
 
-        f.render_widget(chart, vertical_chunks[1]);
+        f.render_widget(chart, chart_chunks[0]);
+
+        let swap_chart = HistoryChart {
+            series: vec![
+                (history(|memory_item| memory_item.activity().swap_in_bytes_per_s), Color::LightCyan),
+                (history(|memory_item| memory_item.activity().swap_out_bytes_per_s), Color::Magenta),
+            ],
+            unit_system: self.config.unit_system(),
+            unit: String::from("B/s"),
+            data_window,
+            time_scale,
+            upper: None,
+        };
+        swap_chart.draw(f, chart_chunks[1], Borders::BOTTOM|Borders::RIGHT, block_style);
 
 
         // building legend
-        let memory_item = if let Some(item) = self.queue_state.back() {
-            item
-        }
-        else {
-            &MemoryItem::default()
-        };
-        let ram_legend = format!(" RAM :: {:.0}/{:.0}GB :: {:.0}%",
-            memory_item.used_memory_gb(),
-            memory_item.total_memory_gb(),
+        let unit_system = self.config.unit_system();
+        let ram_legend = format!(" RAM :: {}/{} :: {:.0}%",
+            format_bytes(memory_item.used_memory(), unit_system),
+            format_bytes(memory_item.total_memory(), unit_system),
             memory_item.percent_memory_usage(),
         );
-        let swap_legend = format!(" SWAP :: {:.0}/{:.0}GB :: {:.0}%",
-            memory_item.used_swap_gb(),
-            memory_item.total_swap_gb(),
+        let swap_legend = format!(" SWAP :: {}/{} :: {:.0}%",
+            format_bytes(memory_item.used_swap(), unit_system),
+            format_bytes(memory_item.total_swap(), unit_system),
             memory_item.percent_swap_usage(),
         );
 
-        let legend = Paragraph::new(
+        let mut lines = vec![
             Line::from(vec![
                 Span::styled(ram_legend, Style::default().fg(Color::LightRed)),
                 Span::raw("  "),
                 Span::styled(swap_legend, Style::default().fg(Color::LightMagenta)),
             ])
-            .right_aligned())
+            .right_aligned(),
+            Self::activity_legend(&memory_item, unit_system, self.config.swap_thrashing_threshold()),
+        ];
+
+        if let Some(breakdown) = breakdown {
+            let bar_width = vertical_chunks[0].width.saturating_sub(2) as usize;
+            lines.push(Self::breakdown_bar(&memory_item, &breakdown, bar_width));
+            lines.push(Self::breakdown_legend(&memory_item, &breakdown, unit_system));
+        }
+
+        let legend = Paragraph::new(lines)
             .block(Block::new()
                 .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
                 .style(block_style)
@@ -205,4 +347,20 @@ impl DrawableComponent for MemoryComponent {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::segment_widths;
+
+    #[test]
+    fn test_segment_widths() {
+        assert_eq!(segment_widths(&[50, 25, 25], 8), vec![4, 2, 2]);
+
+        // rounding never over- or undershoots the width
+        let widths = segment_widths(&[1, 1, 1], 10);
+        assert_eq!(widths.iter().sum::<usize>(), 10);
+
+        assert_eq!(segment_widths(&[0, 0], 10), vec![0, 0]);
+    }
+}
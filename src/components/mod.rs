@@ -1,7 +1,7 @@
 use anyhow::Result;
 use crate::input::{Key, Mouse};
 use ratatui::prelude::*;
-use super::config::KeyConfig;
+use super::config::{KeyConfig, ThemeConfig};
 pub mod filter;
 pub mod help;
 pub mod error;
@@ -12,6 +12,15 @@ pub mod cpu;
 pub mod memory;
 pub mod temp;
 pub mod network;
+pub mod column_chooser;
+pub mod units;
+pub mod limits;
+pub mod process_detail;
+pub mod connections;
+pub mod disks;
+pub mod disk_io;
+pub mod summary;
+pub mod pressure;
 
 pub trait DrawableComponent {
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()>;
@@ -65,6 +74,16 @@ pub fn common_nav(key: Key, key_config: &KeyConfig) -> Option<MoveSelection> {
     }
 }
 
+// helper function for determining the style of a list/table row
+pub fn compute_row_style(focus: bool, selected: bool, theme: &ThemeConfig) -> Style {
+    match (focus, selected) {
+        (true, true) => theme.style_item_selected,
+        (true, false) => theme.style_item_focused,
+        (false, true) => theme.style_item_selected_not_focused,
+        _ => theme.style_item_not_focused,
+    }
+}
+
 #[derive(Copy, Clone)]
 pub enum MoveSelection {
     Up,
@@ -1,66 +1,171 @@
-
 use std::cmp::{max, min};
+use std::collections::BTreeMap;
 
 use anyhow::{Ok, Result};
 use ratatui::{Frame, prelude::*, widgets::*};
+use ratatui::layout::Position;
 
 use crate::components::Refreshable;
+use crate::components::utils::selection::UISelection;
+use crate::components::utils::history_chart::{series, HistoryChart};
 use crate::input::MouseKind;
-use crate::services::ItemProvider;
-use crate::states::bounded_queue_state::BoundedQueueState;
+use crate::services::{ItemProvider, VecProvider};
+use crate::models::bounded_queue_model::BoundedQueueModel;
 use crate::models::items::network_item::NetworkItem;
-use crate::models::items::*;
+use crate::models::units::{format_bytes, format_data_rate, format_value, DataUnit, UnitSystem};
 use crate::config::Config;
 use crate::components::*;
-use crate::config::*;
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum Focus {
+    Chart,
+    InterfaceList,
+}
+
+// NetworkComponent charts the traffic (bytes and packets) of the network interfaces. Like the CPU list, a side list
+// selects between "All" (summed over all interfaces) and a single interface, for which the MAC,
+// IP addresses and MTU are shown. Interfaces that disappear (e.g. veths of stopped containers)
+// are dropped along with their history. Errors and drops are highlighted while they occur.
 pub struct NetworkComponent {
     config: Config,
-    queue_state: BoundedQueueState<NetworkItem>,
+    all: BoundedQueueModel<NetworkItem>,
+    interfaces: BTreeMap<String, BoundedQueueModel<NetworkItem>>,
+    selection_state: UISelection,               // 0 selects All, i selects the i-th interface
     data_window_time_scale: u64,
+    chart_area: Option<Rect>,
+    list_area: Option<Rect>,
+    focus: Focus,
 }
 
 impl NetworkComponent {
     pub fn new<S>(config: Config, service: &S) -> Self
-    where S: ItemProvider<NetworkItem>
+    where S: ItemProvider<NetworkItem> + VecProvider<NetworkItem>
     {
         let capacity = ( config.max_time_scale() / config.refresh_rate() ) as usize;
-        let selection = None;
-        let refresh_bool = true;
         let data_window_time_scale = config.min_time_scale();
 
-        let mut queue_state = BoundedQueueState::new(capacity, selection, refresh_bool);
-        let network = service.fetch_item();
-
-        queue_state.add_item(network);
-
-        Self {
+        let mut component = Self {
             config,
-            queue_state,
+            all: BoundedQueueModel::new(capacity),
+            interfaces: BTreeMap::new(),
+            selection_state: UISelection::new(Some(0)),
             data_window_time_scale,
+            chart_area: None,
+            list_area: None,
+            focus: Focus::InterfaceList,
+        };
+        component.refresh(service);
+        component
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    pub fn set_network_unit(&mut self, network_unit: DataUnit) {
+        self.config.set_network_unit(network_unit);
+    }
+
+    // name of the selected interface, None if All is selected
+    fn selected_interface(&self) -> Option<&str> {
+        let selection = self.selection_state.selection?;
+
+        self.interfaces
+            .keys()
+            .nth(selection.checked_sub(1)?)
+            .map(|interface| interface.as_str())
+    }
+
+    fn selected_queue(&self) -> &BoundedQueueModel<NetworkItem> {
+        self.selected_interface()
+            .and_then(|interface| self.interfaces.get(interface))
+            .unwrap_or(&self.all)
+    }
+
+    fn handle_move_selection(&mut self, dir: MoveSelection) {
+        let len = self.interfaces.len() + 1;        // All
+        self.selection_state.move_selection(dir, len);
+    }
+
+    fn handle_time_scale(&mut self, dir: MoveSelection) {
+        self.data_window_time_scale = match dir {
+            MoveSelection::Down => min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale()),
+            _ => max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale()),
+        };
+    }
+
+    fn handle_mouse_click(&mut self, click_x: u16, click_y: u16) -> bool {
+        let position = Position { x: click_x, y: click_y };
+
+        if self.chart_area.is_some_and(|area| area.contains(position)) {
+            self.focus = Focus::Chart;
+            return true
+        }
+        if self.list_area.is_some_and(|area| area.contains(position)) {
+            self.focus = Focus::InterfaceList;
+            return true
         }
+
+        false
     }
 }
 
 impl<S> Refreshable<S> for NetworkComponent
 where
-    S: ItemProvider<NetworkItem>
+    S: ItemProvider<NetworkItem> + VecProvider<NetworkItem>
 {
     fn refresh(&mut self, service: &S) {
-        let network_item: NetworkItem = service.fetch_item();
-        self.queue_state.add_item(network_item);
+        let capacity = ( self.config.max_time_scale() / self.config.refresh_rate() ) as usize;
+        let selected = self.selected_interface().map(String::from);
+
+        self.all.add_item(service.fetch_item());
+
+        let networks: Vec<NetworkItem> = service.fetch_items();
+        self.interfaces.retain(|interface, _| networks.iter().any(|network| network.interface() == interface));
+        for network in networks {
+            self.interfaces
+                .entry(network.interface().to_string())
+                .or_insert_with(|| BoundedQueueModel::new(capacity))
+                .add_item(network);
+        }
+
+        // keeping the selected interface selected as interfaces come and go, All if it is gone
+        let selection = selected
+            .and_then(|selected| self.interfaces.keys().position(|interface| *interface == selected))
+            .map_or(0, |idx| idx + 1);
+        self.selection_state.set_selection(Some(selection));
     }
 }
 
 impl Component for NetworkComponent {
     fn key_event(&mut self, key: Key) -> Result<EventState> {
         let key_config = &self.config.key_config;
-        if key == key_config.move_down {
-            self.data_window_time_scale = min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale());
-            return Ok(EventState::Consumed)
+
+        match self.focus {
+            Focus::InterfaceList => {
+                if let Some(dir) = common_nav(key, key_config) {
+                    self.handle_move_selection(dir);
+                    return Ok(EventState::Consumed)
+                }
+            }
+            Focus::Chart => {
+                if key == key_config.move_down {
+                    self.handle_time_scale(MoveSelection::Down);
+                    return Ok(EventState::Consumed)
+                }
+                if key == key_config.move_up {
+                    self.handle_time_scale(MoveSelection::Up);
+                    return Ok(EventState::Consumed)
+                }
+            }
         }
-        if key == key_config.move_up {
-            self.data_window_time_scale = max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale());
+
+        // key event to move focus
+        if key == key_config.filter {
+            match self.focus {
+                Focus::InterfaceList => { self.focus = Focus::Chart }
+                Focus::Chart => { self.focus = Focus::InterfaceList }
+            }
             return Ok(EventState::Consumed)
         }
 
@@ -68,13 +173,24 @@ impl Component for NetworkComponent {
     }
 
     fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
-        match mouse.kind {
-            MouseKind::ScrollDown => {
-                self.data_window_time_scale = min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale());
+        match (self.focus, mouse.kind) {
+            (Focus::InterfaceList, MouseKind::ScrollDown) => {
+                self.handle_move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::InterfaceList, MouseKind::ScrollUp) => {
+                self.handle_move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::Chart, MouseKind::ScrollDown) => {
+                self.handle_time_scale(MoveSelection::Down);
                 return Ok(EventState::Consumed)
             }
-            MouseKind::ScrollUp => { 
-                self.data_window_time_scale = max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale());
+            (Focus::Chart, MouseKind::ScrollUp) => {
+                self.handle_time_scale(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (_, MouseKind::LeftClick) if self.handle_mouse_click(mouse.column, mouse.row) => {
                 return Ok(EventState::Consumed)
             }
             _ => {}
@@ -86,129 +202,226 @@ impl Component for NetworkComponent {
 
 impl DrawableComponent for NetworkComponent {
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let horizontal_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Fill(1),                    // legend and charts
+                Constraint::Length(20),                 // interface list
+            ]).split(area);
+
         let vertical_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2),
+                Constraint::Length(4),
                 Constraint::Min(0),
-            ]).split(area);
-        
+            ]).split(horizontal_chunks[0]);
+
+        let chart_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(50),             // bytes
+                Constraint::Percentage(50),             // packets
+            ]).split(vertical_chunks[1]);
+
+        // saving chart and list area for mouse clicks
+        self.chart_area = Some(horizontal_chunks[0]);
+        self.list_area = Some(horizontal_chunks[1]);
+
         let refresh_rate = self.config.refresh_rate();              // default = 2,000 ms
         let time_scale = self.data_window_time_scale;               // default = 60,000 ms
         let data_window = (time_scale / refresh_rate) as usize;     // default = 30
-        let max_idx = data_window.saturating_sub(1);                //
-        let y_max_scale_factor = 1.5;                               // used to scale y-upper bound
-
-        let tx_data: Vec<(f64, f64)> = self.queue_state
-                .iter()
-                .rev()
-                .take(data_window)
-                .enumerate()
-                .map(|(idx, network_item)| {
-                    (
-                        max_idx.saturating_sub(idx) as f64,
-                        byte_to_kb(network_item.tx()) as f64,
-                    )
-                })
-                .collect();
-
-        let rx_data: Vec<(f64, f64)> = self.queue_state
-                .iter()
-                .rev()
-                .take(data_window)
-                .enumerate()
-                .map(|(idx, network_item)| {
-                    (
-                        max_idx.saturating_sub(idx) as f64,
-                        byte_to_kb(network_item.rx()) as f64,
-                    )
-                })
-                .collect();
-
-        // getting upper bound on y
-        let max_y = tx_data
-            .iter()
-            .chain(rx_data.iter())
-            .map(|tuple| tuple.1)
-            .fold(0.0, f64::max);
-
-        // scaling
-        let max_y = max_y * y_max_scale_factor;
-        
-        let datasets = vec![
-            Dataset::default()
-                .data(&tx_data)
-                .graph_type(GraphType::Line)
-                .marker(Marker::Braille)
-                .style(Style::new().light_blue()),
-            
-            Dataset::default()
-                .data(&rx_data)
-                .graph_type(GraphType::Line)
-                .marker(Marker::Braille)
-                .style(Style::new().light_yellow())
-        ];
+        let queue = self.selected_queue();
 
         // set block style
-        let block_style = if focused {
-            self.config.theme_config.style_border_focused
-        }
-        else {
-            self.config.theme_config.style_border_not_focused
+        let (chart_style, list_style) = match (focused, self.focus) {
+            (true, Focus::Chart) => (self.config.theme_config.style_border_focused, self.config.theme_config.style_border_not_focused),
+            (true, Focus::InterfaceList) => (self.config.theme_config.style_border_not_focused, self.config.theme_config.style_border_focused),
+            _ => (self.config.theme_config.style_border_not_focused, self.config.theme_config.style_border_not_focused),
         };
 
-        // building chart
-        let x_axis = Axis::default()
-            .bounds([0.0, max_idx as f64])
-            .labels(vec![Span::raw(format!("{}s", ms_to_s(time_scale))), Span::raw("now")])
-            .labels_alignment(Alignment::Right);
-
-        let y_axis = Axis::default()
-            .bounds([0.0, max_y])
-            .labels(vec![
-                Span::raw("0KB"),
-                Span::raw(format!("{}", max_y)),
-            ])
-            .labels_alignment(Alignment::Right);
+        let unit_system = self.config.unit_system();
+        let network_unit = self.config.network_unit();
 
-        let chart_title = " Network ";
-        let chart = Chart::new(datasets)
-            .block(Block::default()
-                .borders(Borders::LEFT|Borders::BOTTOM|Borders::RIGHT)
-                .style(block_style)
-        )
-        .x_axis(x_axis)
-        .y_axis(y_axis);
+        let bytes_chart = HistoryChart {
+            series: vec![
+                (series(queue, data_window, |item| network_unit.from_bytes(item.per_s(item.tx()))), Color::LightBlue),
+                (series(queue, data_window, |item| network_unit.from_bytes(item.per_s(item.rx()))), Color::LightYellow),
+            ],
+            unit_system,
+            unit: format!("{}/s", network_unit.symbol()),
+            data_window,
+            time_scale,
+            upper: None,
+        };
+        bytes_chart.draw(f, chart_chunks[0], Borders::LEFT|Borders::BOTTOM, chart_style);
+
+        // packet counts always use decimal prefixes
+        let packets_chart = HistoryChart {
+            series: vec![
+                (series(queue, data_window, |item| item.per_s(item.packets_tx())), Color::LightBlue),
+                (series(queue, data_window, |item| item.per_s(item.packets_rx())), Color::LightYellow),
+            ],
+            unit_system: UnitSystem::Si,
+            unit: String::from("pkt/s"),
+            data_window,
+            time_scale,
+            upper: None,
+        };
+        packets_chart.draw(f, chart_chunks[1], Borders::BOTTOM|Borders::RIGHT, chart_style);
 
-        f.render_widget(chart, vertical_chunks[1]);
+        let default_item = NetworkItem::default();
+        let network_item = queue.back().unwrap_or(&default_item);
+        let per_s = |value: u64| format!("{:.1}", network_item.per_s(value));
 
-        let network_item = if let Some(item) = self.queue_state.back() {
-            item
+        let tx_legend = format!(
+            "TX {} :: TOTAL TX {}",
+            format_data_rate(network_item.per_s(network_item.tx()), unit_system, network_unit),
+            format_bytes(network_item.total_tx(), unit_system),
+        );
+        let rx_legend = format!(
+            "RX {} :: TOTAL RX {}",
+            format_data_rate(network_item.per_s(network_item.rx()), unit_system, network_unit),
+            format_bytes(network_item.total_rx(), unit_system),
+        );
+        let packets_legend = format!(
+            "TX {} RX {}",
+            format_value(network_item.per_s(network_item.packets_tx()), UnitSystem::Si, "pkt/s"),
+            format_value(network_item.per_s(network_item.packets_rx()), UnitSystem::Si, "pkt/s"),
+        );
+        let errors_legend = format!(
+            "ERR/s TX {} RX {} :: DROP/s TX {} RX {}",
+            per_s(network_item.errors_tx()),
+            per_s(network_item.errors_rx()),
+            per_s(network_item.drops_tx()),
+            per_s(network_item.drops_rx()),
+        );
+        // errors and drops are highlighted while they occur
+        let errors_style = if network_item.has_errors_or_drops() {
+            Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)
         }
         else {
-            &NetworkItem::default()
+            Style::default()
         };
-        
-        let tx_per_s = network_item.tx() / ms_to_s(refresh_rate);
-        let rx_per_s = network_item.rx() / ms_to_s(refresh_rate);
-        let tx_legend = format!("TX/s {}KB :: TOTAL TX {}MB", byte_to_kb(tx_per_s), byte_to_mb(network_item.total_tx()));
-        let rx_legend = format!("RX/s {}KB :: TOTAL RX {}MB", byte_to_kb(rx_per_s), byte_to_mb(network_item.total_rx()));
 
-        let legend = Paragraph::new(
+        // details of the selected interface
+        let details = match self.selected_interface() {
+            Some(_) => {
+                let ip_addresses = if network_item.ip_addresses().is_empty() {
+                    String::from("-")
+                }
+                else {
+                    network_item.ip_addresses().join(", ")
+                };
+
+                format!(
+                    "MAC {} :: MTU {} :: IP {}",
+                    network_item.mac().unwrap_or("-"),
+                    network_item.mtu().map_or_else(|| String::from("-"), |mtu| mtu.to_string()),
+                    ip_addresses,
+                )
+            }
+            None => format!("{} interfaces", self.interfaces.len()),
+        };
+
+        let legend = Paragraph::new(vec![
             Line::from(vec![
                 Span::styled(tx_legend, Style::default().fg(Color::LightBlue)),
                 Span::raw("  "),
                 Span::styled(rx_legend, Style::default().fg(Color::LightYellow)),
             ])
-            .right_aligned())
+            .right_aligned(),
+            Line::from(vec![
+                Span::raw(packets_legend),
+                Span::raw("  "),
+                Span::styled(errors_legend, errors_style),
+            ])
+            .right_aligned(),
+            Line::from(details).right_aligned(),
+        ])
             .block(Block::new()
                 .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
-                .style(block_style)
-                .title(chart_title)
+                .style(chart_style)
+                .title(format!(" Network ({}) ", self.selected_interface().unwrap_or("All")))
             );
-        
+
         f.render_widget(legend, vertical_chunks[0]);
 
+        // render interface list, interfaces with errors or drops are highlighted
+        let names: Vec<ListItem> = std::iter::once(("All", &self.all))
+            .chain(self.interfaces.iter().map(|(interface, queue)| (interface.as_str(), queue)))
+            .enumerate()
+            .map(|(idx, (name, queue))| {
+                let style = if queue.back().is_some_and(|item| item.has_errors_or_drops()) {
+                    Style::default().fg(Color::LightRed)
+                }
+                else {
+                    Style::default()
+                };
+
+                if Some(idx) == self.selection_state.selection {
+                    ListItem::new(format!("-> {}", name)).style(style)
+                }
+                else {
+                    ListItem::new(format!("   {}", name)).style(style)
+                }
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        list_state.select(self.selection_state.selection);
+
+        let interface_list = List::new(names)
+            .scroll_padding(horizontal_chunks[1].height as usize / 2)
+            .block(Block::default().borders(Borders::ALL).style(list_style));
+
+        f.render_stateful_widget(interface_list, horizontal_chunks[1], &mut list_state);
+
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService {
+        interfaces: Vec<&'static str>,
+    }
+
+    impl ItemProvider<NetworkItem> for DummyService {
+        fn fetch_item(&self) -> NetworkItem {
+            NetworkItem::new(String::from("All"), 3072, 1024, 0, 0)
+        }
+    }
+
+    impl VecProvider<NetworkItem> for DummyService {
+        fn fetch_items(&self) -> Vec<NetworkItem> {
+            self.interfaces
+                .iter()
+                .map(|interface| NetworkItem::new(interface.to_string(), 1024, 512, 0, 0))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_interface_selection() {
+        let config = Config::default();
+        let mut component = NetworkComponent::new(config.clone(), &DummyService { interfaces: vec!["eth0", "lo"] });
+        assert_eq!(component.selected_interface(), None);
+        assert_eq!(component.selected_queue().back().map(|item| item.tx()), Some(3072));
+
+        component.key_event(config.key_config.move_bottom).unwrap();
+        assert_eq!(component.selected_interface(), Some("lo"));
+
+        // lo stays selected as docker0 appears before it, eth0's history is dropped when it disappears
+        component.refresh(&DummyService { interfaces: vec!["docker0", "lo"] });
+        assert_eq!(component.selected_interface(), Some("lo"));
+        assert_eq!(component.selection_state.selection, Some(2));
+        assert_eq!(component.selected_queue().items().len(), 2);
+        assert!(!component.interfaces.contains_key("eth0"));
+
+        // All is selected once the selected interface disappears
+        component.refresh(&DummyService { interfaces: vec!["docker0"] });
+        assert_eq!(component.selected_interface(), None);
+    }
+}
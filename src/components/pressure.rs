@@ -0,0 +1,235 @@
+use std::cmp::{max, min};
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::components::{Component, DrawableComponent, EventState, Refreshable};
+use crate::components::utils::history_chart::{series, HistoryChart};
+use crate::input::{Key, Mouse, MouseKind};
+use crate::services::ItemProvider;
+use crate::models::bounded_queue_model::BoundedQueueModel;
+use crate::models::items::pressure_item::{PressureItem, PressureLevel, PressureResource, StallAverages};
+use crate::models::units::UnitSystem;
+use crate::config::Config;
+
+// (average, color of the some series, color of the full series), brightest for the short term average
+type StallWindow = (fn(&StallAverages) -> f32, Color, Color);
+
+const STALL_WINDOWS: [StallWindow; 3] = [
+    (|stall| stall.avg10, Color::LightYellow, Color::LightRed),
+    (|stall| stall.avg60, Color::Yellow, Color::Red),
+    (|stall| stall.avg300, Color::DarkGray, Color::Magenta),
+];
+
+// PressureComponent shows the pressure stall information (PSI) of the system from /proc/pressure:
+// the percent of time tasks were stalled waiting on cpu, memory or io, averaged over 10s, 60s and
+// 300s. Unlike usage, PSI measures saturation, tasks losing time because a resource is short. The
+// legend lists every resource with values above the configured thresholds in yellow and red, and
+// the chart shows the history of the resource selected with enter.
+pub struct PressureComponent {
+    config: Config,
+    queue: BoundedQueueModel<PressureItem>,
+    data_window_time_scale: u64,
+    resource: PressureResource,
+}
+
+impl PressureComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: ItemProvider<PressureItem>
+    {
+        let capacity = ( config.max_time_scale() / config.refresh_rate() ) as usize;
+        let data_window_time_scale = config.min_time_scale();
+
+        let mut component = Self {
+            config,
+            queue: BoundedQueueModel::new(capacity),
+            data_window_time_scale,
+            resource: PressureResource::default(),
+        };
+        component.refresh(service);
+        component
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    fn increase_time_scale(&mut self) {
+        self.data_window_time_scale = min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale());
+    }
+
+    fn decrease_time_scale(&mut self) {
+        self.data_window_time_scale = max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale());
+    }
+
+    fn level_style(&self, percent: f32) -> Style {
+        match PressureLevel::of(percent, self.config.pressure_warning(), self.config.pressure_critical()) {
+            PressureLevel::Critical => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            PressureLevel::Warning => Style::default().fg(Color::Yellow),
+            PressureLevel::Normal => Style::default(),
+        }
+    }
+
+    // avg10 avg60 avg300, each colored by its level
+    fn stall_spans(&self, stall: Option<&StallAverages>) -> Vec<Span<'static>> {
+        match stall {
+            Some(stall) => [stall.avg10, stall.avg60, stall.avg300]
+                .into_iter()
+                .map(|percent| Span::styled(format!("{:>6.1}", percent), self.level_style(percent)))
+                .collect(),
+            None => vec![Span::styled(format!("{:>18}", "-"), Style::default().fg(Color::DarkGray))],
+        }
+    }
+
+    fn legend_lines(&self, item: &PressureItem) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{:<8}", "")),
+                Span::styled(format!("{:>18}", "some 10/60/300s"), Style::default().fg(Color::LightYellow)),
+                Span::raw("  "),
+                Span::styled(format!("{:>18}", "full 10/60/300s"), Style::default().fg(Color::LightRed)),
+            ]),
+        ];
+
+        for resource in PressureResource::ALL {
+            let pressure = item.resource(resource);
+            let name_style = if resource == self.resource {
+                Style::default().add_modifier(Modifier::BOLD|Modifier::UNDERLINED)
+            }
+            else {
+                Style::default()
+            };
+
+            let mut spans = vec![
+                Span::raw(" "),
+                Span::styled(resource.name(), name_style),
+                Span::raw(" ".repeat(7_usize.saturating_sub(resource.name().len()))),
+            ];
+            spans.extend(self.stall_spans(pressure.map(|pressure| &pressure.some)));
+            spans.push(Span::raw("  "));
+            spans.extend(self.stall_spans(pressure.and_then(|pressure| pressure.full.as_ref())));
+
+            lines.push(Line::from(spans));
+        }
+
+        lines
+    }
+}
+
+impl<S> Refreshable<S> for PressureComponent
+where
+    S: ItemProvider<PressureItem>
+{
+    fn refresh(&mut self, service: &S) {
+        self.queue.add_item(service.fetch_item());
+    }
+}
+
+impl Component for PressureComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        let key_config = &self.config.key_config;
+        if key == key_config.move_down {
+            self.increase_time_scale();
+            return Ok(EventState::Consumed)
+        }
+        if key == key_config.move_up {
+            self.decrease_time_scale();
+            return Ok(EventState::Consumed)
+        }
+        if key == key_config.enter {
+            self.resource = self.resource.next();
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match mouse.kind {
+            MouseKind::ScrollDown => {
+                self.increase_time_scale();
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::ScrollUp => {
+                self.decrease_time_scale();
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for PressureComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let style = if focused {
+            self.config.theme_config.style_border_focused
+        }
+        else {
+            self.config.theme_config.style_border_not_focused
+        };
+
+        let default_item = PressureItem::default();
+        let item = self.queue.back().unwrap_or(&default_item);
+
+        // kernels built without CONFIG_PSI or booted with psi=0
+        if !item.is_available() {
+            let message = Paragraph::new(" /proc/pressure not available, PSI needs Linux 4.20+ with psi enabled")
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).style(style).title(" Pressure "));
+            f.render_widget(message, area);
+            return Ok(())
+        }
+
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5),
+                Constraint::Min(0),
+            ]).split(area);
+
+        let legend = Paragraph::new(self.legend_lines(item))
+            .block(Block::new()
+                .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
+                .style(style)
+                .title(format!(" Pressure :: {} ", self.resource.name()))
+            );
+        f.render_widget(legend, vertical_chunks[0]);
+
+        let refresh_rate = self.config.refresh_rate();
+        let time_scale = self.data_window_time_scale;
+        let data_window = (time_scale / refresh_rate) as usize;
+        let resource = self.resource;
+
+        let mut chart_series = Vec::new();
+        for (average, some_color, full_color) in STALL_WINDOWS {
+            chart_series.push((
+                series(&self.queue, data_window, |item| {
+                    item.resource(resource).map_or(0_f64, |pressure| average(&pressure.some) as f64)
+                }),
+                some_color,
+            ));
+            if item.resource(resource).is_some_and(|pressure| pressure.full.is_some()) {
+                chart_series.push((
+                    series(&self.queue, data_window, |item| {
+                        item.resource(resource)
+                            .and_then(|pressure| pressure.full.as_ref())
+                            .map_or(0_f64, |full| average(full) as f64)
+                    }),
+                    full_color,
+                ));
+            }
+        }
+
+        let chart = HistoryChart {
+            series: chart_series,
+            unit_system: self.config.unit_system(),
+            unit: String::from("%"),
+            data_window,
+            time_scale,
+            upper: None,
+        };
+        chart.draw(f, vertical_chunks[1], Borders::LEFT|Borders::BOTTOM|Borders::RIGHT, style);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashSet};
 use anyhow::{Ok, Result};
 use ratatui::{Frame, prelude::*, widgets::*};
 use ratatui::layout::Position;
@@ -6,10 +7,13 @@ use crate::input::{Key, Mouse, MouseKind};
 use crate::services::VecProvider;
 use crate::components::utils::{selection::UISelection, vertical_scroll::VerticalScroll};
 use crate::components::filter::FilterComponent;
+use crate::models::process_column::{ProcessColumn, ProcessColumnConfig};
+use crate::models::units::UnitSystem;
 use crate::components::*;
 use crate::states::vec_state::VecState;
-use crate::models::items::process_item::{ProcessItem, ProcessItemSortOrder};
-use crate::models::items::*;
+use crate::models::Sortable;
+use crate::models::items::process_item::{CpuMode, ProcessItem, ProcessItemSortOrder};
+use crate::models::items::process_group_item::{ProcessGroupBy, ProcessGroupItem};
 
 #[derive(PartialEq, Clone, Debug)]
 pub enum Focus {
@@ -17,8 +21,15 @@ pub enum Focus {
     List,
 }
 
+// a row of the process list, see ProcessComponent::rows
+#[derive(Clone, Debug)]
+enum ProcessRow {
+    Group(ProcessGroupItem, bool),      // aggregate of a group, is the group expanded?
+    Process(usize),                     // index into vec state model
+}
+
 pub struct ProcessComponent {
-    vec_state: VecState<ProcessItem, ProcessItemSortOrder>,             // underlying the "processlist" is a VecState: see states/vec_state.rs for details
+    vec_state: VecState<ProcessItem, Vec<ProcessItemSortOrder>>,             // underlying the "processlist" is a VecState: see states/vec_state.rs for details
     // ui stuff
     ui_selection: UISelection,                                          // manages ui selection state and movement
     table_area: Option<Rect>,                                           // table area is dynamic, updated by draw, init to None
@@ -27,9 +38,15 @@ pub struct ProcessComponent {
     border_height: u16,                                                 // border height is static, initialize in constructor to 1, e.g., If Border::BOTTOM | Border::TOP, then total height = 2
     scroll: VerticalScroll,
     //
-    sort: ProcessItemSortOrder,
+    sort: Vec<ProcessItemSortOrder>,                                    // sort keys, primary key first
+    secondary_sort_pending: bool,                                       // when true, the next sort key is added as a secondary sort key
     filter_component: FilterComponent,
     focus: Focus,
+    only_mine: bool,                                                    // when true, only processes owned by current_user are listed
+    current_user: Option<String>,                                       // owner of this process, resolved from the fetched process list
+    group_by: Option<ProcessGroupBy>,                                   // when some, processes are folded into one row per group
+    expanded: HashSet<String>,                                          // keys of groups whose member processes are listed
+    unit: Option<(String, String)>,                                     // (name, cgroup path) of the systemd unit the list is scoped to
     pub config: Config,
 }
 
@@ -37,7 +54,8 @@ impl ProcessComponent {
     pub fn new<S>(config: Config, service: &S) -> Self
     where S: VecProvider<ProcessItem>
     {
-        let processes: Vec<ProcessItem> = service.fetch_items();
+        let processes: Vec<ProcessItem> = with_cpu_mode(service.fetch_items(), config.cpu_mode());
+        let current_user: Option<String> = find_current_user(&processes);
 
         let ui_selection: UISelection = if processes.is_empty() { UISelection::new(None) } else { UISelection::new(Some(0)) };
         let table_area: Option<Rect> = None;
@@ -46,11 +64,12 @@ impl ProcessComponent {
         let border_height = 1 as u16;
         let state_selection: Option<usize> = ui_selection.selection;
         let filter: Option<String> = None;
-        let sort: ProcessItemSortOrder = ProcessItemSortOrder::CpuUsageDec;
-        let vec_state: VecState<ProcessItem, ProcessItemSortOrder> = VecState::new(processes, state_selection, Some(sort.clone()), filter);
+        let sort: Vec<ProcessItemSortOrder> = vec![ProcessItemSortOrder::CpuUsageDec];
+        let vec_state: VecState<ProcessItem, Vec<ProcessItemSortOrder>> = VecState::new(processes, state_selection, Some(with_tie_breaker(&sort)), filter);
         let scroll: VerticalScroll = VerticalScroll::new();
         let filter_component: FilterComponent = FilterComponent::new(config.clone());
         let focus: Focus = Focus::List;
+        let only_mine = false;
 
         Self {
             vec_state,
@@ -60,16 +79,22 @@ impl ProcessComponent {
             header_height,
             border_height,
             sort,
+            secondary_sort_pending: false,
             scroll,
             filter_component,
             focus,
+            only_mine,
+            current_user,
+            group_by: None,
+            expanded: HashSet::new(),
+            unit: None,
             config,
         }
     }
 
     // SELECTION HANDLERS::begin
     fn handle_move_selection(&mut self, dir: MoveSelection) {
-        let len = self.vec_state.view_indices().len();
+        let len = self.rows().len();
         // move ui selection by dir
         self.ui_selection.move_selection(dir, len);
         // map ui selection -> vec state index
@@ -79,7 +104,7 @@ impl ProcessComponent {
     }
 
     fn handle_refresh_selection(&mut self) {
-        let len = self.vec_state.view_indices().len();
+        let len = self.rows().len();
         let max_idx = len.saturating_sub(1);
 
         let new_ui_selection: Option<usize> = 
@@ -108,7 +133,7 @@ impl ProcessComponent {
     }
 
     fn handle_filter_selection(&mut self) {
-        let len = self.vec_state.view_indices().len();
+        let len = self.rows().len();
 
         let new_ui_selection: Option<usize> =
         if len == 0 {
@@ -123,56 +148,134 @@ impl ProcessComponent {
         self.vec_state.set_selection(vec_idx);
     }
 
-    // toggles sort if already sorting by specified field
-    // else sets sort to decrementing of specified field
+    // maps a sort key to its column and sorts by it, returns true if key is a sort key
     fn handle_sort(&mut self, key: Key) -> bool {
         let key_config = &self.config.key_config;
-        let sort = self.sort;
-        if key == key_config.sort_pid_toggle {
-            if matches!(sort, ProcessItemSortOrder::PidDec) { 
-                self.sort = ProcessItemSortOrder::PidInc;
-            }
-            else {
-                self.sort = ProcessItemSortOrder::PidDec;
-            }
-            self.vec_state.set_sort(Some(self.sort.clone()));
-            return true;
+
+        let column = if key == key_config.sort_pid_toggle {
+            ProcessColumn::Pid
         }
         else if key == key_config.sort_name_toggle {
-            if matches!(sort, ProcessItemSortOrder::NameDec) {
-                self.sort = ProcessItemSortOrder::NameInc;
-            }
-            else {
-                self.sort = ProcessItemSortOrder::NameDec;
-            }
-            self.vec_state.set_sort(Some(self.sort.clone()));
-            return true
+            ProcessColumn::Name
         }
         else if key == key_config.sort_cpu_toggle {
-            if matches!(sort, ProcessItemSortOrder::CpuUsageDec) {
-                self.sort = ProcessItemSortOrder::CpuUsageInc;
-            }
-            else {
-                self.sort = ProcessItemSortOrder::CpuUsageDec;
-            }
-            self.vec_state.set_sort(Some(self.sort.clone()));
-            return true;
+            ProcessColumn::Cpu
         }
         else if key == key_config.sort_memory_toggle {
-            if matches!(sort, ProcessItemSortOrder::MemoryUsageDec) {
-                self.sort = ProcessItemSortOrder::MemoryUsageInc;
+            ProcessColumn::Mem
+        }
+        else if key == key_config.sort_user_toggle {
+            ProcessColumn::User
+        }
+        else {
+            return false;
+        };
+
+        if std::mem::take(&mut self.secondary_sort_pending) {
+            self.handle_secondary_sort_column(column);
+        }
+        else {
+            self.handle_sort_column(column);
+        }
+        true
+    }
+
+    // toggles primary sort if already sorting by specified column
+    // else replaces primary sort with decrementing of specified column, secondary sort keys are kept
+    fn handle_sort_column(&mut self, column: ProcessColumn) {
+        let (inc, dec) = column.sort_orders();
+        let primary = if self.sort.first() == Some(&dec) { inc } else { dec };
+
+        if !self.sort.is_empty() {
+            self.sort.remove(0);
+        }
+        self.sort.retain(|key| *key != inc && *key != dec);
+        self.sort.insert(0, primary);
+        self.vec_state.set_sort(Some(with_tie_breaker(&self.sort)));
+    }
+
+    // toggles secondary sort if already sorting by specified column
+    // else appends decrementing of specified column as the last sort key
+    fn handle_secondary_sort_column(&mut self, column: ProcessColumn) {
+        let (inc, dec) = column.sort_orders();
+
+        match self.sort.iter().position(|key| *key == inc || *key == dec) {
+            // primary sort key is changed by handle_sort_column
+            Some(0) => {}
+            Some(idx) => self.sort[idx] = if self.sort[idx] == dec { inc } else { dec },
+            None => self.sort.push(dec),
+        }
+        self.vec_state.set_sort(Some(with_tie_breaker(&self.sort)));
+    }
+
+    // removes the last secondary sort key, the primary sort key is never removed
+    fn handle_remove_secondary_sort(&mut self) {
+        if self.sort.len() > 1 {
+            self.sort.pop();
+        }
+        self.vec_state.set_sort(Some(with_tie_breaker(&self.sort)));
+    }
+    // SELECTION HANDLERS::end
+
+    // GROUP HANDLERS::begin
+    // cycles through ungrouped -> grouped by name -> grouped by executable -> grouped by cgroup
+    fn handle_group_toggle(&mut self) {
+        self.group_by = match self.group_by {
+            None => Some(ProcessGroupBy::Name),
+            Some(ProcessGroupBy::Name) => Some(ProcessGroupBy::Exe),
+            Some(ProcessGroupBy::Exe) => Some(ProcessGroupBy::Cgroup),
+            Some(ProcessGroupBy::Cgroup) => None,
+        };
+        self.expanded.clear();
+    }
+
+    // expands/collapses the selected group, returns true if a group row is selected
+    fn handle_expand_group(&mut self) -> bool {
+        let row = self.ui_selection.selection.and_then(|idx| self.rows().into_iter().nth(idx));
+
+        if let Some(ProcessRow::Group(group, expanded)) = row {
+            if expanded {
+                self.expanded.remove(group.key());
             }
             else {
-                self.sort = ProcessItemSortOrder::MemoryUsageDec;
+                self.expanded.insert(group.key().to_string());
             }
-            self.vec_state.set_sort(Some(self.sort.clone()));
             return true;
         }
 
         false
+    }
+    // GROUP HANDLERS::end
 
+    // SCOPE HANDLERS::begin
+    fn handle_only_mine_toggle(&mut self) {
+        self.only_mine = !self.only_mine;
+        self.handle_scopes();
     }
-    // SELECTION HANDLERS::end
+
+    // rebuilds the vec state scopes from the component's toggles
+    fn handle_scopes(&mut self) {
+        let mut scopes: Vec<String> = Vec::new();
+
+        if self.only_mine {
+            if let Some(user) = &self.current_user {
+                scopes.push(format!("user={}", user));
+            }
+        }
+        if let Some((_name, cgroup)) = &self.unit {
+            scopes.push(format!("cgroup={}", cgroup));
+        }
+
+        self.vec_state.set_scopes(scopes);
+    }
+
+    // scopes the list to the member processes of a systemd unit, None lists all processes again
+    pub fn set_unit(&mut self, unit: Option<(String, String)>) {
+        self.unit = unit;
+        self.handle_scopes();
+        self.handle_filter_selection();
+    }
+    // SCOPE HANDLERS::end
 
     // MOUSE CLICK HANDLERS::begin
 
@@ -229,6 +332,39 @@ impl ProcessComponent {
         true
     }
 
+    /* maps click_x to a column if the click is on the table header and sorts by that column,
+       clicking the column that is already sorted on toggles the sort direction. if secondary is
+       true, the column is added as (or toggles) a secondary sort key instead.
+       returns true if the click is on the header, else false
+    */
+    fn handle_mouse_click_on_header(&mut self, click_x: u16, click_y: u16, secondary: bool) -> bool {
+        if self.table_area.is_none() { return false; }
+        let table_area = self.table_area.unwrap();
+        let header_top = table_area.top().saturating_add(self.border_height);
+
+        if click_y < header_top || click_y >= header_top.saturating_add(self.header_height) {
+            return false;
+        }
+        self.focus = Focus::List;
+
+        let column = header_columns(table_area, &self.config.process_columns)
+            .into_iter()
+            .find(|(_column, x, width)| click_x >= *x && click_x < x.saturating_add(*width))
+            .map(|(column, _x, _width)| column);
+
+        if let Some(column) = column {
+            if secondary {
+                self.handle_secondary_sort_column(column);
+            }
+            else {
+                self.handle_sort_column(column);
+            }
+            self.handle_refresh_selection();
+        }
+
+        true
+    }
+
     fn handle_mouse_click_on_filter(&mut self, click_y: u16) -> bool {
         if self.filter_area.is_none() { return false; }
         let filter_area = self.filter_area.unwrap();
@@ -247,15 +383,153 @@ impl ProcessComponent {
     }
     // MOUSE CLICK HANDLERS::end
 
+    // COLUMN HANDLERS::begin
+    // rescales the cpu usage of the listed processes, sorting and filters follow the new values
+    pub fn set_cpu_mode(&mut self, cpu_mode: CpuMode) {
+        self.config.set_cpu_mode(cpu_mode);
+        let processes = with_cpu_mode(self.vec_state.list().to_vec(), cpu_mode);
+        self.vec_state.replace(processes);
+        self.handle_refresh_selection();
+    }
+
+    pub fn columns(&self) -> &[ProcessColumnConfig] {
+        &self.config.process_columns
+    }
+
+    pub fn set_columns(&mut self, columns: Vec<ProcessColumnConfig>) {
+        self.config.process_columns = columns;
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+    // COLUMN HANDLERS::end
+
+    // SELECT BY PID::begin
+    /* selects the process with pid, expanding its group if the list is grouped. if the process is
+       hidden by the filter or a scope, they are cleared first.
+       returns false if the process is not in the list, e.g. it exited
+    */
+    pub fn select_pid(&mut self, pid: u32) -> bool {
+        let Some(vec_idx) = self.vec_state.list().iter().position(|item| item.pid() == pid)
+        else {
+            return false;
+        };
+
+        if let Some(group_by) = self.group_by {
+            self.expanded.insert(group_by.key(&self.vec_state.list()[vec_idx]));
+        }
+
+        if self.row_position(vec_idx).is_none() {
+            self.filter_component.reset();
+            self.vec_state.set_filter(None);
+            self.only_mine = false;
+            self.unit = None;
+            self.handle_scopes();
+        }
+
+        let Some(position) = self.row_position(vec_idx)
+        else {
+            return false;
+        };
+
+        self.focus = Focus::List;
+        self.ui_selection.set_selection(Some(position));
+        self.vec_state.set_selection(Some(vec_idx));
+        true
+    }
+
+    fn row_position(&self, vec_idx: usize) -> Option<usize> {
+        self.rows()
+            .iter()
+            .position(|row| matches!(row, ProcessRow::Process(idx) if *idx == vec_idx))
+    }
+    // SELECT BY PID::end
+
+    // GETTERS::begin
+    // the selected process, None if nothing or a group row is selected
+    pub fn selected_item(&self) -> Option<&ProcessItem> {
+        self.compute_vec_state_idx()
+            .and_then(|idx| self.vec_state.list().get(idx))
+    }
+
+    pub fn item(&self, pid: u32) -> Option<&ProcessItem> {
+        self.vec_state.list().iter().find(|item| item.pid() == pid)
+    }
+    // GETTERS::end
+
     // HELPERS::begin
-    /* computes amd returns vector state index corresponding to ui selection */
+    fn block_title(&self) -> String {
+        let mut block_title = String::from(" Process List ");
+
+        if self.only_mine {
+            if let Some(user) = &self.current_user {
+                block_title.push_str(&format!("(user: {}) ", user));
+            }
+        }
+        if let Some((name, _cgroup)) = &self.unit {
+            block_title.push_str(&format!("(unit: {}, {:?} to show all) ", name, self.config.key_config.exit));
+        }
+        if let Some(group_by) = self.group_by {
+            block_title.push_str(&format!("(grouped by {}) ", group_by.title()));
+        }
+        if self.secondary_sort_pending {
+            block_title.push_str("(then sort by: ...) ");
+        }
+
+        block_title
+    }
+
+    /* computes amd returns vector state index corresponding to ui selection, None if a group row is selected */
     fn compute_vec_state_idx(&self) -> Option<usize> {
         // map ui_selection.selection to vec_state
         let vec_idx = self.ui_selection.selection
-            .and_then(|ui_selection| self.vec_state.view_indices().get(ui_selection).cloned());
+            .and_then(|ui_selection| match self.rows().get(ui_selection) {
+                Some(ProcessRow::Process(idx)) => Some(*idx),
+                _ => None,
+            });
 
         vec_idx
     }
+
+    /* computes the rows of the process list. ungrouped, rows are the filtered and sorted processes.
+       grouped, each group of filtered processes is a row, sorted by the aggregated fields, followed
+       by its member processes if the group is expanded
+    */
+    fn rows(&self) -> Vec<ProcessRow> {
+        let indices = self.vec_state.view_indices();
+
+        let group_by = match self.group_by {
+            Some(group_by) => group_by,
+            None => return indices.into_iter().map(ProcessRow::Process).collect(),
+        };
+
+        // BTreeMap: groups with equal sort keys are ordered by group key
+        let items = self.vec_state.list();
+        let mut groups: BTreeMap<String, (ProcessGroupItem, Vec<usize>)> = BTreeMap::new();
+        for idx in indices {
+            let key = group_by.key(&items[idx]);
+            let (group, members) = groups
+                .entry(key)
+                .or_insert_with_key(|key| (ProcessGroupItem::new(key.clone()), Vec::new()));
+            group.add(&items[idx]);
+            members.push(idx);
+        }
+
+        let mut groups: Vec<(ProcessGroupItem, Vec<usize>)> = groups.into_values().collect();
+        groups.sort_by(|(a, _), (b, _)| a.cmp_with(b, &self.sort));
+
+        let mut rows: Vec<ProcessRow> = Vec::new();
+        for (group, members) in groups {
+            let expanded = self.expanded.contains(group.key());
+            rows.push(ProcessRow::Group(group, expanded));
+            if expanded {
+                rows.extend(members.into_iter().map(ProcessRow::Process));
+            }
+        }
+
+        rows
+    }
     // HELPERS::end
 }
 
@@ -264,8 +538,10 @@ where
     S: VecProvider<ProcessItem>
 {
     fn refresh(&mut self, service: &S) {
-        let processes: Vec<ProcessItem> = service.fetch_items();
+        let processes: Vec<ProcessItem> = with_cpu_mode(service.fetch_items(), self.config.cpu_mode());
+        self.current_user = find_current_user(&processes);
         self.vec_state.replace(processes);
+        self.handle_scopes();
         self.handle_refresh_selection();
     }
 }
@@ -298,11 +574,47 @@ impl Component for ProcessComponent {
                 return Ok(EventState::Consumed)
             }
 
+            if key == self.config.key_config.sort_secondary {
+                self.secondary_sort_pending = !self.secondary_sort_pending;
+                return Ok(EventState::Consumed)
+            }
+
             if self.handle_sort(key) {
                 // logic for handling selection after sort is similar enough to refresh
                 self.handle_refresh_selection();
                 return Ok(EventState::Consumed)
             }
+            // any other key cancels a pending secondary sort
+            self.secondary_sort_pending = false;
+
+            if key == self.config.key_config.sort_remove_secondary {
+                self.handle_remove_secondary_sort();
+                self.handle_refresh_selection();
+                return Ok(EventState::Consumed)
+            }
+
+            if key == self.config.key_config.only_mine_toggle {
+                self.handle_only_mine_toggle();
+                self.handle_filter_selection();
+                return Ok(EventState::Consumed)
+            }
+
+            if key == self.config.key_config.group_toggle {
+                self.handle_group_toggle();
+                self.handle_filter_selection();
+                return Ok(EventState::Consumed)
+            }
+
+            if key == self.config.key_config.exit && self.unit.is_some() {
+                self.set_unit(None);
+                return Ok(EventState::Consumed)
+            }
+
+            if key == self.config.key_config.enter && self.handle_expand_group() {
+                // logic for handling selection after expanding is similar enough to refresh
+                self.handle_refresh_selection();
+                return Ok(EventState::Consumed)
+            }
         }
         
         Ok(EventState::NotConsumed)
@@ -318,16 +630,19 @@ impl Component for ProcessComponent {
                 self.handle_move_selection(MoveSelection::Up);
                 return Ok(EventState::Consumed);
             }
+            MouseKind::MiddleClick if self.handle_mouse_click_on_header(mouse.column, mouse.row, true) => {
+                return Ok(EventState::Consumed)
+            }
             MouseKind::LeftClick => {
+                if self.handle_mouse_click_on_header(mouse.column, mouse.row, false) {
+                    return Ok(EventState::Consumed)
+                }
                 if self.handle_mouse_click_on_list(mouse.row) {
                     return Ok(EventState::Consumed);
                 }
                 if self.handle_mouse_click_on_filter(mouse.row) {
                     return Ok(EventState::Consumed);
                 }
-                /*if self.handle_mouse_click_on_header(mouse.column, mouse.row) {
-                    return Ok(EventState::Consumed)
-                }*/
             }
             _ => {}
         }
@@ -336,28 +651,6 @@ impl Component for ProcessComponent {
     }
 }
 
-// helper function to match key to sort variant
-fn handle_sort(key: Key, key_config: &KeyConfig, sort: &ProcessItemSortOrder) -> Option<ProcessItemSortOrder> {
-    if key == key_config.sort_pid_toggle {
-        if matches!(sort, ProcessItemSortOrder::PidDec) { return Some(ProcessItemSortOrder::PidInc)};
-        return Some(ProcessItemSortOrder::PidDec);
-    }
-    if key == key_config.sort_cpu_toggle {
-        if matches!(sort, ProcessItemSortOrder::PidDec) { return Some(ProcessItemSortOrder::PidInc)};
-        return Some(ProcessItemSortOrder::PidDec);
-    }
-
-
-
-    if key == key_config.sort_cpu_toggle        { return Some(ProcessItemSortOrder::CpuUsageDec) }
-    if key == key_config.sort_memory_toggle     { return Some(ProcessItemSortOrder::MemoryUsageInc) }
-    if key == key_config.sort_memory_toggle     { return Some(ProcessItemSortOrder::MemoryUsageDec) }
-    if key == key_config.sort_name_toggle             { return Some(ProcessItemSortOrder::NameInc) }
-    if key == key_config.sort_name_toggle            { return Some(ProcessItemSortOrder::NameDec) }
-
-    return None
-}
-
 impl DrawableComponent for ProcessComponent {
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
         let horizontal_chunks = Layout::default()
@@ -383,8 +676,8 @@ impl DrawableComponent for ProcessComponent {
         self.filter_area = Some(horizontal_chunks[1]);
 
         // update vertical scroll
-        let indices = self.vec_state.view_indices();
-        let len = indices.len();
+        let rows = self.rows();
+        let len = rows.len();
         self.ui_selection.selection.map_or_else(
             { ||
                 // if selection is none
@@ -394,10 +687,21 @@ impl DrawableComponent for ProcessComponent {
                 self.scroll.update(idx, len, visible_list_height);
         },);
 
-        let visible_items = self.vec_state
-            .iter_with_selection()
+        let items = self.vec_state.list();
+        let selection = self.ui_selection.selection;
+        let visible_items = rows
+            .iter()
+            .enumerate()
             .skip(self.scroll.get_top())
-            .take(visible_list_height);
+            .take(visible_list_height)
+            .map(|(idx, row)| {
+                let row = match row {
+                    ProcessRow::Group(group, expanded) => ProcessRowView::Group(group, *expanded),
+                    ProcessRow::Process(idx) if self.group_by.is_some() => ProcessRowView::Member(&items[*idx]),
+                    ProcessRow::Process(idx) => ProcessRowView::Process(&items[*idx]),
+                };
+                (row, Some(idx) == selection)
+            });
 
         draw_process_list(
             f, 
@@ -409,8 +713,9 @@ impl DrawableComponent for ProcessComponent {
             else {
                 false
             },
-            self.config.theme_config.clone(),
-            self.sort.clone(),
+            &self.config,
+            &self.sort,
+            &self.block_title(),
         );
 
         self.scroll.draw(
@@ -439,28 +744,42 @@ impl DrawableComponent for ProcessComponent {
     }
 }
 
+// what a row of the process list displays
+enum ProcessRowView<'a> {
+    Group(&'a ProcessGroupItem, bool),
+    Process(&'a ProcessItem),
+    Member(&'a ProcessItem),            // process listed under its expanded group
+}
+
 fn draw_process_list<'a, I>(
     f: &mut Frame,
     area: Rect,
     visible_items: I,
     focus: bool,
-    theme_config: ThemeConfig,
-    sort_order: ProcessItemSortOrder,
+    config: &Config,
+    sort_order: &[ProcessItemSortOrder],
+    block_title: &str,
 )
 where
-    I: Iterator<Item = (usize, &'a ProcessItem, bool)>,
+    I: Iterator<Item = (ProcessRowView<'a>, bool)>,
 {
+    let theme_config = &config.theme_config;
+    let columns: Vec<&ProcessColumnConfig> = config.process_columns
+        .iter()
+        .filter(|column| column.visible)
+        .collect();
+
     // setting header
-    let header_labels = [
-        "",
-        &header_with_sort(&sort_order, &ProcessItemSortOrder::PidInc, &ProcessItemSortOrder::PidDec, "PID(p)"),
-        &header_with_sort(&sort_order, &ProcessItemSortOrder::NameInc, &ProcessItemSortOrder::NameDec, "NAME(n)"),
-        &header_with_sort(&sort_order, &ProcessItemSortOrder::CpuUsageInc, &ProcessItemSortOrder::CpuUsageDec, "CPU(c)(%)"),
-        &header_with_sort(&sort_order, &ProcessItemSortOrder::MemoryUsageInc, &ProcessItemSortOrder::MemoryUsageDec, "MEM(m)(MB)"),
-        "STATUS",
-        "RUNTIME",
-        //"PATH",
-    ];
+    let mut header_labels: Vec<String> = vec![String::new()];
+    for column in &columns {
+        let (inc, dec) = column.column.sort_orders();
+        // the cpu header names the cpu mode the usage is in
+        let header = match column.column {
+            ProcessColumn::Cpu => format!("CPU(c)(% {})", config.cpu_mode().title()),
+            _ => column.column.header().to_string(),
+        };
+        header_labels.push(header_with_sort(sort_order, &inc, &dec, &header));
+    }
 
     let header = header_labels
         .into_iter()
@@ -471,42 +790,33 @@ where
 
     // setting rows
     let rows = visible_items
-        .map(|(_idx, item, selected)| {
-            let style = compute_row_style(focus, selected, &theme_config);
+        .map(|(row, selected)| {
+            let style = compute_row_style(focus, selected, theme_config);
             let indicator = if style == theme_config.style_item_selected {
                 "->"
             } else {
                 ""
             };
 
-            let cells = vec![
-                Cell::from(indicator),
-                Cell::from(item.pid().to_string()),
-                Cell::from(format!("{:.40}", item.name())),
-                Cell::from(format!("{:.2}", item.cpu_usage())),
-                Cell::from(format!("{}",    byte_to_mb(item.memory_usage()))),
-                Cell::from(item.status()),
-                Cell::from(item.run_time_dd_hh_mm_ss()),
-                //Cell::from(item.path()),
-            ];
+            let mut cells = vec![Cell::from(indicator)];
+            for column in &columns {
+                let text = match &row {
+                    ProcessRowView::Group(group, expanded) => column.column.group_cell(group, *expanded, config.unit_system()),
+                    ProcessRowView::Process(item) => column.column.cell(item, config.unit_system()),
+                    // indenting member names under their group
+                    ProcessRowView::Member(item) if column.column == ProcessColumn::Name => format!("  {}", column.column.cell(item, config.unit_system())),
+                    ProcessRowView::Member(item) => column.column.cell(item, config.unit_system()),
+                };
+                cells.push(Cell::from(text));
+            }
             Row::new(cells).style(style)
         })
         .collect::<Vec<_>>();
 
     // setting width constraints
-    let widths =
-    vec![
-        Constraint::Length(2),  // arrow
-        Constraint::Percentage(10), // pid
-        Constraint::Percentage(30), // name
-        Constraint::Percentage(15), // cpu usage
-        Constraint::Percentage(15), // memory usage
-        Constraint::Percentage(15), // status
-        Constraint::Percentage(15), // run time
-    ];
+    let widths = column_widths(&config.process_columns);
 
     // setting block information
-    let block_title: &str = " Process List ";
     let block_style = if focus { theme_config.style_border_focused } else { theme_config.style_border_not_focused };
 
     // setting the table
@@ -519,33 +829,89 @@ where
     f.render_widget(table, area);
 }
 
-// helper function for building header labels
+// helper function for building width constraints, column widths are relative weights
+fn column_widths(columns: &[ProcessColumnConfig]) -> Vec<Constraint> {
+    let mut widths = vec![Constraint::Length(2)];  // arrow
+
+    for column in columns.iter().filter(|column| column.visible) {
+        widths.push(Constraint::Fill(column.width));
+    }
+
+    widths
+}
+
+// helper function for computing the (column, x, width) of each visible column in the table header,
+// mirrors the layout ratatui's Table uses: default column spacing of 1 and no highlight symbol
+fn header_columns(table_area: Rect, columns: &[ProcessColumnConfig]) -> Vec<(ProcessColumn, u16, u16)> {
+    let column_spacing = 1;
+    let inner = table_area.inner(&Margin { horizontal: 1, vertical: 1 });
+    let rects = Layout::horizontal(column_widths(columns))
+        .flex(layout::Flex::Start)
+        .spacing(column_spacing)
+        .split(Rect::new(inner.x, inner.y, inner.width, 1));
+
+    // skipping the arrow column, the spacing right of a column is considered part of it
+    columns
+        .iter()
+        .filter(|column| column.visible)
+        .zip(rects.iter().skip(1))
+        .map(|(column, rect)| (column.column, rect.x, rect.width.saturating_add(column_spacing)))
+        .collect()
+}
+
+// helper function for building header labels, secondary sort keys are numbered by priority
 fn header_with_sort(
-    current: &ProcessItemSortOrder,
+    sort: &[ProcessItemSortOrder],
     inc: &ProcessItemSortOrder,
     dec: &ProcessItemSortOrder,
     base: &str,
 ) -> String {
-    match current {
-        s if s == inc => format!("{base} ▲"),
-        s if s == dec => format!("{base} ▼"),
+    let position = sort.iter().position(|key| key == inc || key == dec);
+    let priority = match position {
+        Some(idx) if idx > 0 => (idx + 1).to_string(),
+        _ => String::new(),
+    };
+
+    match position.map(|idx| &sort[idx]) {
+        Some(s) if s == inc => format!("{base} ▲{priority}"),
+        Some(s) if s == dec => format!("{base} ▼{priority}"),
         _ => base.to_string(),
     }
 }
 
-// helper function for determining row style
-fn compute_row_style(focus: bool, selected: bool, theme: &ThemeConfig) -> Style {
-    match (focus, selected) {
-        (true, true) => theme.style_item_selected,
-        (true, false) => theme.style_item_focused,
-        (false, true) => theme.style_item_selected_not_focused,
-        _ => theme.style_item_not_focused,
+fn with_cpu_mode(mut processes: Vec<ProcessItem>, cpu_mode: CpuMode) -> Vec<ProcessItem> {
+    for process in processes.iter_mut() {
+        process.set_cpu_mode(cpu_mode);
+    }
+    processes
+}
+
+// helper function for making sort keys total: process list order changes between refreshes,
+// so ties left by the sort keys are broken by increasing pid to keep rows from jittering
+fn with_tie_breaker(sort: &[ProcessItemSortOrder]) -> Vec<ProcessItemSortOrder> {
+    let mut keys = sort.to_vec();
+
+    if !keys.iter().any(|key| matches!(key, ProcessItemSortOrder::PidInc | ProcessItemSortOrder::PidDec)) {
+        keys.push(ProcessItemSortOrder::PidInc);
     }
+
+    keys
+}
+
+// helper function for finding the owner of this process in a process list
+fn find_current_user(processes: &[ProcessItem]) -> Option<String> {
+    let pid = std::process::id();
+
+    processes
+        .iter()
+        .find(|item| item.pid() == pid)
+        .map(|item| item.user().to_string())
 }
 
 
 
 
+
 // TEST MODULE
 #[cfg(test)]
 mod test {
@@ -668,14 +1034,224 @@ mod test {
         assert!(component.vec_state.selection().is_some());
     }
     
+    #[test]
+    fn test_only_mine_toggle() {
+        let mut service = DummyService::new();
+        service.set(0);
+        let config = Config::default();
+        let mut component = ProcessComponent::new(config.clone(), &service);
+        let len = component.vec_state.view_indices().len();
+
+        // the current user is not known to the dummy service, toggling should not hide anything
+        assert!(component.current_user.is_none());
+        component.key_event(config.key_config.only_mine_toggle).unwrap();
+        assert!(component.only_mine);
+        assert_eq!(component.vec_state.view_indices().len(), len);
+
+        // once the current user is known, only their processes are listed
+        component.current_user = Some(String::from("alice"));
+        component.handle_scopes();
+        assert_eq!(component.vec_state.view_indices().len(), 2);
+
+        component.key_event(config.key_config.only_mine_toggle).unwrap();
+        assert!(!component.only_mine);
+        assert_eq!(component.vec_state.view_indices().len(), len);
+    }
+
+    #[test]
+    fn test_unit_scope() {
+        let config = Config::default();
+        let mut component = ProcessComponent::new(config.clone(), &DummyService::new());
+        component.vec_state.replace(vec![
+            ProcessItem::new(1, String::from("sshd"), 0.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/usr/sbin/sshd"))
+                .with_cgroup(String::from("/system.slice/sshd.service"), String::from("sshd.service")),
+            ProcessItem::new(2, String::from("bash"), 0.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/usr/bin/bash"))
+                .with_cgroup(String::from("/user.slice/user-1000.slice/session-2.scope"), String::from("session-2.scope")),
+            ProcessItem::new(3, String::from("vim"), 0.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/usr/bin/vim"))
+                .with_cgroup(String::from("/user.slice/user-1000.slice/session-2.scope"), String::from("session-2.scope")),
+        ]);
+
+        // slices include the processes of their child units
+        component.set_unit(Some((String::from("user-1000.slice"), String::from("/user.slice/user-1000.slice"))));
+        assert_eq!(component.vec_state.view_indices().len(), 2);
+        assert!(component.block_title().contains("user-1000.slice"));
+
+        component.set_unit(Some((String::from("sshd.service"), String::from("/system.slice/sshd.service"))));
+        assert_eq!(component.vec_state.view_indices(), vec![0]);
+        assert_eq!(component.vec_state.selection(), Some(0));
+
+        component.key_event(config.key_config.exit).unwrap();
+        assert!(component.unit.is_none());
+        assert_eq!(component.vec_state.view_indices().len(), 3);
+    }
+
+    #[test]
+    fn test_handle_mouse_click_on_header() {
+        let mut service = DummyService::new();
+        service.set(0);
+        let config = Config::default();
+        let mut component = ProcessComponent::new(config.clone(), &service);
+        let table_area = Rect::new(0, 0, 120, 20);
+        component.table_area = Some(table_area);
+
+        let header_y = table_area.y + 1;
+        let columns = header_columns(table_area, component.columns());
+        let (_, status_x, _) = *columns.iter().find(|(column, _, _)| *column == ProcessColumn::Status).unwrap();
+
+        // clicking a header sorts by its column, clicking again toggles direction
+        let click = Mouse { kind: MouseKind::LeftClick, column: status_x, row: header_y };
+        component.mouse_event(click).unwrap();
+        assert!(component.sort[0] == ProcessItemSortOrder::StatusDec);
+        component.mouse_event(click).unwrap();
+        assert!(component.sort[0] == ProcessItemSortOrder::StatusInc);
+
+        // clicking the arrow column or below the header does not change sort
+        component.mouse_event(Mouse { kind: MouseKind::LeftClick, column: 1, row: header_y }).unwrap();
+        assert!(component.sort[0] == ProcessItemSortOrder::StatusInc);
+        component.mouse_event(Mouse { kind: MouseKind::LeftClick, column: status_x, row: header_y + 1 }).unwrap();
+        assert!(component.sort[0] == ProcessItemSortOrder::StatusInc);
+
+        // middle click adds a secondary sort key
+        let (_, pid_x, _) = *columns.iter().find(|(column, _, _)| *column == ProcessColumn::Pid).unwrap();
+        component.mouse_event(Mouse { kind: MouseKind::MiddleClick, column: pid_x, row: header_y }).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::StatusInc, ProcessItemSortOrder::PidDec]);
+    }
+
+    #[test]
+    fn test_secondary_sort() {
+        let mut service = DummyService::new();
+        service.set(0);
+        let config = Config::default();
+        let key_config = &config.key_config;
+        let mut component = ProcessComponent::new(config.clone(), &service);
+
+        // status desc, then memory desc
+        component.handle_sort_column(ProcessColumn::Status);
+        component.key_event(key_config.sort_secondary).unwrap();
+        component.key_event(key_config.sort_memory_toggle).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::StatusDec, ProcessItemSortOrder::MemoryUsageDec]);
+
+        let view: Vec<u32> = component.vec_state
+            .iter_with_selection()
+            .map(|(_, item, _)| item.pid())
+            .collect();
+        // "Sleeping" rows first, ties sorted by memory decreasing
+        assert_eq!(&view[..3], &[4, 1, 10]);
+
+        // toggling the secondary key direction does not change the primary key
+        component.key_event(key_config.sort_secondary).unwrap();
+        component.key_event(key_config.sort_memory_toggle).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::StatusDec, ProcessItemSortOrder::MemoryUsageInc]);
+
+        // changing the primary key keeps secondary keys
+        component.key_event(key_config.sort_cpu_toggle).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::CpuUsageDec, ProcessItemSortOrder::MemoryUsageInc]);
+
+        // promoting a secondary key to primary removes it from the secondary keys
+        component.key_event(key_config.sort_memory_toggle).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::MemoryUsageDec]);
+
+        component.key_event(key_config.sort_secondary).unwrap();
+        component.key_event(key_config.sort_pid_toggle).unwrap();
+        component.key_event(key_config.sort_remove_secondary).unwrap();
+        component.key_event(key_config.sort_remove_secondary).unwrap();
+        assert!(component.sort == vec![ProcessItemSortOrder::MemoryUsageDec]);
+    }
+
+    #[test]
+    fn test_sort_ties_are_stable() {
+        let config = Config::default();
+        let items = vec![
+            ProcessItem::new(3, String::from("c"), 0.0, 1, 0, 0, 0, String::from("Sleeping"), String::from("test/")),
+            ProcessItem::new(1, String::from("a"), 0.0, 1, 0, 0, 0, String::from("Sleeping"), String::from("test/")),
+            ProcessItem::new(2, String::from("b"), 0.0, 1, 0, 0, 0, String::from("Sleeping"), String::from("test/")),
+        ];
+        let mut reversed = items.clone();
+        reversed.reverse();
+
+        let mut component = ProcessComponent::new(config.clone(), &DummyService::new());
+        let mut views: Vec<Vec<u32>> = Vec::new();
+        for items in [items, reversed] {
+            component.vec_state.replace(items);
+            views.push(component.vec_state.iter_with_selection().map(|(_, item, _)| item.pid()).collect());
+        }
+
+        // equal cpu usage, rows are ordered by pid regardless of model order
+        assert_eq!(views[0], vec![1, 2, 3]);
+        assert_eq!(views[0], views[1]);
+    }
+
+    #[test]
+    fn test_grouped_rows() {
+        let config = Config::default();
+        let mut component = ProcessComponent::new(config.clone(), &DummyService::new());
+        component.vec_state.replace(vec![
+            ProcessItem::new(1, String::from("chrome"), 1.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/opt/chrome")),
+            ProcessItem::new(2, String::from("postgres"), 5.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/usr/bin/postgres")),
+            ProcessItem::new(3, String::from("chrome"), 6.0, 10, 0, 0, 0, String::from("Sleeping"), String::from("/opt/chrome")),
+        ]);
+        component.handle_filter_selection();
+
+        component.key_event(config.key_config.group_toggle).unwrap();
+        assert_eq!(component.group_by, Some(ProcessGroupBy::Name));
+
+        // groups are sorted by summed cpu usage decreasing
+        let rows = component.rows();
+        assert_eq!(rows.len(), 2);
+        assert!(matches!(&rows[0], ProcessRow::Group(group, false) if group.key() == "chrome" && group.count() == 2));
+        assert!(matches!(&rows[1], ProcessRow::Group(group, false) if group.key() == "postgres"));
+        // group rows do not map to a process
+        assert!(component.vec_state.selection().is_none());
+
+        // expanding lists member processes, sorted, below their group
+        component.key_event(config.key_config.enter).unwrap();
+        let rows = component.rows();
+        assert_eq!(rows.len(), 4);
+        assert!(matches!(rows[1], ProcessRow::Process(2)));
+        assert!(matches!(rows[2], ProcessRow::Process(0)));
+
+        component.handle_move_selection(MoveSelection::Down);
+        assert_eq!(component.vec_state.selection(), Some(2));
+
+        // cycling through grouping modes back to the plain list
+        component.key_event(config.key_config.group_toggle).unwrap();
+        assert_eq!(component.group_by, Some(ProcessGroupBy::Exe));
+        component.key_event(config.key_config.group_toggle).unwrap();
+        assert_eq!(component.group_by, Some(ProcessGroupBy::Cgroup));
+        component.key_event(config.key_config.group_toggle).unwrap();
+        assert_eq!(component.group_by, None);
+        assert_eq!(component.rows().len(), 3);
+    }
+
+    #[test]
+    fn test_select_pid() {
+        let config = Config::default();
+        let mut component = ProcessComponent::new(config.clone(), &DummyService::new());
+
+        // Slack (pid 1) is hidden by the filter, which is cleared
+        component.vec_state.set_filter(Some("Chrome"));
+        component.handle_filter_selection();
+        assert!(component.select_pid(1));
+        assert!(component.vec_state.filter().is_none());
+        assert_eq!(component.selected_item().map(|item| item.pid()), Some(1));
+
+        // grouped, the group of the process is expanded
+        component.key_event(config.key_config.group_toggle).unwrap();
+        assert!(component.select_pid(9));
+        assert_eq!(component.selected_item().map(|item| item.name()), Some("Discord-Helper"));
+        assert!(component.expanded.contains("Discord-Helper"));
+
+        assert!(!component.select_pid(100));
+    }
+
     //TODO: add tests for mouse_event() and key_event()
 
     fn test_data(idx: usize) -> Vec<ProcessItem> {
         match idx {
             0 => {
                 return vec![
-                    ProcessItem::new(0, String::from("Discord"), 12.0, 12, 12, 12, 12, String::from("Runnable"), String::from("test/")),
-                    ProcessItem::new(1, String::from("Slack"), 8.5, 15, 15, 15, 15, String::from("Sleeping"), String::from("test/")),
+                    ProcessItem::new(0, String::from("Discord"), 12.0, 12, 12, 12, 12, String::from("Runnable"), String::from("test/")).with_user(String::from("alice")),
+                    ProcessItem::new(1, String::from("Slack"), 8.5, 15, 15, 15, 15, String::from("Sleeping"), String::from("test/")).with_user(String::from("alice")),
                     ProcessItem::new(2, String::from("Chrome"), 25.3, 40, 40, 40, 40, String::from("Runnable"), String::from("test/")),
                     ProcessItem::new(3, String::from("iTerm"), 9.0, 9, 9, 9, 9, String::from("Runnable"), String::from("test/")),
                     ProcessItem::new(4, String::from("Spotify"), 7.2, 22, 22, 22, 22, String::from("Sleeping"), String::from("test/")),
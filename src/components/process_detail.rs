@@ -0,0 +1,364 @@
+use std::io;
+use anyhow::{Ok, Result};
+use crate::input::*;
+use ratatui::{
+    Frame,
+    prelude::*,
+    widgets::*,
+};
+use crate::config::Config;
+use crate::services::PidVecProvider;
+use crate::components::utils::{selection::UISelection, vertical_scroll::VerticalScroll};
+use crate::components::{common_nav, compute_row_style, Component, DrawableComponent, EventState, MoveSelection, Refreshable};
+use crate::models::items::file_item::{FileItem, FileKind};
+use crate::models::items::process_item::ProcessItem;
+use crate::models::units::{format_bytes, UnitSystem};
+
+// ProcessDetailComponent is a popup showing a single process: its fields and its open file
+// descriptors (an lsof subset), with sockets resolved to their addresses and state. It is opened
+// from the process list and refreshed by App while visible.
+pub struct ProcessDetailComponent {
+    process: Option<ProcessItem>,
+    exited: bool,                       // the process is no longer in the process list
+    files: Vec<FileItem>,
+    error: Option<String>,              // why the open files could not be listed
+    ui_selection: UISelection,
+    scroll: VerticalScroll,
+    visible: bool,
+    pub config: Config,
+}
+
+impl ProcessDetailComponent {
+    pub fn new(config: Config) -> Self {
+        Self {
+            process: None,
+            exited: false,
+            files: Vec::new(),
+            error: None,
+            ui_selection: UISelection::new(None),
+            scroll: VerticalScroll::new(),
+            visible: false,
+            config,
+        }
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    pub fn show<S>(&mut self, process: ProcessItem, service: &S)
+    where S: PidVecProvider<FileItem>
+    {
+        self.process = Some(process);
+        self.exited = false;
+        self.ui_selection.set_selection(None);
+        self.visible = true;
+        self.refresh(service);
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.process.as_ref().map(|process| process.pid())
+    }
+
+    // updates the shown process fields, None if the process exited
+    pub fn set_process(&mut self, process: Option<ProcessItem>) {
+        match process {
+            Some(process) => self.process = Some(process),
+            None => self.exited = true,
+        }
+    }
+
+    // HELPERS::begin
+    // e.g. "12 open: 3 file, 5 socket, 4 pipe"
+    fn summary(&self) -> String {
+        let counts = FileKind::ALL
+            .iter()
+            .map(|kind| (kind, self.files.iter().filter(|file| file.kind() == *kind).count()))
+            .filter(|(_kind, count)| *count > 0)
+            .map(|(kind, count)| format!("{} {}", count, kind.title()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{} open: {}", self.files.len(), counts)
+    }
+
+    fn info_lines(&self) -> Vec<Line<'_>> {
+        let Some(process) = &self.process
+        else {
+            return Vec::new();
+        };
+
+        vec![
+            Line::from(format!(
+                "PID: {}  PPID: {}  User: {}  Status: {}{}",
+                process.pid(),
+                process.ppid(),
+                process.user(),
+                process.status(),
+                if self.exited { " (exited)" } else { "" },
+            )),
+            Line::from(format!(
+                "CPU: {:.2}%  Memory: {} ({:.1}%)  Threads: {}  Nice: {}  Runtime: {}",
+                process.cpu_usage(),
+                format_bytes(process.memory_usage(), self.config.unit_system()),
+                process.memory_percent(),
+                process.threads(),
+                process.nice(),
+                process.run_time_dd_hh_mm_ss(),
+            )),
+            Line::from(format!("Executable: {}", process.path())),
+            Line::from(format!("Command: {}", process.cmd())),
+            Line::from(format!("Cgroup: {}", process.cgroup())),
+        ]
+    }
+    // HELPERS::end
+}
+
+// helper function for describing why open files could not be listed
+fn fetch_error_text(error: &io::Error) -> String {
+    match error.kind() {
+        io::ErrorKind::PermissionDenied => String::from("Permission denied: open files of processes of other users can only be listed as root"),
+        io::ErrorKind::NotFound => String::from("The process has exited"),
+        _ => format!("Open files could not be listed: {}", error),
+    }
+}
+
+impl<S> Refreshable<S> for ProcessDetailComponent
+where
+    S: PidVecProvider<FileItem>
+{
+    fn refresh(&mut self, service: &S) {
+        let Some(pid) = self.pid()
+        else {
+            return;
+        };
+
+        match service.fetch_pid_items(pid) {
+            std::result::Result::Ok(files) => {
+                self.files = files;
+                self.error = None;
+            }
+            Err(error) => {
+                self.files.clear();
+                self.error = Some(fetch_error_text(&error));
+            }
+        }
+
+        let len = self.files.len();
+        let selection = if len == 0 {
+            None
+        }
+        else {
+            Some(self.ui_selection.selection.unwrap_or(0).min(len - 1))
+        };
+        self.ui_selection.set_selection(selection);
+    }
+}
+
+impl Component for ProcessDetailComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed)
+        }
+
+        if key == self.config.key_config.exit {
+            self.hide();
+            return Ok(EventState::Consumed)
+        }
+
+        if let Some(move_dir) = common_nav(key, &self.config.key_config) {
+            self.ui_selection.move_selection(move_dir, self.files.len());
+            return Ok(EventState::Consumed)
+        }
+
+        // the popup is modal
+        Ok(EventState::Consumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed)
+        }
+
+        match mouse.kind {
+            MouseKind::ScrollDown => self.ui_selection.move_selection(MoveSelection::Down, self.files.len()),
+            MouseKind::ScrollUp => self.ui_selection.move_selection(MoveSelection::Up, self.files.len()),
+            _ => {}
+        }
+
+        Ok(EventState::Consumed)
+    }
+}
+
+impl DrawableComponent for ProcessDetailComponent {
+    fn draw(&mut self, f: &mut Frame, _area: Rect, _focused: bool) -> Result<()> {
+        if !self.visible {
+            return Ok(())
+        }
+
+        let size = f.size();
+        let area = Rect::new(
+            size.width / 20,
+            size.height / 20,
+            size.width.saturating_sub(size.width / 10),
+            size.height.saturating_sub(size.height / 10),
+        );
+
+        let title = match &self.process {
+            Some(process) => format!(" {} ({}) ", process.name(), process.pid()),
+            None => String::from(" Process "),
+        };
+        let block = Block::default()
+            .title(title)
+            .title_bottom(format!(" [{:?}] close ", self.config.key_config.exit))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+        let inner = block.inner(area);
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let info_lines = self.info_lines();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(info_lines.len() as u16),
+                Constraint::Fill(1),
+            ])
+            .split(inner);
+
+        f.render_widget(Paragraph::new(info_lines), chunks[0]);
+
+        let files_title = match self.error {
+            Some(_) => String::from(" Open Files "),
+            None => format!(" Open Files ({}) ", self.summary()),
+        };
+        let files_block = Block::default()
+            .title(files_title)
+            .borders(Borders::TOP);
+
+        if let Some(error) = &self.error {
+            let paragraph = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .block(files_block);
+            f.render_widget(paragraph, chunks[1]);
+            return Ok(())
+        }
+
+        // header, top border
+        let visible_list_height = chunks[1].height.saturating_sub(2) as usize;
+        match self.ui_selection.selection {
+            Some(idx) => {
+                self.scroll.update(idx, self.files.len(), visible_list_height);
+            }
+            None => self.scroll.reset(),
+        }
+
+        let theme_config = &self.config.theme_config;
+        let rows = self.files
+            .iter()
+            .enumerate()
+            .skip(self.scroll.get_top())
+            .take(visible_list_height)
+            .map(|(idx, file)| {
+                let style = compute_row_style(true, Some(idx) == self.ui_selection.selection, theme_config);
+
+                Row::new(vec![
+                    Cell::from(file.fd().to_string()),
+                    Cell::from(file.kind().title()),
+                    Cell::from(file.description()),
+                ])
+                .style(style)
+            })
+            .collect::<Vec<_>>();
+
+        let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(11), Constraint::Fill(1)])
+            .header(Row::new(vec!["FD", "TYPE", "TARGET"]).style(theme_config.style_border_focused))
+            .block(files_block);
+
+        f.render_widget(table, chunks[1]);
+        self.scroll.draw(f, chunks[1], true)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::items::socket_item::SocketItem;
+
+    struct DummyService {
+        error: Option<io::ErrorKind>,
+    }
+
+    impl PidVecProvider<FileItem> for DummyService {
+        fn fetch_pid_items(&self, _pid: u32) -> io::Result<Vec<FileItem>> {
+            if let Some(kind) = self.error {
+                return Err(io::Error::from(kind));
+            }
+
+            std::result::Result::Ok(vec![
+                FileItem::new(0, FileKind::Device, String::from("/dev/null")),
+                FileItem::new(1, FileKind::Pipe, String::from("pipe:[13607]")),
+                FileItem::new(3, FileKind::Socket, String::from("socket:[40771]")).with_socket(SocketItem::new(
+                    String::from("tcp"),
+                    String::from("10.0.2.15:22"),
+                    String::from("10.0.2.2:54178"),
+                    String::from("ESTAB"),
+                    40771,
+                )),
+                FileItem::new(5, FileKind::Socket, String::from("socket:[99999]")),
+            ])
+        }
+    }
+
+    fn process() -> ProcessItem {
+        ProcessItem::new(1234, String::from("sshd"), 0.0, 0, 0, 0, 0, String::from("Sleeping"), String::from("/usr/sbin/sshd"))
+    }
+
+    #[test]
+    fn test_show() {
+        let config = Config::default();
+        let mut component = ProcessDetailComponent::new(config.clone());
+        component.show(process(), &DummyService { error: None });
+
+        assert!(component.is_visible());
+        assert_eq!(component.pid(), Some(1234));
+        assert_eq!(component.summary(), "4 open: 2 socket, 1 pipe, 1 device");
+        assert_eq!(component.files[2].description(), "tcp 10.0.2.15:22 -> 10.0.2.2:54178 ESTAB");
+        assert_eq!(component.files[3].description(), "socket:[99999]");
+        assert_eq!(component.ui_selection.selection, Some(0));
+
+        component.key_event(config.key_config.move_bottom).unwrap();
+        assert_eq!(component.ui_selection.selection, Some(3));
+
+        component.key_event(config.key_config.exit).unwrap();
+        assert!(!component.is_visible());
+    }
+
+    #[test]
+    fn test_permission_denied() {
+        let mut component = ProcessDetailComponent::new(Config::default());
+        component.show(process(), &DummyService { error: Some(io::ErrorKind::PermissionDenied) });
+
+        assert!(component.files.is_empty());
+        assert!(component.error.as_deref().unwrap().starts_with("Permission denied"));
+        assert_eq!(component.ui_selection.selection, None);
+
+        // the process exiting while the popup is open
+        component.set_process(None);
+        component.refresh(&DummyService { error: Some(io::ErrorKind::NotFound) });
+        assert!(component.exited);
+        assert_eq!(component.error.as_deref(), Some("The process has exited"));
+    }
+}
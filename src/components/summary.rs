@@ -0,0 +1,106 @@
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::components::{DrawableComponent, Refreshable};
+use crate::services::ItemProvider;
+use crate::models::items::summary_item::SummaryItem;
+use crate::models::units::{format_value, UnitSystem};
+use crate::config::Config;
+
+// SummaryComponent is the header above the panels answering "what's the load?": the host, its uptime,
+// the load average, the processes by state and the context switch and interrupt rates. It is not
+// focusable.
+pub struct SummaryComponent {
+    config: Config,
+    summary: SummaryItem,
+}
+
+impl SummaryComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: ItemProvider<SummaryItem>
+    {
+        Self {
+            config,
+            summary: service.fetch_item(),
+        }
+    }
+}
+
+impl<S> Refreshable<S> for SummaryComponent
+where
+    S: ItemProvider<SummaryItem>
+{
+    fn refresh(&mut self, service: &S) {
+        self.summary = service.fetch_item();
+    }
+}
+
+impl DrawableComponent for SummaryComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, _focused: bool) -> Result<()> {
+        let summary = &self.summary;
+        let load = summary.load_average();
+        let tasks = summary.tasks();
+
+        let host = format!(
+            "{} :: {} :: kernel {} :: up {}",
+            summary.host_name(),
+            summary.os_version(),
+            summary.kernel_version(),
+            summary.uptime(),
+        );
+
+        // load above the number of cpus means tasks are waiting to run
+        let load_style = if summary.is_overloaded() {
+            Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)
+        }
+        else {
+            Style::default()
+        };
+        let load = format!(
+            "load {:.2} {:.2} {:.2} ({} cpus)",
+            load.one,
+            load.five,
+            load.fifteen,
+            summary.cpu_count(),
+        );
+
+        let tasks_legend = format!(
+            "{} processes, {} threads: {} running, {} sleeping, ",
+            tasks.processes,
+            tasks.threads,
+            tasks.running,
+            tasks.sleeping,
+        );
+        let zombie_style = if tasks.zombie > 0 {
+            Style::default().fg(Color::LightRed)
+        }
+        else {
+            Style::default()
+        };
+
+        // rates always use decimal prefixes
+        let rates = format!(
+            "ctxt {} :: intr {}",
+            format_value(summary.context_switches_per_s(), UnitSystem::Si, "/s"),
+            format_value(summary.interrupts_per_s(), UnitSystem::Si, "/s"),
+        );
+
+        let header = Paragraph::new(vec![
+            Line::from(vec![
+                Span::raw(host),
+                Span::raw(" :: "),
+                Span::styled(load, load_style),
+            ]),
+            Line::from(vec![
+                Span::raw(tasks_legend),
+                Span::styled(format!("{} zombie", tasks.zombie), zombie_style),
+                Span::raw(" :: "),
+                Span::raw(rates),
+            ]),
+        ])
+            .style(self.config.theme_config.style_item_not_focused);
+
+        f.render_widget(header, area);
+
+        Ok(())
+    }
+}
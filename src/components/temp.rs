@@ -1,37 +1,115 @@
+use std::cmp::{max, min};
+use std::collections::BTreeMap;
+
 use anyhow::{Ok, Result};
-use ratatui::widgets::{Block, Borders, Table};
-use ratatui::prelude::*;
-use ratatui::Frame;
-use ratatui::widgets::Cell;
-use ratatui::widgets::Row;
+use ratatui::{Frame, prelude::*, widgets::*};
+use ratatui::layout::Position;
 
 use crate::input::*;
-
 use crate::components::Refreshable;
-use crate::services::sysinfo_service::SysInfoService;
+use crate::components::utils::selection::UISelection;
 use crate::components::utils::vertical_scroll::VerticalScroll;
-use crate::models::items::temp_item::TempItem;
+use crate::components::utils::history_chart::HistoryChart;
+use crate::models::bounded_queue_model::BoundedQueueModel;
+use crate::models::items::temp_item::{TempItem, TempLevel};
+use crate::models::units::UnitSystem;
 use crate::services::VecProvider;
-use crate::{components::EventState, config::Config};
-use super::{Component, DrawableComponent};
+use crate::config::Config;
+use crate::components::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Focus {
+    SensorList,
+    Chart,
+}
 
+// TempComponent lists the temperature sensors with their current, peak and critical
+// temperature, and charts the history of the selected sensor against its critical temperature.
+// Sensors approaching their critical temperature (where the hardware throttles) are yellow and
+// sensors close to it red. Sensors without a reading show N/A.
 pub struct TempComponent {
     config: Config,
-    temps: Vec<TempItem>,
-    ui_selection: usize,
-    vertical_scroll: VerticalScroll,
+    sensors: BTreeMap<String, BoundedQueueModel<TempItem>>,
+    ui_selection: UISelection,
+    scroll: VerticalScroll,
+    data_window_time_scale: u64,
+    list_area: Option<Rect>,
+    chart_area: Option<Rect>,
+    header_height: u16,
+    border_height: u16,
+    focus: Focus,
 }
 
 impl TempComponent {
-    pub fn new(config: Config, sysinfo: &SysInfoService) -> Self {
-        let temps: Vec<TempItem> = sysinfo.fetch_items();
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<TempItem>
+    {
+        let data_window_time_scale = config.min_time_scale();
 
-        Self {
+        let mut component = Self {
             config,
-            temps,
-            ui_selection: 0,
-            vertical_scroll: VerticalScroll::new(),
+            sensors: BTreeMap::new(),
+            ui_selection: UISelection::new(None),
+            scroll: VerticalScroll::new(),
+            data_window_time_scale,
+            list_area: None,
+            chart_area: None,
+            header_height: 1,
+            border_height: 1,
+            focus: Focus::SensorList,
+        };
+        component.refresh(service);
+        component
+    }
+
+    fn selected_sensor(&self) -> Option<&str> {
+        self.sensors
+            .keys()
+            .nth(self.ui_selection.selection?)
+            .map(|label| label.as_str())
+    }
+
+    fn selected_queue(&self) -> Option<&BoundedQueueModel<TempItem>> {
+        self.sensors.get(self.selected_sensor()?)
+    }
+
+    fn handle_move_selection(&mut self, dir: MoveSelection) {
+        let len = self.sensors.len();
+        self.ui_selection.move_selection(dir, len);
+    }
+
+    fn handle_time_scale(&mut self, dir: MoveSelection) {
+        self.data_window_time_scale = match dir {
+            MoveSelection::Down => min(self.data_window_time_scale.saturating_add(self.config.time_inc()), self.config.max_time_scale()),
+            _ => max(self.data_window_time_scale.saturating_sub(self.config.time_inc()), self.config.min_time_scale()),
+        };
+    }
+
+    // focuses the clicked side and selects the clicked sensor, returns true if the click is on the component
+    fn handle_mouse_click(&mut self, click_x: u16, click_y: u16) -> bool {
+        let position = Position { x: click_x, y: click_y };
+
+        if self.chart_area.is_some_and(|area| area.contains(position)) {
+            self.focus = Focus::Chart;
+            return true
+        }
+
+        let Some(list_area) = self.list_area.filter(|area| area.contains(position))
+        else {
+            return false
+        };
+        self.focus = Focus::SensorList;
+
+        let list_top = list_area.top().saturating_add(self.border_height).saturating_add(self.header_height);
+        let list_bottom = list_area.bottom().saturating_sub(self.border_height);
+        if click_y >= list_top && click_y < list_bottom {
+            let item_idx = self.scroll.get_top().saturating_add((click_y - list_top) as usize);
+            if item_idx < self.sensors.len() {
+                self.ui_selection.set_selection(Some(item_idx));
+            }
         }
+
+        true
     }
 }
 
@@ -39,111 +117,280 @@ impl<S> Refreshable<S> for TempComponent
 where S: VecProvider<TempItem>
 {
     fn refresh(&mut self, service: &S) {
-        self.temps = service.fetch_items();
+        let capacity = ( self.config.max_time_scale() / self.config.refresh_rate() ) as usize;
+        let selected = self.selected_sensor().map(String::from);
+
+        let temps: Vec<TempItem> = service.fetch_items();
+        self.sensors.retain(|label, _| temps.iter().any(|temp| temp.label() == label));
+        for temp in temps {
+            self.sensors
+                .entry(temp.label().to_string())
+                .or_insert_with(|| BoundedQueueModel::new(capacity))
+                .add_item(temp);
+        }
+
+        // keeping the selected sensor selected as sensors come and go, the first one if it is gone
+        let selection = selected
+            .and_then(|selected| self.sensors.keys().position(|label| *label == selected))
+            .or(if self.sensors.is_empty() { None } else { Some(0) });
+        self.ui_selection.set_selection(selection);
     }
 }
 
 impl Component for TempComponent {
     fn key_event(&mut self, key: Key) -> Result<EventState> {
-        let temps_max_idx = self.temps.len().saturating_sub(1);
+        let key_config = &self.config.key_config;
 
-        if key == self.config.key_config.move_down {
-            if self.ui_selection < temps_max_idx {
-                self.ui_selection = self.ui_selection.saturating_add(1);
+        match self.focus {
+            Focus::SensorList => {
+                if let Some(dir) = common_nav(key, key_config) {
+                    self.handle_move_selection(dir);
+                    return Ok(EventState::Consumed)
+                }
+            }
+            Focus::Chart => {
+                if key == key_config.move_down {
+                    self.handle_time_scale(MoveSelection::Down);
+                    return Ok(EventState::Consumed)
+                }
+                if key == key_config.move_up {
+                    self.handle_time_scale(MoveSelection::Up);
+                    return Ok(EventState::Consumed)
+                }
             }
-            return Ok(super::EventState::Consumed);
         }
-        if key == self.config.key_config.move_up {
-            self.ui_selection = self.ui_selection.saturating_sub(1);
-            return Ok(super::EventState::Consumed);
+
+        // key event to move focus
+        if key == key_config.filter {
+            match self.focus {
+                Focus::SensorList => { self.focus = Focus::Chart }
+                Focus::Chart => { self.focus = Focus::SensorList }
+            }
+            return Ok(EventState::Consumed)
         }
-        
-        Ok(super::EventState::NotConsumed)
+
+        Ok(EventState::NotConsumed)
     }
 
-    fn mouse_event(&mut self, _mouse: Mouse) -> Result<EventState> {
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match (self.focus, mouse.kind) {
+            (Focus::SensorList, MouseKind::ScrollDown) => {
+                self.handle_move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::SensorList, MouseKind::ScrollUp) => {
+                self.handle_move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::Chart, MouseKind::ScrollDown) => {
+                self.handle_time_scale(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            (Focus::Chart, MouseKind::ScrollUp) => {
+                self.handle_time_scale(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            (_, MouseKind::LeftClick) if self.handle_mouse_click(mouse.column, mouse.row) => {
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
         Ok(EventState::NotConsumed)
     }
 }
 
-//TODO: take a closer look at:
-//  1. VerticalScroll
-//  2. ListIter & ListItemsIter
 impl DrawableComponent for TempComponent {
     fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
-        let visible_height = area.height.saturating_sub(3) as usize;
-        
-        self.vertical_scroll.update(
-            self.ui_selection,
-            self.temps.len(),
-            visible_height,
-        );
+        let horizontal_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(50),             // sensor list
+                Constraint::Percentage(50),             // chart
+            ]).split(area);
+
+        // saving list and chart area for mouse clicks
+        self.list_area = Some(horizontal_chunks[0]);
+        self.chart_area = Some(horizontal_chunks[1]);
+
+        let theme_config = &self.config.theme_config;
+        let (list_style, chart_style) = match (focused, self.focus) {
+            (true, Focus::SensorList) => (theme_config.style_border_focused, theme_config.style_border_not_focused),
+            (true, Focus::Chart) => (theme_config.style_border_not_focused, theme_config.style_border_focused),
+            _ => (theme_config.style_border_not_focused, theme_config.style_border_not_focused),
+        };
+
+        // sensor list
+        let visible_list_height = horizontal_chunks[0]
+            .height
+            .saturating_sub(self.border_height)
+            .saturating_sub(self.border_height)
+            .saturating_sub(self.header_height) as usize;
 
-        let visible_items = self.temps.iter().skip(self.vertical_scroll.get_top()).take(visible_height);
+        self.ui_selection.selection.map_or_else(
+            || self.scroll.reset(),
+            |idx| {
+                self.scroll.update(idx, self.sensors.len(), visible_list_height);
+            },
+        );
 
-        let header = ["Sensor", "Temp", "Max Temp", "Critical Temp"]
+        let header = ["", "SENSOR", "TEMP(°C)", "PEAK", "CRIT"]
             .into_iter()
             .map(Cell::from)
             .collect::<Row>()
-            .style(
-                if focused {
-                    self.config.theme_config.style_border_focused
-                }
-                else {
-                    self.config.theme_config.style_border_not_focused
-                }
-            )
+            .style(list_style)
             .height(1);
 
-        let rows = visible_items
-            .map(|item| {
-                let style: Style =
-                    if focused && item.label().eq(self.temps[self.ui_selection].label()) {
-                        self.config.theme_config.style_item_selected       
-                    }
-                    else if focused {
-                        self.config.theme_config.style_item_focused
-                    }
-                    else if !focused && item.label().eq(self.temps[self.ui_selection].label()) {
-                        self.config.theme_config.style_item_selected_not_focused
-                    }
-                    else {
-                        self.config.theme_config.style_item_not_focused
-                    };
-                
-                let cells: Vec<Cell> = vec![
-                    Cell::from(item.label().to_string()),
-                    Cell::from(item.temp().to_string()),
-                    Cell::from(item.max_temp().to_string()),
-                    Cell::from(item.critical_temp().to_string()),
-                ];
-
-                Row::new(cells).style(style)
+        let rows = self.sensors
+            .values()
+            .filter_map(|queue| queue.back())
+            .enumerate()
+            .skip(self.scroll.get_top())
+            .take(visible_list_height)
+            .map(|(idx, temp)| {
+                let selected = Some(idx) == self.ui_selection.selection;
+                let style = compute_row_style(focused && self.focus == Focus::SensorList, selected, theme_config);
+                let indicator = if selected { "->" } else { "" };
+
+                let style = match temp.level() {
+                    TempLevel::Critical => style.fg(Color::Red).add_modifier(Modifier::BOLD),
+                    TempLevel::High => style.fg(Color::Yellow),
+                    TempLevel::Normal => style,
+                };
+
+                Row::new(vec![
+                    Cell::from(indicator),
+                    Cell::from(temp.label().to_string()),
+                    Cell::from(format_or_na(temp.temp())),
+                    Cell::from(format_or_na(temp.peak_temp())),
+                    Cell::from(format_or_na(temp.critical_temp())),
+                ])
+                .style(style)
             })
             .collect::<Vec<_>>();
 
         let widths = vec![
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
+            Constraint::Length(2),
+            Constraint::Fill(4),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
         ];
 
-        let block_title: &str = " Sensor Temperatures ";
-        let block_style = if focused {
-            self.config.theme_config.style_border_focused
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(" Sensor Temperatures "))
+            .style(list_style);
+
+        f.render_widget(table, horizontal_chunks[0]);
+        self.scroll.draw(f, horizontal_chunks[0], focused)?;
+
+        // history of the selected sensor
+        let refresh_rate = self.config.refresh_rate();              // default = 2,000 ms
+        let time_scale = self.data_window_time_scale;               // default = 60,000 ms
+        let data_window = (time_scale / refresh_rate) as usize;     // default = 30
+        let max_idx = data_window.saturating_sub(1);
+
+        let default_queue = BoundedQueueModel::new(0);
+        let queue = self.selected_queue().unwrap_or(&default_queue);
+
+        // readings missing in between are left out of the line
+        let temp_data: Vec<(f64, f64)> = queue
+            .iter()
+            .rev()
+            .take(data_window)
+            .enumerate()
+            .filter_map(|(idx, temp)| {
+                temp.temp().map(|value| (max_idx.saturating_sub(idx) as f64, value as f64))
+            })
+            .collect();
+
+        let critical_temp = queue.back()
+            .and_then(|temp| temp.critical_temp())
+            .filter(|critical_temp| *critical_temp > 0_f32);
+
+        let mut series = vec![(temp_data, Color::LightYellow)];
+        if let Some(critical_temp) = critical_temp {
+            series.push((vec![(0_f64, critical_temp as f64), (max_idx as f64, critical_temp as f64)], Color::Red));
         }
-        else {
-            self.config.theme_config.style_border_not_focused
+
+        let chart = HistoryChart {
+            series,
+            unit_system: UnitSystem::Si,
+            unit: String::from("°C"),
+            data_window,
+            time_scale,
+            upper: critical_temp.map(|critical_temp| critical_temp as f64 + 10_f64),
         };
 
-        let table = Table::new(rows, widths)
-            .header(header)
-            .block(Block::default().borders(Borders::ALL).title(block_title))
-            .style(block_style);
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ]).split(horizontal_chunks[1]);
+
+        chart.draw(f, vertical_chunks[1], Borders::LEFT|Borders::BOTTOM|Borders::RIGHT, chart_style);
+
+        // building legend
+        let legend = queue.back().map_or_else(String::new, |temp| {
+            format!("NOW {} :: CRIT {}", format_or_na(temp.temp()), format_or_na(temp.critical_temp()))
+        });
+
+        let legend = Paragraph::new(Line::from(legend).right_aligned())
+            .block(Block::new()
+                .borders(Borders::LEFT|Borders::TOP|Borders::RIGHT)
+                .style(chart_style)
+                .title(format!(" {} ", self.selected_sensor().unwrap_or("No sensors")))
+            );
+
+        f.render_widget(legend, vertical_chunks[0]);
 
-        f.render_widget(table, area);
-        
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn format_or_na(temp: Option<f32>) -> String {
+    temp.map_or_else(|| String::from("N/A"), |temp| format!("{:.1}", temp))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService {
+        temps: Vec<(&'static str, Option<f32>)>,
+    }
+
+    impl VecProvider<TempItem> for DummyService {
+        fn fetch_items(&self) -> Vec<TempItem> {
+            self.temps
+                .iter()
+                .map(|(label, temp)| TempItem::new(*temp, None, Some(100_f32), label.to_string()))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_sensor_history() {
+        let config = Config::default();
+        let mut component = TempComponent::new(config.clone(), &DummyService {
+            temps: vec![("coretemp Core 0", Some(50_f32)), ("nvme Composite", None)],
+        });
+        assert_eq!(component.selected_sensor(), Some("coretemp Core 0"));
+
+        component.key_event(config.key_config.move_down).unwrap();
+        assert_eq!(component.selected_sensor(), Some("nvme Composite"));
+
+        // history is kept per sensor, the selection follows the sensor as others appear
+        component.refresh(&DummyService {
+            temps: vec![("acpitz temp1", Some(40_f32)), ("coretemp Core 0", Some(55_f32)), ("nvme Composite", Some(42_f32))],
+        });
+        assert_eq!(component.selected_sensor(), Some("nvme Composite"));
+        assert_eq!(component.selected_queue().map(|queue| queue.items().len()), Some(2));
+        assert_eq!(component.sensors["coretemp Core 0"].back().and_then(|temp| temp.temp()), Some(55_f32));
+
+        assert_eq!(format_or_na(None), "N/A");
+        assert_eq!(format_or_na(Some(55_f32)), "55.0");
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::{Ok, Result};
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::config::*;
+use crate::input::{Key, Mouse, MouseKind};
+use crate::services::VecProvider;
+use crate::components::utils::sortable_table::{SortableColumn, SortableTable};
+use crate::components::*;
+use crate::models::units::{format_bytes, format_data_rate, DataUnit, UnitSystem};
+use crate::models::items::process_item::CpuMode;
+use crate::models::items::unit_item::{UnitItem, UnitItemSortOrder};
+use crate::models::items::pressure_item::PressureLevel;
+
+// columns of the unit list
+const UNIT_COLUMNS: [SortableColumn<UnitItemSortOrder>; 7] = [
+    SortableColumn::sorted("UNIT(n)", UnitItemSortOrder::NameInc, UnitItemSortOrder::NameDec, 40),
+    SortableColumn::sorted("CPU(c)(%)", UnitItemSortOrder::CpuUsageInc, UnitItemSortOrder::CpuUsageDec, 12),
+    SortableColumn::sorted("MEM(m)", UnitItemSortOrder::MemoryUsageInc, UnitItemSortOrder::MemoryUsageDec, 13),
+    SortableColumn::sorted("PIDS(p)", UnitItemSortOrder::PidsInc, UnitItemSortOrder::PidsDec, 10),
+    SortableColumn::sorted("READ", UnitItemSortOrder::ReadRateInc, UnitItemSortOrder::ReadRateDec, 12),
+    SortableColumn::sorted("WRITE", UnitItemSortOrder::WriteRateInc, UnitItemSortOrder::WriteRateDec, 12),
+    SortableColumn::sorted("PSI(%)", UnitItemSortOrder::PressureInc, UnitItemSortOrder::PressureDec, 9),
+];
+
+// UnitComponent lists the systemd units of the host with the resource usage of their cgroups,
+// similar to systemd-cgtop. Pressing enter on a unit requests a drill in, App then scopes the
+// process list to the unit's cgroup, see take_drill_in.
+pub struct UnitComponent {
+    table: SortableTable<UnitItem, UnitItemSortOrder>,
+    drill_in: Option<UnitItem>,                 // unit to show the member processes of, taken by App
+    pub config: Config,
+}
+
+impl UnitComponent {
+    pub fn new<S>(config: Config, service: &S) -> Self
+    where S: VecProvider<UnitItem>
+    {
+        let units = with_cpu_mode(service.fetch_items(), config.cpu_mode());
+        let mut table = SortableTable::new(&UNIT_COLUMNS, units, UnitItemSortOrder::CpuUsageDec);
+        table.set_header(1, cpu_header(config.cpu_mode()));
+
+        Self {
+            table,
+            drill_in: None,
+            config,
+        }
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.config.set_unit_system(unit_system);
+    }
+
+    // rescales the cpu usage of the listed units like the process list's
+    pub fn set_cpu_mode(&mut self, cpu_mode: CpuMode) {
+        self.config.set_cpu_mode(cpu_mode);
+        let units = with_cpu_mode(self.table.vec_state().list().to_vec(), cpu_mode);
+        self.table.replace(units, |unit| unit.cgroup().to_string());
+        self.table.set_header(1, cpu_header(cpu_mode));
+    }
+
+    pub fn take_drill_in(&mut self) -> Option<UnitItem> {
+        self.drill_in.take()
+    }
+
+    // maps a sort key to its column, returns true if key is a sort key
+    fn handle_sort(&mut self, key: Key) -> bool {
+        let key_config = &self.config.key_config;
+
+        let column = if key == key_config.sort_name_toggle {
+            0
+        }
+        else if key == key_config.sort_cpu_toggle {
+            1
+        }
+        else if key == key_config.sort_memory_toggle {
+            2
+        }
+        else if key == key_config.sort_pid_toggle {
+            3
+        }
+        else {
+            return false;
+        };
+
+        self.table.sort_column(column);
+        true
+    }
+}
+
+impl<S> Refreshable<S> for UnitComponent
+where
+    S: VecProvider<UnitItem>
+{
+    fn refresh(&mut self, service: &S) {
+        let units = with_cpu_mode(service.fetch_items(), self.config.cpu_mode());
+        self.table.replace(units, |unit| unit.cgroup().to_string());
+    }
+}
+
+impl Component for UnitComponent {
+    fn key_event(&mut self, key: Key) -> Result<EventState> {
+        if let Some(move_dir) = common_nav(key, &self.config.key_config) {
+            self.table.move_selection(move_dir);
+            return Ok(EventState::Consumed)
+        }
+
+        if self.handle_sort(key) {
+            return Ok(EventState::Consumed)
+        }
+
+        if key == self.config.key_config.enter {
+            self.drill_in = self.table.selected().cloned();
+            return Ok(EventState::Consumed)
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+
+    fn mouse_event(&mut self, mouse: Mouse) -> Result<EventState> {
+        match mouse.kind {
+            MouseKind::ScrollDown => {
+                self.table.move_selection(MoveSelection::Down);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::ScrollUp => {
+                self.table.move_selection(MoveSelection::Up);
+                return Ok(EventState::Consumed)
+            }
+            MouseKind::LeftClick if self.table.handle_mouse_click(mouse.column, mouse.row) => {
+                return Ok(EventState::Consumed)
+            }
+            _ => {}
+        }
+
+        Ok(EventState::NotConsumed)
+    }
+}
+
+impl DrawableComponent for UnitComponent {
+    fn draw(&mut self, f: &mut Frame, area: Rect, focused: bool) -> Result<()> {
+        let len = self.table.row_count();
+        let title = if len == 0 {
+            String::from(" Units (cgroup v2 not available) ")
+        }
+        else {
+            format!(" Units ({}) ", len)
+        };
+
+        let config = &self.config;
+        let unit_system = config.unit_system();
+
+        self.table.draw(f, area, focused, title, &config.theme_config, |unit, style| {
+            let cells = vec![
+                Cell::from(unit.name().to_string()),
+                Cell::from(format_or_na(unit.cpu_usage(), |cpu_usage| format!("{:.2}", cpu_usage))),
+                Cell::from(format_or_na(unit.memory_usage(), |memory| format_bytes(memory, unit_system))),
+                Cell::from(format_or_na(unit.pids(), |pids| pids.to_string())),
+                Cell::from(format_or_na(unit.read_bytes_per_s(), |bytes| format_data_rate(bytes as f64, unit_system, DataUnit::Bytes))),
+                Cell::from(format_or_na(unit.written_bytes_per_s(), |bytes| format_data_rate(bytes as f64, unit_system, DataUnit::Bytes))),
+                pressure_cell(unit.pressure(), config),
+            ];
+            (cells, style)
+        })
+    }
+}
+
+fn with_cpu_mode(mut units: Vec<UnitItem>, cpu_mode: CpuMode) -> Vec<UnitItem> {
+    for unit in units.iter_mut() {
+        unit.set_cpu_mode(cpu_mode);
+    }
+    units
+}
+
+// the cpu header names the cpu mode the usage is in
+fn cpu_header(cpu_mode: CpuMode) -> String {
+    format!("CPU(c)(% {})", cpu_mode.title())
+}
+
+// stalled units stand out from the rest of the row
+fn pressure_cell(pressure: Option<f32>, config: &Config) -> Cell<'static> {
+    let cell = Cell::from(format_or_na(pressure, |pressure| format!("{:.1}", pressure)));
+
+    match pressure.map(|pressure| PressureLevel::of(pressure, config.pressure_warning(), config.pressure_critical())) {
+        Some(PressureLevel::Critical) => cell.style(Style::default().fg(Color::Red)),
+        Some(PressureLevel::Warning) => cell.style(Style::default().fg(Color::Yellow)),
+        _ => cell,
+    }
+}
+
+fn format_or_na<T>(value: Option<T>, format: impl Fn(T) -> String) -> String {
+    value.map_or_else(|| String::from("N/A"), format)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct DummyService {
+        nginx_cpu_usage: f32,
+    }
+
+    const DUMMY_SERVICE: DummyService = DummyService { nginx_cpu_usage: 12.0 };
+
+    impl VecProvider<UnitItem> for DummyService {
+        fn fetch_items(&self) -> Vec<UnitItem> {
+            vec![
+                UnitItem::new(String::from("sshd.service"), String::from("/system.slice/sshd.service"), Some(0.5), Some(8388608), Some(3), Some(0), Some(0)),
+                UnitItem::new(String::from("nginx.service"), String::from("/system.slice/nginx.service"), Some(self.nginx_cpu_usage), Some(67108864), Some(9), Some(1024), Some(0)),
+                UnitItem::new(String::from("session-2.scope"), String::from("/user.slice/user-1000.slice/session-2.scope"), None, Some(4194304), None, None, None),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let config = Config::default();
+        let mut component = UnitComponent::new(config.clone(), &DUMMY_SERVICE);
+        let names = |component: &UnitComponent| -> Vec<String> {
+            component.table.vec_state().iter_with_selection().map(|(_, unit, _)| unit.name().to_string()).collect()
+        };
+
+        // cpu decreasing, unknown usage last
+        assert_eq!(names(&component), vec!["nginx.service", "sshd.service", "session-2.scope"]);
+
+        component.key_event(config.key_config.sort_name_toggle).unwrap();
+        assert_eq!(names(&component), vec!["sshd.service", "session-2.scope", "nginx.service"]);
+        component.key_event(config.key_config.sort_name_toggle).unwrap();
+        assert_eq!(names(&component), vec!["nginx.service", "session-2.scope", "sshd.service"]);
+    }
+
+    #[test]
+    fn test_drill_in() {
+        let config = Config::default();
+        let mut component = UnitComponent::new(config.clone(), &DUMMY_SERVICE);
+        assert!(component.take_drill_in().is_none());
+
+        component.key_event(config.key_config.move_down).unwrap();
+        component.key_event(config.key_config.enter).unwrap();
+
+        let unit = component.take_drill_in().unwrap();
+        assert_eq!(unit.cgroup(), "/system.slice/sshd.service");
+        assert!(component.take_drill_in().is_none());
+    }
+
+    #[test]
+    fn test_refresh_keeps_selection() {
+        let config = Config::default();
+        let mut component = UnitComponent::new(config.clone(), &DUMMY_SERVICE);
+
+        // sshd.service is second by cpu usage, then first after nginx.service drops
+        component.key_event(config.key_config.move_down).unwrap();
+        component.refresh(&DummyService { nginx_cpu_usage: 0.1 });
+
+        assert_eq!(component.table.selected().map(|unit| unit.name()), Some("sshd.service"));
+        assert_eq!(component.table.ui_selection(), Some(0));
+    }
+
+    #[test]
+    fn test_cpu_mode() {
+        struct CountedService;
+
+        impl VecProvider<UnitItem> for CountedService {
+            fn fetch_items(&self) -> Vec<UnitItem> {
+                // busy on 3 of 8 logical cpus, 4 physical cores
+                vec![UnitItem::new(String::from("nginx.service"), String::from("/system.slice/nginx.service"), Some(300.0), None, None, None, None)
+                    .with_cpu_counts(8, 4)]
+            }
+        }
+
+        let mut config = Config::default();
+        config.set_cpu_mode(CpuMode::Irix);
+        let mut component = UnitComponent::new(config, &CountedService);
+        let cpu_usage = |component: &UnitComponent| component.table.selected().and_then(|unit| unit.cpu_usage());
+        assert_eq!(cpu_usage(&component), Some(300.0));
+
+        component.set_cpu_mode(CpuMode::Solaris);
+        assert_eq!(cpu_usage(&component), Some(37.5));
+
+        // refreshes keep the mode
+        component.refresh(&CountedService);
+        assert_eq!(cpu_usage(&component), Some(37.5));
+
+        component.set_cpu_mode(CpuMode::Physical);
+        assert_eq!(cpu_usage(&component), Some(75.0));
+    }
+}
@@ -0,0 +1,87 @@
+use ratatui::{Frame, prelude::*, symbols::Marker, widgets::*};
+use crate::config::ms_to_s;
+use crate::models::bounded_queue_model::BoundedQueueModel;
+use crate::models::units::{fixed_axis, nice_axis, UnitSystem};
+
+// helper function for building a chart series of the last data_window items, the newest at the right
+pub fn series<T, F>(queue: &BoundedQueueModel<T>, data_window: usize, value: F) -> Vec<(f64, f64)>
+where F: Fn(&T) -> f64
+{
+    let max_idx = data_window.saturating_sub(1);
+
+    queue
+        .iter()
+        .rev()
+        .take(data_window)
+        .enumerate()
+        .map(|(idx, item)| {
+            (
+                max_idx.saturating_sub(idx) as f64,
+                value(item),
+            )
+        })
+        .collect()
+}
+
+// line chart of the history of one or more series (e.g. tx and rx), the y-axis is scaled to fit
+// the series on round ticks unless a fixed upper bound (e.g. 100%, a critical temperature) is set
+pub struct HistoryChart {
+    pub series: Vec<(Vec<(f64, f64)>, Color)>,
+    pub unit_system: UnitSystem,
+    pub unit: String,
+    pub data_window: usize,
+    pub time_scale: u64,
+    pub upper: Option<f64>,
+}
+
+impl HistoryChart {
+    pub fn draw(&self, f: &mut Frame, area: Rect, borders: Borders, style: Style) {
+        let max_idx = self.data_window.saturating_sub(1);
+
+        let y_scale = match self.upper {
+            Some(upper) => fixed_axis(upper, &self.unit, 3),
+            None => {
+                // getting upper bound on y
+                let max_y = self.series
+                    .iter()
+                    .flat_map(|(data, _)| data.iter())
+                    .map(|tuple| tuple.1)
+                    .fold(0.0, f64::max);
+
+                nice_axis(max_y, self.unit_system, &self.unit, 3)
+            }
+        };
+
+        let datasets = self.series
+            .iter()
+            .map(|(data, color)| {
+                Dataset::default()
+                    .data(data)
+                    .graph_type(GraphType::Line)
+                    .marker(Marker::Braille)
+                    .style(Style::new().fg(*color))
+            })
+            .collect();
+
+        // building chart
+        let x_axis = Axis::default()
+            .bounds([0.0, max_idx as f64])
+            .labels(vec![Span::raw(format!("{}s", ms_to_s(self.time_scale))), Span::raw("now")])
+            .labels_alignment(Alignment::Right);
+
+        let y_axis = Axis::default()
+            .bounds([0.0, y_scale.upper])
+            .labels(y_scale.labels.into_iter().map(Span::raw).collect())
+            .labels_alignment(Alignment::Right);
+
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .borders(borders)
+                .style(style)
+        )
+        .x_axis(x_axis)
+        .y_axis(y_axis);
+
+        f.render_widget(chart, area);
+    }
+}
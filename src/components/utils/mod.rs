@@ -1,2 +1,4 @@
 pub mod vertical_scroll;
-pub mod selection;
\ No newline at end of file
+pub mod selection;
+pub mod history_chart;
+pub mod sortable_table;
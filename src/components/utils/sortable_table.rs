@@ -0,0 +1,387 @@
+use anyhow::Result;
+use ratatui::{Frame, prelude::*, widgets::*};
+use crate::components::utils::{selection::UISelection, vertical_scroll::VerticalScroll};
+use crate::components::{compute_row_style, DrawableComponent, MoveSelection};
+use crate::config::ThemeConfig;
+use crate::models::{Filterable, Sortable};
+use crate::states::vec_state::VecState;
+
+// a column of a sortable table, columns without sort orders are not sorted when clicked
+pub struct SortableColumn<S: 'static> {
+    header: &'static str,
+    sort: Option<(S, S)>,       // (increasing, decreasing)
+    width: u16,
+}
+
+impl<S> SortableColumn<S> {
+    pub const fn sorted(header: &'static str, inc: S, dec: S, width: u16) -> Self {
+        Self {
+            header,
+            sort: Some((inc, dec)),
+            width,
+        }
+    }
+
+    pub const fn unsorted(header: &'static str, width: u16) -> Self {
+        Self {
+            header,
+            sort: None,
+            width,
+        }
+    }
+}
+
+// list of items drawn as a table with a selection arrow, sortable by clicking on the column headers.
+// keeps the ui selection (row in the sorted and filtered view) and the selection of the VecState
+// (index of the item) in sync while scrolling, sorting and refreshing, panels only supply the
+// columns and the cells of each row
+pub struct SortableTable<T, S: 'static> {
+    vec_state: VecState<T, S>,
+    ui_selection: UISelection,
+    columns: &'static [SortableColumn<S>],
+    headers: Vec<String>,                       // column headers, panels may relabel a column, see set_header
+    sort: S,
+    decreasing_first: bool,                     // direction a column is sorted in when first selected
+    table_area: Option<Rect>,
+    header_height: u16,
+    border_height: u16,
+    scroll: VerticalScroll,
+}
+
+impl<T, S> SortableTable<T, S>
+where
+    T: Filterable + Sortable<S>,
+    S: Copy + PartialEq,
+{
+    pub fn new(columns: &'static [SortableColumn<S>], items: Vec<T>, sort: S) -> Self {
+        let ui_selection: UISelection = if items.is_empty() { UISelection::new(None) } else { UISelection::new(Some(0)) };
+        let vec_state = VecState::new(items, ui_selection.selection, Some(sort), None);
+
+        let mut table = Self {
+            vec_state,
+            ui_selection,
+            columns,
+            headers: columns.iter().map(|column| column.header.to_string()).collect(),
+            sort,
+            decreasing_first: true,
+            table_area: None,
+            header_height: 1,
+            border_height: 1,
+            scroll: VerticalScroll::new(),
+        };
+        table.refresh_selection();
+        table
+    }
+
+    pub fn with_increasing_first(mut self) -> Self {
+        self.decreasing_first = false;
+        self
+    }
+
+    // relabels a column, e.g. with the mode a value is shown in
+    pub fn set_header(&mut self, column: usize, header: String) {
+        if let Some(label) = self.headers.get_mut(column) {
+            *label = header;
+        }
+    }
+
+    // GETTERS::begin
+    pub fn vec_state(&self) -> &VecState<T, S> {
+        &self.vec_state
+    }
+
+    pub fn ui_selection(&self) -> Option<usize> {
+        self.ui_selection.selection
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.vec_state.selection()
+            .and_then(|idx| self.vec_state.list().get(idx))
+    }
+
+    pub fn sort(&self) -> S {
+        self.sort
+    }
+
+    // number of rows after filtering
+    pub fn row_count(&self) -> usize {
+        self.vec_state.view_indices().len()
+    }
+    // GETTERS::end
+
+    // SELECTION HANDLERS::begin
+    pub fn move_selection(&mut self, dir: MoveSelection) {
+        let len = self.row_count();
+        self.ui_selection.move_selection(dir, len);
+        self.sync_selection();
+    }
+
+    fn refresh_selection(&mut self) {
+        let len = self.row_count();
+
+        let new_ui_selection: Option<usize> =
+        if len == 0 {
+            None
+        }
+        else {
+            Some(self.ui_selection.selection.unwrap_or(0).min(len - 1))
+        };
+
+        self.ui_selection.set_selection(new_ui_selection);
+        self.sync_selection();
+    }
+
+    fn sync_selection(&mut self) {
+        let vec_idx = self.ui_selection.selection
+            .and_then(|ui_selection| self.vec_state.view_indices().get(ui_selection).cloned());
+        self.vec_state.set_selection(vec_idx);
+    }
+
+    // replaces the items, keeping the selected item selected as its position in the sort changes.
+    // items are matched by key, e.g. the cgroup of a unit
+    pub fn replace<K, F>(&mut self, items: Vec<T>, key: F)
+    where
+        K: PartialEq,
+        F: Fn(&T) -> K,
+    {
+        let selected = self.selected().map(&key);
+
+        self.vec_state.replace(items);
+
+        if let Some(selected) = selected {
+            let position = self.vec_state
+                .view_indices()
+                .iter()
+                .position(|idx| key(&self.vec_state.list()[*idx]) == selected);
+            if position.is_some() {
+                self.ui_selection.set_selection(position);
+            }
+        }
+        self.refresh_selection();
+    }
+
+    // filters the items, the selection moves to the first match
+    pub fn set_filter(&mut self, filter: Option<&str>) {
+        self.vec_state.set_filter(filter);
+        self.ui_selection.set_selection(None);
+        self.refresh_selection();
+    }
+    // SELECTION HANDLERS::end
+
+    // SORT HANDLERS::begin
+    // sorts by the column if it is sortable, toggling the direction if already sorting by it
+    pub fn sort_column(&mut self, column: usize) {
+        let Some((inc, dec)) = self.columns.get(column).and_then(|column| column.sort)
+        else {
+            return;
+        };
+        let (first, second) = if self.decreasing_first { (dec, inc) } else { (inc, dec) };
+
+        self.sort = if self.sort == first {
+            second
+        }
+        else {
+            first
+        };
+        self.vec_state.set_sort(Some(self.sort));
+        self.refresh_selection();
+    }
+    // SORT HANDLERS::end
+
+    // MOUSE CLICK HANDLERS::begin
+    // sorts by the clicked header column or selects the clicked row, returns true if the click is on the table
+    pub fn handle_mouse_click(&mut self, click_x: u16, click_y: u16) -> bool {
+        let Some(table_area) = self.table_area
+        else {
+            return false;
+        };
+        let header_top = table_area.top().saturating_add(self.border_height);
+        let list_top = header_top.saturating_add(self.header_height);
+        let list_bottom = table_area.bottom().saturating_sub(self.border_height);
+
+        if click_y < header_top || click_y >= list_bottom {
+            return false;
+        }
+
+        if click_y == header_top {
+            let rects = Layout::horizontal(self.column_widths())
+                .flex(layout::Flex::Start)
+                .spacing(1)
+                .split(table_area.inner(&Margin { horizontal: 1, vertical: 1 }));
+            let column = rects
+                .iter()
+                .skip(1)
+                .position(|rect| click_x >= rect.x && click_x <= rect.right());
+            if let Some(column) = column {
+                self.sort_column(column);
+            }
+            return true;
+        }
+
+        let item_idx = self.scroll.get_top().saturating_add((click_y - list_top) as usize);
+        if item_idx < self.scroll.get_count() {
+            self.ui_selection.set_selection(Some(item_idx));
+            self.sync_selection();
+        }
+
+        true
+    }
+    // MOUSE CLICK HANDLERS::end
+
+    // draws the visible rows, row returns the cells of an item and its style given the selection style,
+    // e.g. to flag items in red
+    pub fn draw<'a, F>(&mut self, f: &mut Frame, area: Rect, focused: bool, title: String, theme_config: &ThemeConfig, row: F) -> Result<()>
+    where F: Fn(&T, Style) -> (Vec<Cell<'a>>, Style)
+    {
+        self.table_area = Some(area);
+
+        let visible_list_height = area
+            .height
+            .saturating_sub(self.border_height)
+            .saturating_sub(self.border_height)
+            .saturating_sub(self.header_height) as usize;
+
+        let len = self.row_count();
+        self.ui_selection.selection.map_or_else(
+            || self.scroll.reset(),
+            |idx| {
+                self.scroll.update(idx, len, visible_list_height);
+            },
+        );
+
+        let block_style = if focused { theme_config.style_border_focused } else { theme_config.style_border_not_focused };
+
+        let header = self.columns
+            .iter()
+            .zip(&self.headers)
+            .map(|(column, label)| match column.sort {
+                Some((inc, _)) if self.sort == inc => format!("{} ▲", label),
+                Some((_, dec)) if self.sort == dec => format!("{} ▼", label),
+                _ => label.clone(),
+            })
+            .fold(vec![Cell::from("")], |mut cells, label| {
+                cells.push(Cell::from(label));
+                cells
+            })
+            .into_iter()
+            .collect::<Row>()
+            .style(block_style)
+            .height(1);
+
+        let rows = self.vec_state
+            .iter_with_selection()
+            .skip(self.scroll.get_top())
+            .take(visible_list_height)
+            .map(|(_idx, item, selected)| {
+                let style = compute_row_style(focused, selected, theme_config);
+                let indicator = if style == theme_config.style_item_selected { "->" } else { "" };
+                let (cells, style) = row(item, style);
+
+                let mut row_cells = vec![Cell::from(indicator)];
+                row_cells.extend(cells);
+                Row::new(row_cells).style(style)
+            })
+            .collect::<Vec<_>>();
+
+        let table = Table::new(rows, self.column_widths())
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(block_style);
+
+        f.render_widget(table, area);
+        self.scroll.draw(f, area, focused)?;
+
+        Ok(())
+    }
+
+    fn column_widths(&self) -> Vec<Constraint> {
+        let mut widths = vec![Constraint::Length(2)];  // arrow
+        widths.extend(self.columns.iter().map(|column| Constraint::Fill(column.width)));
+        widths
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum DummySortOrder {
+        NameInc,
+        NameDec,
+    }
+
+    struct DummyItem(&'static str);
+
+    impl Filterable for DummyItem {
+        fn matches_filter(&self, filter: &str) -> bool {
+            self.0.contains(filter)
+        }
+    }
+
+    impl Sortable<DummySortOrder> for DummyItem {
+        fn cmp_with(&self, other: &Self, sort: &DummySortOrder) -> Ordering {
+            match sort {
+                DummySortOrder::NameInc => self.0.cmp(other.0),
+                DummySortOrder::NameDec => other.0.cmp(self.0),
+            }
+        }
+    }
+
+    const DUMMY_COLUMNS: [SortableColumn<DummySortOrder>; 2] = [
+        SortableColumn::sorted("NAME", DummySortOrder::NameInc, DummySortOrder::NameDec, 10),
+        SortableColumn::unsorted("SIZE", 10),
+    ];
+
+    fn names(table: &SortableTable<DummyItem, DummySortOrder>) -> Vec<&'static str> {
+        table.vec_state().iter_with_selection().map(|(_, item, _)| item.0).collect()
+    }
+
+    #[test]
+    fn test_sort_column() {
+        let items = vec![DummyItem("b"), DummyItem("a"), DummyItem("c")];
+        let mut table = SortableTable::new(&DUMMY_COLUMNS, items, DummySortOrder::NameInc);
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+
+        table.sort_column(0);
+        assert_eq!(names(&table), vec!["c", "b", "a"]);
+        table.sort_column(0);
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+
+        // unsorted and missing columns are ignored
+        table.sort_column(1);
+        table.sort_column(2);
+        assert_eq!(table.sort(), DummySortOrder::NameInc);
+    }
+
+    #[test]
+    fn test_replace_keeps_selection() {
+        let items = vec![DummyItem("a"), DummyItem("b"), DummyItem("c")];
+        let mut table = SortableTable::new(&DUMMY_COLUMNS, items, DummySortOrder::NameInc);
+        table.move_selection(MoveSelection::Down);
+
+        table.replace(vec![DummyItem("c"), DummyItem("b")], |item| item.0);
+        assert_eq!(table.selected().map(|item| item.0), Some("b"));
+        assert_eq!(table.ui_selection(), Some(0));
+    }
+
+    #[test]
+    fn test_mouse_click() {
+        let items = vec![DummyItem("a"), DummyItem("b"), DummyItem("c")];
+        let mut table = SortableTable::new(&DUMMY_COLUMNS, items, DummySortOrder::NameInc);
+        table.table_area = Some(Rect::new(0, 0, 40, 10));
+        table.scroll.update(0, 3, 7);
+
+        // clicking the NAME header, right of the arrow column
+        assert!(table.handle_mouse_click(5, 1));
+        assert_eq!(table.sort(), DummySortOrder::NameDec);
+
+        // clicking the second row
+        assert!(table.handle_mouse_click(5, 3));
+        assert_eq!(table.selected().map(|item| item.0), Some("b"));
+
+        // clicking the border
+        assert!(!table.handle_mouse_click(5, 0));
+    }
+}
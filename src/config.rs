@@ -1,11 +1,25 @@
+use std::fs;
 use std::ops::Div;
+use std::path::PathBuf;
+use anyhow::{Context, Result};
 use serde::{Deserialize,Serialize};
+use crate::models::process_column::{default_process_columns, normalize_process_columns, ProcessColumnConfig};
+use crate::models::units::{DataUnit, UnitSystem};
+use crate::models::items::process_item::CpuMode;
 
 #[derive(Clone)]
 pub struct Config {
     pub key_config: KeyConfig,
     pub mouse_config: MouseConfig,
     pub theme_config: ThemeConfig,
+    pub process_columns: Vec<ProcessColumnConfig>,
+    unit_system: UnitSystem,
+    network_unit: DataUnit,
+    disk_usage_threshold: f32,
+    pressure_warning: f32,
+    pressure_critical: f32,
+    swap_thrashing_threshold: u64,
+    cpu_mode: CpuMode,
     refresh_rate: u64,
     max_time_scale: u64,
     min_time_scale: u64,
@@ -20,12 +34,24 @@ impl Default for Config {
         let min_time_scale = 60000;            // ms (60 seconds)
         let time_inc = 30000;               // ms (30 seconds)
         let tick_rate = 250;                // ms
+        let disk_usage_threshold = 90_f32;  // percent
+        let pressure_warning = 10_f32;      // percent of time stalled
+        let pressure_critical = 40_f32;     // percent of time stalled
+        let swap_thrashing_threshold = 1048576;    // bytes/s (1 MiB/s)
 
 
         Self {
             key_config: KeyConfig::default(),
             mouse_config: MouseConfig::default(),
             theme_config: ThemeConfig::default(),
+            process_columns: default_process_columns(),
+            unit_system: UnitSystem::default(),
+            network_unit: DataUnit::default(),
+            disk_usage_threshold,
+            pressure_warning,
+            pressure_critical,
+            swap_thrashing_threshold,
+            cpu_mode: CpuMode::default(),
             refresh_rate,
             max_time_scale,
             min_time_scale,
@@ -55,6 +81,146 @@ impl Config {
     pub fn tick_rate(&self) -> u64 {
         self.tick_rate
     } 
+
+    pub fn unit_system(&self) -> UnitSystem {
+        self.unit_system
+    }
+
+    pub fn set_unit_system(&mut self, unit_system: UnitSystem) {
+        self.unit_system = unit_system;
+    }
+
+    pub fn network_unit(&self) -> DataUnit {
+        self.network_unit
+    }
+
+    pub fn set_network_unit(&mut self, network_unit: DataUnit) {
+        self.network_unit = network_unit;
+    }
+
+    pub fn disk_usage_threshold(&self) -> f32 {
+        self.disk_usage_threshold
+    }
+
+    pub fn pressure_warning(&self) -> f32 {
+        self.pressure_warning
+    }
+
+    pub fn pressure_critical(&self) -> f32 {
+        self.pressure_critical
+    }
+
+    pub fn swap_thrashing_threshold(&self) -> u64 {
+        self.swap_thrashing_threshold
+    }
+
+    pub fn cpu_mode(&self) -> CpuMode {
+        self.cpu_mode
+    }
+
+    pub fn set_cpu_mode(&mut self, cpu_mode: CpuMode) {
+        self.cpu_mode = cpu_mode;
+    }
+
+    // loads the default config, overridden by the user's config file if one exists
+    pub fn load() -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Some(path) = config_path() {
+            if path.exists() {
+                let contents = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read config file {}", path.display()))?;
+                let config_file: ConfigFile = toml::from_str(&contents)
+                    .with_context(|| format!("failed to parse config file {}", path.display()))?;
+                config.apply(config_file);
+            }
+        }
+
+        Ok(config)
+    }
+
+    // writes the persisted subset of the config to the user's config file
+    pub fn save(&self) -> Result<()> {
+        let path = config_path().context("could not determine config file location")?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create config directory {}", dir.display()))?;
+        }
+
+        let contents = toml::to_string(&ConfigFile::from(self))?;
+        fs::write(&path, contents)
+            .with_context(|| format!("failed to write config file {}", path.display()))?;
+
+        Ok(())
+    }
+
+    fn apply(&mut self, config_file: ConfigFile) {
+        if let Some(process_columns) = config_file.process_columns {
+            self.process_columns = normalize_process_columns(process_columns);
+        }
+        if let Some(unit_system) = config_file.unit_system {
+            self.unit_system = unit_system;
+        }
+        if let Some(network_unit) = config_file.network_unit {
+            self.network_unit = network_unit;
+        }
+        if let Some(disk_usage_threshold) = config_file.disk_usage_threshold {
+            self.disk_usage_threshold = disk_usage_threshold.clamp(0_f32, 100_f32);
+        }
+        if let Some(pressure_warning) = config_file.pressure_warning {
+            self.pressure_warning = pressure_warning.clamp(0_f32, 100_f32);
+        }
+        if let Some(pressure_critical) = config_file.pressure_critical {
+            self.pressure_critical = pressure_critical.clamp(0_f32, 100_f32);
+        }
+        if let Some(swap_thrashing_threshold) = config_file.swap_thrashing_threshold {
+            self.swap_thrashing_threshold = swap_thrashing_threshold;
+        }
+        if let Some(cpu_mode) = config_file.cpu_mode {
+            self.cpu_mode = cpu_mode;
+        }
+    }
+}
+
+// ConfigFile is the subset of Config persisted in the user's config file.
+// Every field is optional so that partial (or older) config files still load.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    process_columns: Option<Vec<ProcessColumnConfig>>,
+    unit_system: Option<UnitSystem>,            // "iec" (KiB, MiB, ..) or "si" (kB, MB, ..)
+    network_unit: Option<DataUnit>,             // "bytes" or "bits"
+    disk_usage_threshold: Option<f32>,          // percent of space or inodes used above which mounts are flagged
+    pressure_warning: Option<f32>,              // percent of time stalled above which pressure is flagged in yellow
+    pressure_critical: Option<f32>,             // percent of time stalled above which pressure is flagged in red
+    swap_thrashing_threshold: Option<u64>,      // bytes/s swapped in and out at once above which the memory panel flags thrashing
+    cpu_mode: Option<CpuMode>,                  // "irix", "solaris" or "physical"
+}
+
+impl From<&Config> for ConfigFile {
+    fn from(config: &Config) -> Self {
+        Self {
+            process_columns: Some(config.process_columns.clone()),
+            unit_system: Some(config.unit_system),
+            network_unit: Some(config.network_unit),
+            disk_usage_threshold: Some(config.disk_usage_threshold),
+            pressure_warning: Some(config.pressure_warning),
+            pressure_critical: Some(config.pressure_critical),
+            swap_thrashing_threshold: Some(config.swap_thrashing_threshold),
+            cpu_mode: Some(config.cpu_mode),
+        }
+    }
+}
+
+// $XDG_CONFIG_HOME/process-display/config.toml, falling back to ~/.config (or %APPDATA% on Windows)
+fn config_path() -> Option<PathBuf> {
+    let dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))?;
+
+    Some(dir.join("process-display").join("config.toml"))
 }
 
 pub fn ms_to_s(data_ms: u64) -> u64 {
@@ -77,8 +243,23 @@ pub struct KeyConfig {
     pub sort_pid_toggle: Key,
     pub sort_cpu_toggle: Key,
     pub sort_memory_toggle: Key,
+    pub sort_user_toggle: Key,
+    pub only_mine_toggle: Key,
+    pub sort_secondary: Key,
+    pub group_toggle: Key,
+    pub sort_remove_secondary: Key,
+    pub column_chooser: Key,
+    pub move_column_up: Key,
+    pub move_column_down: Key,
+    pub shrink_column: Key,
+    pub grow_column: Key,
     pub follow_selection: Key,
     pub expand: Key,
+    pub unit_system_toggle: Key,
+    pub network_unit_toggle: Key,
+    pub toggle_iowait: Key,
+    pub toggle_steal: Key,
+    pub cpu_mode_toggle: Key,
 }
 
 impl Default for KeyConfig {
@@ -98,8 +279,23 @@ impl Default for KeyConfig {
             sort_pid_toggle: Key::Char('p'),
             sort_cpu_toggle: Key::Char('c'),
             sort_memory_toggle: Key::Char('m'),
+            sort_user_toggle: Key::Char('u'),
+            only_mine_toggle: Key::Char('U'),
+            sort_secondary: Key::Char('+'),
+            group_toggle: Key::Char('g'),
+            sort_remove_secondary: Key::Char('-'),
+            column_chooser: Key::Char('o'),
+            move_column_up: Key::Char('['),
+            move_column_down: Key::Char(']'),
+            shrink_column: Key::Char('<'),
+            grow_column: Key::Char('>'),
             follow_selection: Key::Char('f'),
             expand: Key::Char('e'),
+            unit_system_toggle: Key::Char('K'),
+            network_unit_toggle: Key::Char('b'),
+            toggle_iowait: Key::Char('i'),
+            toggle_steal: Key::Char('s'),
+            cpu_mode_toggle: Key::Char('I'),
         }
     }
 }
@@ -149,4 +345,64 @@ impl Default for ThemeConfig {
             style_item_selected_not_focused: Style::default().fg(Color::White),
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::process_column::ProcessColumn;
+
+    #[test]
+    fn test_config_file_round_trip() {
+        let mut config = Config::default();
+        config.process_columns.swap(0, 1);
+        config.process_columns[0].width = 3;
+        config.process_columns[1].visible = false;
+        config.unit_system = UnitSystem::Si;
+        config.network_unit = DataUnit::Bits;
+        config.disk_usage_threshold = 80_f32;
+        config.pressure_warning = 5_f32;
+        config.pressure_critical = 20_f32;
+        config.swap_thrashing_threshold = 4096;
+        config.cpu_mode = CpuMode::Solaris;
+
+        let contents = toml::to_string(&ConfigFile::from(&config)).unwrap();
+        let mut loaded = Config::default();
+        loaded.apply(toml::from_str(&contents).unwrap());
+
+        assert!(loaded.process_columns == config.process_columns);
+        assert_eq!(loaded.unit_system(), UnitSystem::Si);
+        assert_eq!(loaded.network_unit(), DataUnit::Bits);
+        assert_eq!(loaded.disk_usage_threshold(), 80_f32);
+        assert_eq!(loaded.pressure_warning(), 5_f32);
+        assert_eq!(loaded.pressure_critical(), 20_f32);
+        assert_eq!(loaded.swap_thrashing_threshold(), 4096);
+        assert_eq!(loaded.cpu_mode(), CpuMode::Solaris);
+    }
+
+    #[test]
+    fn test_partial_config_file() {
+        let contents = r#"
+            [[process_columns]]
+            column = "cmd"
+            visible = true
+            width = 30
+        "#;
+        let mut config = Config::default();
+        config.apply(toml::from_str(contents).unwrap());
+
+        assert_eq!(config.process_columns[0].column, ProcessColumn::Cmd);
+        assert!(config.process_columns[0].visible);
+        assert_eq!(config.process_columns.len(), ProcessColumn::ALL.len());
+
+        // empty config files keep the defaults
+        let mut config = Config::default();
+        config.apply(toml::from_str("").unwrap());
+        assert!(config.process_columns == default_process_columns());
+        assert_eq!(config.unit_system(), UnitSystem::Iec);
+        assert_eq!(config.network_unit(), DataUnit::Bytes);
+        assert_eq!(config.pressure_warning(), 10_f32);
+        assert_eq!(config.pressure_critical(), 40_f32);
+        assert_eq!(config.swap_thrashing_threshold(), 1048576);
+        assert_eq!(config.cpu_mode(), CpuMode::Physical);
+    }
+}
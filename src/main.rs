@@ -25,6 +25,9 @@ pub mod states;
 pub mod services;
 
 fn main() -> Result<()> {
+    // load config before entering raw mode so config errors are printed normally
+    let config = config::Config::load()?;
+
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
     //stdout().execute(EnterAlternateScreen)?;
@@ -32,7 +35,6 @@ fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    let config = config::Config::default();
     let tick_rate = config.tick_rate();
     let refresh_rate = config.refresh_rate();
     
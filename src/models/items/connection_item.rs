@@ -0,0 +1,147 @@
+use crate::models::{Filterable, Sortable};
+use crate::models::items::socket_item::SocketItem;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConnectionItemSortOrder {
+    ProtocolInc,
+    ProtocolDec,
+    LocalInc,
+    LocalDec,
+    RemoteInc,
+    RemoteDec,
+    StateInc,
+    StateDec,
+    PidInc,
+    PidDec,
+    NameInc,
+    NameDec,
+}
+
+// a socket of the host and the process owning it. pid and name are None if the owner is not
+// known, e.g. the socket belongs to a process of another user and not running as root
+#[derive(Clone, Default, Debug)]
+pub struct ConnectionItem {
+    socket: SocketItem,
+    pid: Option<u32>,
+    name: Option<String>,
+}
+
+impl ConnectionItem {
+    pub fn new(socket: SocketItem, pid: Option<u32>, name: Option<String>) -> Self {
+        Self {
+            socket,
+            pid,
+            name,
+        }
+    }
+
+    // GETTERS
+    pub fn socket(&self) -> &SocketItem {
+        &self.socket
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.pid
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    // sort key of the local address: inet sockets by port, then unix sockets by path
+    fn local_key(&self) -> (bool, Option<u16>, &str) {
+        (self.socket.is_unix(), self.socket.local_port(), self.socket.local())
+    }
+}
+
+impl PartialEq for ConnectionItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.socket.eq(&other.socket)
+    }
+}
+
+impl Filterable for ConnectionItem {
+    // ":<port>" matches the local or remote port, "pid=<pid>" the owner, anything else is matched
+    // against protocol, addresses, state and process name
+    fn matches_filter(&self, filter: &str) -> bool {
+        if let Some(port_str) = filter.strip_prefix(':') {
+            if let Ok(port) = port_str.trim().parse::<u16>() {
+                return self.socket.local_port() == Some(port) || self.socket.remote_port() == Some(port);
+            }
+        }
+        if let Some(pid_str) = filter.strip_prefix("pid=") {
+            if let Ok(pid) = pid_str.trim().parse::<u32>() {
+                return self.pid == Some(pid);
+            }
+        }
+
+        let filter = filter.to_lowercase();
+        [
+            self.socket.protocol(),
+            self.socket.local(),
+            self.socket.remote(),
+            self.socket.state(),
+            self.name.as_deref().unwrap_or_default(),
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(&filter))
+    }
+}
+
+impl Sortable<ConnectionItemSortOrder> for ConnectionItem {
+    fn cmp_with(&self, other: &Self, sort: &ConnectionItemSortOrder) -> std::cmp::Ordering {
+        match sort {
+            ConnectionItemSortOrder::ProtocolInc => self.socket.protocol().cmp(other.socket.protocol()),
+            ConnectionItemSortOrder::ProtocolDec => other.socket.protocol().cmp(self.socket.protocol()),
+            ConnectionItemSortOrder::LocalInc =>    self.local_key().cmp(&other.local_key()),
+            ConnectionItemSortOrder::LocalDec =>    other.local_key().cmp(&self.local_key()),
+            ConnectionItemSortOrder::RemoteInc =>   self.socket.remote().cmp(other.socket.remote()),
+            ConnectionItemSortOrder::RemoteDec =>   other.socket.remote().cmp(self.socket.remote()),
+            ConnectionItemSortOrder::StateInc =>    self.socket.state().cmp(other.socket.state()),
+            ConnectionItemSortOrder::StateDec =>    other.socket.state().cmp(self.socket.state()),
+            ConnectionItemSortOrder::PidInc =>      self.pid.cmp(&other.pid),
+            ConnectionItemSortOrder::PidDec =>      other.pid.cmp(&self.pid),
+            ConnectionItemSortOrder::NameInc =>     self.name.cmp(&other.name),
+            ConnectionItemSortOrder::NameDec =>     other.name.cmp(&self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn connection(protocol: &str, local: &str, remote: &str, state: &str, pid: Option<u32>, name: Option<&str>) -> ConnectionItem {
+        let socket = SocketItem::new(protocol.to_string(), local.to_string(), remote.to_string(), state.to_string(), 0);
+        ConnectionItem::new(socket, pid, name.map(String::from))
+    }
+
+    #[test]
+    fn test_filter() {
+        let http = connection("tcp6", "[::1]:8080", "*:*", "LISTEN", Some(812), Some("gunicorn"));
+        let client = connection("tcp", "10.0.2.15:54178", "10.0.2.2:8080", "ESTAB", None, None);
+        let unix = connection("u_str", "/run/app:8080", "*", "LISTEN", Some(1), Some("systemd"));
+
+        assert!(http.matches_filter(":8080"));
+        assert!(client.matches_filter(":8080"));
+        assert!(!http.matches_filter(":80"));
+        // unix socket paths have no port
+        assert!(!unix.matches_filter(":8080"));
+
+        assert!(http.matches_filter("pid=812"));
+        assert!(!client.matches_filter("pid=812"));
+        assert!(http.matches_filter("Gunicorn"));
+        assert!(client.matches_filter("estab"));
+    }
+
+    #[test]
+    fn test_sort_local() {
+        let ssh = connection("tcp", "0.0.0.0:22", "*:*", "LISTEN", None, None);
+        let http = connection("tcp6", "[::1]:8080", "*:*", "LISTEN", None, None);
+        let unix = connection("u_str", "/run/systemd/private", "*", "LISTEN", None, None);
+
+        assert!(ssh.cmp_with(&http, &ConnectionItemSortOrder::LocalInc).is_lt());
+        assert!(http.cmp_with(&unix, &ConnectionItemSortOrder::LocalInc).is_lt());
+        assert!(unix.cmp_with(&ssh, &ConnectionItemSortOrder::LocalDec).is_lt());
+    }
+}
@@ -1,8 +1,39 @@
+// percent of time a cpu spent in each state since the previous refresh, from /proc/stat
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct CpuBreakdown {
+    pub user: f32,
+    pub nice: f32,
+    pub system: f32,
+    pub idle: f32,
+    pub iowait: f32,
+    pub irq: f32,
+    pub softirq: f32,
+    pub steal: f32,
+    pub guest: f32,
+}
+
+// frequency range the governor scales a cpu within, in MHz like the frequency
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CpuScaling {
+    pub min: u64,
+    pub max: u64,
+    pub governor: String,
+}
+
+impl CpuScaling {
+    // governors that keep the frequency low to save power
+    pub fn is_power_saving(&self) -> bool {
+        matches!(self.governor.as_str(), "powersave" | "conservative")
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct CpuItem {
     id: usize,
     usage: f32,
-    frequency: u64,
+    frequency: u64,                 // MHz
+    breakdown: Option<CpuBreakdown>,
+    scaling: Option<CpuScaling>,
 }
 
 impl CpuItem {
@@ -15,9 +46,21 @@ impl CpuItem {
             id,
             usage,
             frequency,
+            breakdown: None,
+            scaling: None,
         }
     }
 
+    pub fn with_breakdown(mut self, breakdown: Option<CpuBreakdown>) -> Self {
+        self.breakdown = breakdown;
+        self
+    }
+
+    pub fn with_scaling(mut self, scaling: Option<CpuScaling>) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
     pub fn usage(&self) -> f32 {
         self.usage
     }
@@ -35,6 +78,16 @@ impl CpuItem {
         self.frequency
     }
 
+    // None on platforms without /proc/stat and on the first refresh
+    pub fn breakdown(&self) -> Option<&CpuBreakdown> {
+        self.breakdown.as_ref()
+    }
+
+    // None without a cpufreq driver
+    pub fn scaling(&self) -> Option<&CpuScaling> {
+        self.scaling.as_ref()
+    }
+
 }
 
 /*
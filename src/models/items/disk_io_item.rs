@@ -0,0 +1,67 @@
+// i/o of a block device since the previous refresh, rates are 0 before the second refresh
+#[derive(Clone, Default, Debug)]
+pub struct DiskIoItem {
+    device: String,
+    read_bytes_per_s: f64,
+    written_bytes_per_s: f64,
+    read_iops: f64,
+    write_iops: f64,
+    utilization: f32,               // percent of the time the device was busy
+    in_flight: u64,                 // requests currently issued to the device
+}
+
+impl DiskIoItem {
+    pub fn new(device: String, in_flight: u64) -> Self {
+        Self {
+            device,
+            in_flight,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_throughput(mut self, read_bytes_per_s: f64, written_bytes_per_s: f64) -> Self {
+        self.read_bytes_per_s = read_bytes_per_s;
+        self.written_bytes_per_s = written_bytes_per_s;
+        self
+    }
+
+    pub fn with_iops(mut self, read_iops: f64, write_iops: f64) -> Self {
+        self.read_iops = read_iops;
+        self.write_iops = write_iops;
+        self
+    }
+
+    pub fn with_utilization(mut self, utilization: f32) -> Self {
+        self.utilization = utilization;
+        self
+    }
+
+    // GETTERS
+    pub fn device(&self) -> &str {
+        &self.device
+    }
+
+    pub fn read_bytes_per_s(&self) -> f64 {
+        self.read_bytes_per_s
+    }
+
+    pub fn written_bytes_per_s(&self) -> f64 {
+        self.written_bytes_per_s
+    }
+
+    pub fn read_iops(&self) -> f64 {
+        self.read_iops
+    }
+
+    pub fn write_iops(&self) -> f64 {
+        self.write_iops
+    }
+
+    pub fn utilization(&self) -> f32 {
+        self.utilization
+    }
+
+    pub fn in_flight(&self) -> u64 {
+        self.in_flight
+    }
+}
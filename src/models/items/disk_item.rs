@@ -0,0 +1,164 @@
+use crate::models::{Filterable, Sortable};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DiskItemSortOrder {
+    MountInc,
+    MountDec,
+    SizeInc,
+    SizeDec,
+    AvailableInc,
+    AvailableDec,
+    UsageInc,
+    UsageDec,
+    InodeUsageInc,
+    InodeUsageDec,
+}
+
+// space and inode usage of a mounted filesystem. inodes are None when the filesystem
+// does not report them (e.g. btrfs, vfat)
+#[derive(Clone, Default, Debug)]
+pub struct DiskItem {
+    mount_point: String,
+    device: String,
+    fs_type: String,
+    total_space: u64,
+    available_space: u64,
+    inodes_total: Option<u64>,
+    inodes_free: Option<u64>,
+}
+
+impl DiskItem {
+    pub fn new(mount_point: String, device: String, fs_type: String, total_space: u64, available_space: u64) -> Self {
+        Self {
+            mount_point,
+            device,
+            fs_type,
+            total_space,
+            available_space,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_inodes(mut self, inodes_total: u64, inodes_free: u64) -> Self {
+        self.inodes_total = Some(inodes_total);
+        self.inodes_free = Some(inodes_free);
+        self
+    }
+
+    // GETTERS
+    pub fn mount_point(&self) -> &str {
+        &self.mount_point
+    }
+
+    pub fn device(&self) -> &str {
+        &self.device
+    }
+
+    pub fn fs_type(&self) -> &str {
+        &self.fs_type
+    }
+
+    pub fn total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    pub fn available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    // includes the blocks reserved for root, like df
+    pub fn used_space(&self) -> u64 {
+        self.total_space.saturating_sub(self.available_space)
+    }
+
+    pub fn inodes_total(&self) -> Option<u64> {
+        self.inodes_total
+    }
+
+    pub fn inodes_used(&self) -> Option<u64> {
+        Some(self.inodes_total?.saturating_sub(self.inodes_free?))
+    }
+
+    // used space in percent of the size
+    pub fn usage(&self) -> f32 {
+        if self.total_space == 0 {
+            return 0_f32;
+        }
+        self.used_space() as f32 / self.total_space as f32 * 100_f32
+    }
+
+    // used inodes in percent of all inodes
+    pub fn inode_usage(&self) -> Option<f32> {
+        let total = self.inodes_total?;
+
+        if total == 0 {
+            return None;
+        }
+        Some(self.inodes_used()? as f32 / total as f32 * 100_f32)
+    }
+
+    // a filesystem is full when either its space or its inodes run out
+    pub fn is_above(&self, threshold: f32) -> bool {
+        self.usage() >= threshold || self.inode_usage().is_some_and(|usage| usage >= threshold)
+    }
+}
+
+impl PartialEq for DiskItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.mount_point.eq(&other.mount_point)
+    }
+}
+
+impl Filterable for DiskItem {
+    fn matches_filter(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+
+        self.mount_point.to_lowercase().contains(&filter) ||
+        self.device.to_lowercase().contains(&filter) ||
+        self.fs_type.to_lowercase().contains(&filter)
+    }
+}
+
+impl Sortable<DiskItemSortOrder> for DiskItem {
+    fn cmp_with(&self, other: &Self, sort: &DiskItemSortOrder) -> std::cmp::Ordering {
+        match sort {
+            DiskItemSortOrder::MountInc =>          self.mount_point.cmp(&other.mount_point),
+            DiskItemSortOrder::MountDec =>          other.mount_point.cmp(&self.mount_point),
+            DiskItemSortOrder::SizeInc =>           self.total_space.cmp(&other.total_space),
+            DiskItemSortOrder::SizeDec =>           other.total_space.cmp(&self.total_space),
+            DiskItemSortOrder::AvailableInc =>      self.available_space.cmp(&other.available_space),
+            DiskItemSortOrder::AvailableDec =>      other.available_space.cmp(&self.available_space),
+            DiskItemSortOrder::UsageInc =>          self.usage().partial_cmp(&other.usage()).unwrap_or(std::cmp::Ordering::Equal),
+            DiskItemSortOrder::UsageDec =>          other.usage().partial_cmp(&self.usage()).unwrap_or(std::cmp::Ordering::Equal),
+            DiskItemSortOrder::InodeUsageInc =>     self.inode_usage().partial_cmp(&other.inode_usage()).unwrap_or(std::cmp::Ordering::Equal),
+            DiskItemSortOrder::InodeUsageDec =>     other.inode_usage().partial_cmp(&self.inode_usage()).unwrap_or(std::cmp::Ordering::Equal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_usage() {
+        let item = DiskItem::new(String::from("/var"), String::from("/dev/sda3"), String::from("ext4"), 1000, 50)
+            .with_inodes(200, 150);
+
+        assert_eq!(item.used_space(), 950);
+        assert_eq!(item.usage(), 95_f32);
+        assert_eq!(item.inodes_used(), Some(50));
+        assert_eq!(item.inode_usage(), Some(25_f32));
+        assert!(item.is_above(90_f32));
+
+        // running out of inodes fills a filesystem with free space
+        let item = DiskItem::new(String::from("/srv"), String::from("/dev/sdb1"), String::from("ext4"), 1000, 900)
+            .with_inodes(200, 2);
+        assert!(item.is_above(90_f32));
+
+        let item = DiskItem::new(String::from("/boot/efi"), String::from("/dev/sda1"), String::from("vfat"), 0, 0);
+        assert_eq!(item.usage(), 0_f32);
+        assert_eq!(item.inode_usage(), None);
+        assert!(!item.is_above(90_f32));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::models::items::socket_item::SocketItem;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FileKind {
+    File,
+    Socket,
+    Pipe,
+    AnonInode,
+    Device,
+    Unknown,                    // the descriptor's target could not be read
+}
+
+impl FileKind {
+    pub const ALL: [FileKind; 6] = [
+        FileKind::File,
+        FileKind::Socket,
+        FileKind::Pipe,
+        FileKind::AnonInode,
+        FileKind::Device,
+        FileKind::Unknown,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            FileKind::File => "file",
+            FileKind::Socket => "socket",
+            FileKind::Pipe => "pipe",
+            FileKind::AnonInode => "anon_inode",
+            FileKind::Device => "device",
+            FileKind::Unknown => "unknown",
+        }
+    }
+}
+
+// an open file descriptor of a process
+#[derive(Clone, Debug)]
+pub struct FileItem {
+    fd: u32,
+    kind: FileKind,
+    target: String,             // path, or e.g. socket:[40771] when the socket could not be resolved
+    socket: Option<SocketItem>, // inet socket the descriptor refers to
+}
+
+impl FileItem {
+    pub fn new(fd: u32, kind: FileKind, target: String) -> Self {
+        Self {
+            fd,
+            kind,
+            target,
+            socket: None,
+        }
+    }
+
+    pub fn with_socket(mut self, socket: SocketItem) -> Self {
+        self.socket = Some(socket);
+        self
+    }
+
+    // GETTERS
+    pub fn fd(&self) -> u32 {
+        self.fd
+    }
+
+    pub fn kind(&self) -> FileKind {
+        self.kind
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn socket(&self) -> Option<&SocketItem> {
+        self.socket.as_ref()
+    }
+
+    // description shown in the open files list, sockets as "<protocol> <local> -> <remote> <state>"
+    pub fn description(&self) -> String {
+        match &self.socket {
+            Some(socket) => format!("{} {} -> {} {}", socket.protocol(), socket.local(), socket.remote(), socket.state()),
+            None => self.target.clone(),
+        }
+    }
+}
+
+impl PartialEq for FileItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.fd.eq(&other.fd)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::models::{Filterable, Sortable};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LimitItemSortOrder {
+    NameInc,
+    NameDec,
+    CpuQuotaUsageInc,
+    CpuQuotaUsageDec,
+    ThrottledInc,
+    ThrottledDec,
+    MemoryLimitUsageInc,
+    MemoryLimitUsageDec,
+    OomKillsInc,
+    OomKillsDec,
+}
+
+// resource limits of a cgroup and its usage relative to them. limits are None when unlimited,
+// rates are None before the second refresh
+#[derive(Clone, Default, Debug)]
+pub struct LimitItem {
+    name: String,
+    cgroup: String,
+    cpu_usage: Option<f32>,             // percent of a single cpu, not normalized
+    cpu_quota: Option<f32>,             // cpu.max quota, in percent of a single cpu
+    throttled_percent: Option<f32>,     // percent of periods throttled since the previous refresh
+    throttled_ms_per_s: Option<f32>,    // time throttled since the previous refresh, in ms per second
+    memory_usage: Option<u64>,
+    memory_max: Option<u64>,
+    memory_high: Option<u64>,
+    ooms: u64,                          // total times the cgroup ran out of memory
+    recent_ooms: u64,                   // times it ran out of memory since the previous refresh
+    oom_kills: u64,                     // total oom kills
+    recent_oom_kills: u64,              // oom kills since the previous refresh
+}
+
+impl LimitItem {
+    pub fn new(name: String, cgroup: String) -> Self {
+        Self {
+            name,
+            cgroup,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_cpu(mut self, cpu_usage: Option<f32>, cpu_quota: Option<f32>, throttled_percent: Option<f32>) -> Self {
+        self.cpu_usage = cpu_usage;
+        self.cpu_quota = cpu_quota;
+        self.throttled_percent = throttled_percent;
+        self
+    }
+
+    pub fn with_throttled_time(mut self, throttled_ms_per_s: Option<f32>) -> Self {
+        self.throttled_ms_per_s = throttled_ms_per_s;
+        self
+    }
+
+    pub fn with_memory(mut self, memory_usage: Option<u64>, memory_max: Option<u64>, memory_high: Option<u64>) -> Self {
+        self.memory_usage = memory_usage;
+        self.memory_max = memory_max;
+        self.memory_high = memory_high;
+        self
+    }
+
+    pub fn with_ooms(mut self, ooms: u64, recent_ooms: u64) -> Self {
+        self.ooms = ooms;
+        self.recent_ooms = recent_ooms;
+        self
+    }
+
+    pub fn with_oom_kills(mut self, oom_kills: u64, recent_oom_kills: u64) -> Self {
+        self.oom_kills = oom_kills;
+        self.recent_oom_kills = recent_oom_kills;
+        self
+    }
+
+    // GETTERS
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cgroup(&self) -> &str {
+        &self.cgroup
+    }
+
+    pub fn cpu_usage(&self) -> Option<f32> {
+        self.cpu_usage
+    }
+
+    pub fn cpu_quota(&self) -> Option<f32> {
+        self.cpu_quota
+    }
+
+    pub fn throttled_percent(&self) -> Option<f32> {
+        self.throttled_percent
+    }
+
+    pub fn throttled_ms_per_s(&self) -> Option<f32> {
+        self.throttled_ms_per_s
+    }
+
+    pub fn memory_usage(&self) -> Option<u64> {
+        self.memory_usage
+    }
+
+    pub fn memory_max(&self) -> Option<u64> {
+        self.memory_max
+    }
+
+    pub fn memory_high(&self) -> Option<u64> {
+        self.memory_high
+    }
+
+    pub fn ooms(&self) -> u64 {
+        self.ooms
+    }
+
+    pub fn recent_ooms(&self) -> u64 {
+        self.recent_ooms
+    }
+
+    pub fn oom_kills(&self) -> u64 {
+        self.oom_kills
+    }
+
+    pub fn recent_oom_kills(&self) -> u64 {
+        self.recent_oom_kills
+    }
+
+    // cpu usage in percent of the cpu quota
+    pub fn cpu_quota_usage(&self) -> Option<f32> {
+        match (self.cpu_usage, self.cpu_quota) {
+            (Some(usage), Some(quota)) if quota > 0_f32 => Some(usage / quota * 100_f32),
+            _ => None,
+        }
+    }
+
+    // memory usage in percent of memory.max, or memory.high if there is no hard limit
+    pub fn memory_limit_usage(&self) -> Option<f32> {
+        let limit = self.memory_max.or(self.memory_high)?;
+        let usage = self.memory_usage?;
+
+        if limit == 0 {
+            return None;
+        }
+        Some(usage as f32 / limit as f32 * 100_f32)
+    }
+
+    pub fn is_limited(&self) -> bool {
+        self.cpu_quota.is_some() || self.memory_max.is_some() || self.memory_high.is_some()
+    }
+
+    pub fn is_throttled(&self) -> bool {
+        self.throttled_percent.is_some_and(|throttled| throttled > 0_f32)
+    }
+}
+
+impl PartialEq for LimitItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cgroup.eq(&other.cgroup)
+    }
+}
+
+impl Filterable for LimitItem {
+    fn matches_filter(&self, filter: &str) -> bool {
+        self.name.to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+impl Sortable<LimitItemSortOrder> for LimitItem {
+    fn cmp_with(&self, other: &Self, sort: &LimitItemSortOrder) -> std::cmp::Ordering {
+        match sort {
+            LimitItemSortOrder::NameInc =>              self.name.cmp(&other.name),
+            LimitItemSortOrder::NameDec =>              other.name.cmp(&self.name),
+            LimitItemSortOrder::CpuQuotaUsageInc =>     self.cpu_quota_usage().partial_cmp(&other.cpu_quota_usage()).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::CpuQuotaUsageDec =>     other.cpu_quota_usage().partial_cmp(&self.cpu_quota_usage()).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::ThrottledInc =>         self.throttled_percent.partial_cmp(&other.throttled_percent).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::ThrottledDec =>         other.throttled_percent.partial_cmp(&self.throttled_percent).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::MemoryLimitUsageInc =>  self.memory_limit_usage().partial_cmp(&other.memory_limit_usage()).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::MemoryLimitUsageDec =>  other.memory_limit_usage().partial_cmp(&self.memory_limit_usage()).unwrap_or(std::cmp::Ordering::Equal),
+            LimitItemSortOrder::OomKillsInc =>          self.oom_kills.cmp(&other.oom_kills),
+            LimitItemSortOrder::OomKillsDec =>          other.oom_kills.cmp(&self.oom_kills),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_limit_usage() {
+        let item = LimitItem::new(String::from("docker:4f1c2a9b8e7d"), String::from("/system.slice/docker-4f1c2a9b8e7d.scope"))
+            .with_cpu(Some(20_f32), Some(20_f32), Some(35_f32))
+            .with_memory(Some(256), Some(512), Some(384));
+
+        // 20% of a cpu is 100% of a 0.2 cpu quota
+        assert_eq!(item.cpu_quota_usage(), Some(100_f32));
+        assert_eq!(item.memory_limit_usage(), Some(50_f32));
+        assert!(item.is_limited());
+        assert!(item.is_throttled());
+
+        // memory.high is used without a hard limit
+        let item = item.with_memory(Some(256), None, Some(1024));
+        assert_eq!(item.memory_limit_usage(), Some(25_f32));
+
+        let unlimited = LimitItem::new(String::from("sshd.service"), String::from("/system.slice/sshd.service"))
+            .with_cpu(Some(5_f32), None, Some(0_f32))
+            .with_memory(Some(256), None, None);
+        assert_eq!(unlimited.cpu_quota_usage(), None);
+        assert_eq!(unlimited.memory_limit_usage(), None);
+        assert!(!unlimited.is_limited());
+        assert!(!unlimited.is_throttled());
+    }
+}
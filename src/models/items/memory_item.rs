@@ -1,9 +1,48 @@
+// where the memory goes besides applications, in bytes
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct MemoryBreakdown {
+    pub buffers: u64,
+    pub cached: u64,                        // page cache, includes shared
+    pub shared: u64,
+    pub sreclaimable: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+}
+
+impl MemoryBreakdown {
+    // memory the kernel gives back when applications need it, like the buff/cache column of free(1)
+    pub fn reclaimable(&self) -> u64 {
+        self.buffers + self.cached + self.sreclaimable
+    }
+}
+
+// paging since the previous refresh, all 0 before the second refresh
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct MemoryActivity {
+    pub swap_in_bytes_per_s: f64,
+    pub swap_out_bytes_per_s: f64,
+    pub faults_per_s: f64,                  // minor and major
+    pub major_faults_per_s: f64,
+}
+
+impl MemoryActivity {
+    // swapping in and out at the same time, both above threshold, the box needs more memory than it
+    // has. a few pages of background swap traffic are not thrashing
+    pub fn is_thrashing(&self, threshold_bytes_per_s: f64) -> bool {
+        self.swap_in_bytes_per_s >= threshold_bytes_per_s && self.swap_out_bytes_per_s >= threshold_bytes_per_s
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MemoryItem {
     total_memory: u64,
     used_memory: u64,
     total_swap: u64,
     used_swap: u64,
+    available_memory: u64,
+    free_memory: u64,
+    breakdown: Option<MemoryBreakdown>,     // None where /proc/meminfo is not available
+    activity: MemoryActivity,
 }
 
 impl MemoryItem {
@@ -13,9 +52,29 @@ impl MemoryItem {
             used_memory,
             total_swap,
             used_swap,
+            available_memory: 0,
+            free_memory: 0,
+            breakdown: None,
+            activity: MemoryActivity::default(),
         }
     }
 
+    pub fn with_available(mut self, available_memory: u64, free_memory: u64) -> Self {
+        self.available_memory = available_memory;
+        self.free_memory = free_memory;
+        self
+    }
+
+    pub fn with_breakdown(mut self, breakdown: Option<MemoryBreakdown>) -> Self {
+        self.breakdown = breakdown;
+        self
+    }
+
+    pub fn with_activity(mut self, activity: MemoryActivity) -> Self {
+        self.activity = activity;
+        self
+    }
+
     pub fn update(&mut self, total_memory: u64, used_memory: u64, total_swap: u64, used_swap: u64) {
         self.total_memory = total_memory;
         self.used_memory = used_memory;
@@ -31,16 +90,8 @@ impl MemoryItem {
         self.used_memory
     }
     
-    pub fn total_memory_gb(&self) -> f64 {
-        self.total_memory as f64 / 1000000000_f64 
-    }
-
-    pub fn used_memory_gb(&self) -> f64 {
-        self.used_memory as f64 / 1000000000_f64 
-    }
-
     pub fn percent_memory_usage(&self) -> f64 {
-        let percent = (self.used_memory_gb() / self.total_memory_gb()) * 100_f64;
+        let percent = (self.used_memory as f64 / self.total_memory as f64) * 100_f64;
         if percent.is_nan() {
             return 0_f64
         }
@@ -49,24 +100,53 @@ impl MemoryItem {
         }
     }
 
-    pub fn total_swap(&self) -> u64 {
-        self.total_swap
+    // memory that can be handed to applications without swapping, free memory plus reclaimable caches
+    pub fn available_memory(&self) -> u64 {
+        self.available_memory
     }
 
-    pub fn used_swap(&self) -> u64 {
-        self.used_swap
+    pub fn percent_available(&self) -> f64 {
+        percent_of(self.available_memory, self.total_memory)
+    }
+
+    pub fn free_memory(&self) -> u64 {
+        self.free_memory
+    }
+
+    pub fn breakdown(&self) -> Option<&MemoryBreakdown> {
+        self.breakdown.as_ref()
     }
 
-    pub fn total_swap_gb(&self) -> f64 {
-        self.total_swap as f64 / 1000000000_f64 
+    pub fn cache_memory(&self) -> u64 {
+        self.breakdown.map_or(0, |breakdown| breakdown.reclaimable())
     }
 
-    pub fn used_swap_gb(&self) -> f64 {
-        self.used_swap as f64 / 1000000000_f64 
+    pub fn percent_cache(&self) -> f64 {
+        percent_of(self.cache_memory(), self.total_memory)
+    }
+
+    // memory held by applications and the kernel that cannot be reclaimed, the rest of total is
+    // free or caches
+    pub fn app_memory(&self) -> u64 {
+        self.total_memory
+            .saturating_sub(self.free_memory)
+            .saturating_sub(self.cache_memory())
+    }
+
+    pub fn activity(&self) -> &MemoryActivity {
+        &self.activity
+    }
+
+    pub fn total_swap(&self) -> u64 {
+        self.total_swap
+    }
+
+    pub fn used_swap(&self) -> u64 {
+        self.used_swap
     }
 
     pub fn percent_swap_usage(&self) -> f64 {
-        let percent = (self.used_swap_gb() / self.total_swap_gb()) * 100_f64;
+        let percent = (self.used_swap as f64 / self.total_swap as f64) * 100_f64;
         if percent.is_nan() {
             return 0_f64
         }
@@ -76,17 +156,26 @@ impl MemoryItem {
     }
 }
 
+fn percent_of(value: u64, total: u64) -> f64 {
+    if total == 0 {
+        0_f64
+    }
+    else {
+        value as f64 / total as f64 * 100_f64
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::MemoryItem;
+    use super::{MemoryActivity, MemoryBreakdown, MemoryItem};
 
     #[test]
     fn test_default() {
         let instance = MemoryItem::default();
         assert_eq!(instance.total_memory(), 0);
         assert_eq!(instance.used_memory(), 0);
-        assert_eq!(instance.total_memory_gb(), 0.0);
-        assert_eq!(instance.used_memory_gb(), 0.0);
+        assert_eq!(instance.percent_memory_usage(), 0.0);
+        assert_eq!(instance.percent_swap_usage(), 0.0);
     }
 
     #[test]
@@ -94,7 +183,47 @@ mod test {
         let instance = MemoryItem::new(1, 2, 0, 0);
         assert_eq!(instance.total_memory(), 1);
         assert_eq!(instance.used_memory(), 2);
-        assert_eq!(instance.total_memory_gb(), 0.000000001);
-        assert_eq!(instance.used_memory_gb(), 0.000000002);
+        assert_eq!(instance.percent_memory_usage(), 200.0);
+        assert_eq!(instance.percent_swap_usage(), 0.0);
+    }
+
+    #[test]
+    fn test_breakdown() {
+        let breakdown = MemoryBreakdown {
+            buffers: 10,
+            cached: 300,
+            shared: 50,
+            sreclaimable: 40,
+            dirty: 2,
+            writeback: 0,
+        };
+        let instance = MemoryItem::new(1000, 450, 0, 0)
+            .with_available(500, 200)
+            .with_breakdown(Some(breakdown));
+
+        assert_eq!(instance.cache_memory(), 350);
+        assert_eq!(instance.app_memory(), 450);
+        assert_eq!(instance.percent_available(), 50.0);
+        assert_eq!(instance.percent_cache(), 35.0);
+
+        // without /proc/meminfo everything not free counts as used
+        let instance = MemoryItem::new(1000, 450, 0, 0).with_available(500, 200);
+        assert_eq!(instance.cache_memory(), 0);
+        assert_eq!(instance.app_memory(), 800);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_activity() {
+        let threshold = 1024_f64 * 1024_f64;
+        let activity = MemoryActivity { swap_in_bytes_per_s: 2_f64 * threshold, ..Default::default() };
+        assert!(!activity.is_thrashing(threshold));
+
+        // background swap traffic in both directions
+        let activity = MemoryActivity { swap_in_bytes_per_s: 4096_f64, swap_out_bytes_per_s: 8192_f64, ..activity };
+        assert!(!activity.is_thrashing(threshold));
+
+        let activity = MemoryActivity { swap_in_bytes_per_s: 2_f64 * threshold, swap_out_bytes_per_s: threshold, ..activity };
+        assert!(activity.is_thrashing(threshold));
+        assert_eq!(MemoryItem::default().with_activity(activity).activity(), &activity);
+    }
+}
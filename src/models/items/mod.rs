@@ -1,15 +1,16 @@
-use std::ops::Div;
-
 pub mod cpu_item;
 pub mod memory_item;
 pub mod temp_item;
 pub mod process_item;
 pub mod network_item;
-
-pub fn byte_to_kb(data: u64) -> u64 {
-    data.div(1024)
-}
-
-pub fn byte_to_mb(data: u64) -> u64 {
-    data.div(1048576)
-}
\ No newline at end of file
+pub mod process_group_item;
+pub mod unit_item;
+pub mod limit_item;
+pub mod socket_item;
+pub mod file_item;
+pub mod connection_item;
+pub mod disk_item;
+pub mod disk_io_item;
+pub mod summary_item;
+pub mod topology_item;
+pub mod pressure_item;
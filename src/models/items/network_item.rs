@@ -1,27 +1,86 @@
-#[derive(Default)]
+use crate::models::units::per_second;
+
+// traffic of a network interface since the previous refresh, or summed over all interfaces
+#[derive(Clone, Default, Debug)]
 pub struct NetworkItem {
+    interface: String,
     tx: u64,
     rx: u64,
     total_tx: u64,
     total_rx: u64,
+    packets_tx: u64,
+    packets_rx: u64,
+    errors_tx: u64,
+    errors_rx: u64,
+    drops_tx: u64,                  // from /proc/net/dev, sysinfo does not expose drops
+    drops_rx: u64,
+    mac: Option<String>,
+    ip_addresses: Vec<String>,      // address/prefix
+    mtu: Option<u64>,
+    interval_s: f64,                // time since the previous refresh the deltas were measured over
 }
 
 impl NetworkItem {
     pub fn new(
+        interface: String,
         tx: u64,
         rx: u64,
         total_tx: u64,
         total_rx: u64,
     ) -> Self {
         Self {
+            interface,
             tx,
             rx,
             total_tx,
             total_rx,
+            ..Default::default()
         }
     }
 
-    // GETTERS 
+    pub fn with_packets(mut self, packets_tx: u64, packets_rx: u64) -> Self {
+        self.packets_tx = packets_tx;
+        self.packets_rx = packets_rx;
+        self
+    }
+
+    pub fn with_errors(mut self, errors_tx: u64, errors_rx: u64) -> Self {
+        self.errors_tx = errors_tx;
+        self.errors_rx = errors_rx;
+        self
+    }
+
+    pub fn with_drops(mut self, drops_tx: u64, drops_rx: u64) -> Self {
+        self.drops_tx = drops_tx;
+        self.drops_rx = drops_rx;
+        self
+    }
+
+    pub fn with_interval(mut self, interval_s: f64) -> Self {
+        self.interval_s = interval_s;
+        self
+    }
+
+    pub fn with_mac(mut self, mac: String) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+
+    pub fn with_ip_addresses(mut self, ip_addresses: Vec<String>) -> Self {
+        self.ip_addresses = ip_addresses;
+        self
+    }
+
+    pub fn with_mtu(mut self, mtu: u64) -> Self {
+        self.mtu = Some(mtu);
+        self
+    }
+
+    // GETTERS
+    pub fn interface(&self) -> &str {
+        &self.interface
+    }
+
     pub fn tx(&self) -> u64 {
         self.tx
     }
@@ -37,4 +96,50 @@ impl NetworkItem {
     pub fn total_rx(&self) -> u64 {
         self.total_rx
     }
-}
\ No newline at end of file
+
+    pub fn packets_tx(&self) -> u64 {
+        self.packets_tx
+    }
+
+    pub fn packets_rx(&self) -> u64 {
+        self.packets_rx
+    }
+
+    pub fn errors_tx(&self) -> u64 {
+        self.errors_tx
+    }
+
+    pub fn errors_rx(&self) -> u64 {
+        self.errors_rx
+    }
+
+    pub fn drops_tx(&self) -> u64 {
+        self.drops_tx
+    }
+
+    pub fn drops_rx(&self) -> u64 {
+        self.drops_rx
+    }
+
+    // a delta of the item (e.g. tx, packets_rx) per second
+    pub fn per_s(&self, delta: u64) -> f64 {
+        per_second(delta, self.interval_s)
+    }
+
+    // errors or drops since the previous refresh, e.g. a flapping nic or ring buffer overflow
+    pub fn has_errors_or_drops(&self) -> bool {
+        self.errors_tx + self.errors_rx + self.drops_tx + self.drops_rx > 0
+    }
+
+    pub fn mac(&self) -> Option<&str> {
+        self.mac.as_deref()
+    }
+
+    pub fn ip_addresses(&self) -> &[String] {
+        &self.ip_addresses
+    }
+
+    pub fn mtu(&self) -> Option<u64> {
+        self.mtu
+    }
+}
@@ -0,0 +1,131 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PressureResource {
+    #[default]
+    Cpu,
+    Memory,
+    Io,
+}
+
+impl PressureResource {
+    pub const ALL: [PressureResource; 3] = [Self::Cpu, Self::Memory, Self::Io];
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Cpu => Self::Memory,
+            Self::Memory => Self::Io,
+            Self::Io => Self::Cpu,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+            Self::Io => "io",
+        }
+    }
+}
+
+// how a stall percentage compares to the configured thresholds
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PressureLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
+impl PressureLevel {
+    pub fn of(percent: f32, warning: f32, critical: f32) -> Self {
+        if percent >= critical {
+            Self::Critical
+        }
+        else if percent >= warning {
+            Self::Warning
+        }
+        else {
+            Self::Normal
+        }
+    }
+}
+
+// percent of wall time tasks were stalled, averaged over 10s, 60s and 300s
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct StallAverages {
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+}
+
+// "some" is the time at least one task was stalled, "full" the time all non-idle tasks were
+// stalled at once. full is None on kernels not reporting it for the resource
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct ResourcePressure {
+    pub some: StallAverages,
+    pub full: Option<StallAverages>,
+}
+
+// pressure stall information of the system, resources are None without psi support
+#[derive(Clone, Default, Debug)]
+pub struct PressureItem {
+    cpu: Option<ResourcePressure>,
+    memory: Option<ResourcePressure>,
+    io: Option<ResourcePressure>,
+}
+
+impl PressureItem {
+    pub fn new(cpu: Option<ResourcePressure>, memory: Option<ResourcePressure>, io: Option<ResourcePressure>) -> Self {
+        Self {
+            cpu,
+            memory,
+            io,
+        }
+    }
+
+    // GETTERS
+    pub fn resource(&self, resource: PressureResource) -> Option<&ResourcePressure> {
+        match resource {
+            PressureResource::Cpu => self.cpu.as_ref(),
+            PressureResource::Memory => self.memory.as_ref(),
+            PressureResource::Io => self.io.as_ref(),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        PressureResource::ALL.iter().any(|resource| self.resource(*resource).is_some())
+    }
+
+    // the highest short term "some" stall over all resources, None without psi support
+    pub fn worst_some_avg10(&self) -> Option<f32> {
+        PressureResource::ALL
+            .iter()
+            .filter_map(|resource| self.resource(*resource))
+            .map(|pressure| pressure.some.avg10)
+            .reduce(f32::max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pressure() {
+        let pressure = |avg10: f32| ResourcePressure {
+            some: StallAverages { avg10, avg60: 0_f32, avg300: 0_f32 },
+            full: None,
+        };
+        let item = PressureItem::new(Some(pressure(1.5)), Some(pressure(12_f32)), None);
+
+        assert!(item.is_available());
+        assert_eq!(item.worst_some_avg10(), Some(12_f32));
+        assert_eq!(item.resource(PressureResource::Io), None);
+        assert_eq!(PressureResource::Io.next(), PressureResource::Cpu);
+
+        assert!(!PressureItem::default().is_available());
+        assert_eq!(PressureItem::default().worst_some_avg10(), None);
+
+        assert_eq!(PressureLevel::of(5_f32, 10_f32, 40_f32), PressureLevel::Normal);
+        assert_eq!(PressureLevel::of(10_f32, 10_f32, 40_f32), PressureLevel::Warning);
+        assert_eq!(PressureLevel::of(55_f32, 10_f32, 40_f32), PressureLevel::Critical);
+    }
+}
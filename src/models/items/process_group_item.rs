@@ -0,0 +1,166 @@
+use std::path::Path;
+use crate::models::Sortable;
+use crate::models::items::process_item::{ProcessItem, ProcessItemSortOrder};
+
+// field that processes are folded by in the grouped process list
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProcessGroupBy {
+    Name,
+    Exe,
+    Cgroup,
+}
+
+impl ProcessGroupBy {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ProcessGroupBy::Name => "name",
+            ProcessGroupBy::Exe => "executable",
+            ProcessGroupBy::Cgroup => "cgroup/container",
+        }
+    }
+
+    // key the process is grouped under
+    pub fn key(&self, item: &ProcessItem) -> String {
+        match self {
+            ProcessGroupBy::Name => item.name().to_string(),
+            // processes with an unknown executable path (e.g. permission denied) fall back to their name
+            ProcessGroupBy::Exe => {
+                if Path::new(item.path()).is_absolute() {
+                    item.path().to_string()
+                }
+                else {
+                    item.name().to_string()
+                }
+            }
+            // processes whose cgroup could not be read (e.g. exited, non Linux) are grouped together
+            ProcessGroupBy::Cgroup => {
+                if item.cgroup_name().is_empty() {
+                    String::from("-")
+                }
+                else {
+                    item.cgroup_name().to_string()
+                }
+            }
+        }
+    }
+}
+
+// aggregate of all processes sharing a group key
+#[derive(Clone, Default, Debug)]
+pub struct ProcessGroupItem {
+    key: String,
+    count: usize,
+    cpu_usage: f32,
+    memory_usage: u64,
+    memory_percent: f32,
+    virtual_memory: u64,
+    threads: u32,
+}
+
+impl ProcessGroupItem {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            ..Default::default()
+        }
+    }
+
+    // MUTATORS
+    pub fn add(&mut self, item: &ProcessItem) {
+        self.count += 1;
+        self.cpu_usage += item.cpu_usage();
+        self.memory_usage += item.memory_usage();
+        self.memory_percent += item.memory_percent();
+        self.virtual_memory += item.virtual_memory();
+        self.threads += item.threads();
+    }
+
+    // GETTERS
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    pub fn memory_usage(&self) -> u64 {
+        self.memory_usage
+    }
+
+    pub fn memory_percent(&self) -> f32 {
+        self.memory_percent
+    }
+
+    pub fn virtual_memory(&self) -> u64 {
+        self.virtual_memory
+    }
+
+    pub fn threads(&self) -> u32 {
+        self.threads
+    }
+}
+
+// groups are sorted with the process list's sort keys: name sorts by group key, aggregated
+// fields sort by their sum, pid sorts by process count, remaining fields compare equal
+impl Sortable<ProcessItemSortOrder> for ProcessGroupItem {
+    fn cmp_with(&self, other: &Self, sort: &ProcessItemSortOrder) -> std::cmp::Ordering {
+        match sort {
+            ProcessItemSortOrder::PidInc =>             self.count.cmp(&other.count),
+            ProcessItemSortOrder::PidDec =>             other.count.cmp(&self.count),
+            ProcessItemSortOrder::NameInc |
+            ProcessItemSortOrder::CmdInc |
+            ProcessItemSortOrder::CgroupInc =>          self.key.cmp(&other.key),
+            ProcessItemSortOrder::NameDec |
+            ProcessItemSortOrder::CmdDec |
+            ProcessItemSortOrder::CgroupDec =>          other.key.cmp(&self.key),
+            ProcessItemSortOrder::CpuUsageInc =>        self.cpu_usage.partial_cmp(&other.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::CpuUsageDec =>        other.cpu_usage.partial_cmp(&self.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::MemoryUsageInc =>     self.memory_usage.cmp(&other.memory_usage),
+            ProcessItemSortOrder::MemoryUsageDec =>     other.memory_usage.cmp(&self.memory_usage),
+            ProcessItemSortOrder::MemoryPercentInc =>   self.memory_percent.partial_cmp(&other.memory_percent).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::MemoryPercentDec =>   other.memory_percent.partial_cmp(&self.memory_percent).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::VirtualMemoryInc =>   self.virtual_memory.cmp(&other.virtual_memory),
+            ProcessItemSortOrder::VirtualMemoryDec =>   other.virtual_memory.cmp(&self.virtual_memory),
+            ProcessItemSortOrder::ThreadsInc =>         self.threads.cmp(&other.threads),
+            ProcessItemSortOrder::ThreadsDec =>         other.threads.cmp(&self.threads),
+            _ =>                                        std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_by() {
+        let with_exe = ProcessItem::new(1, String::from("postgres"), 1.0, 1, 0, 0, 0, String::from("Sleeping"), String::from("/usr/bin/postgres"));
+        let without_exe = ProcessItem::new(2, String::from("postgres"), 1.0, 1, 0, 0, 0, String::from("Sleeping"), String::from("Permission Denied"));
+
+        assert_eq!(ProcessGroupBy::Name.key(&with_exe), "postgres");
+        assert_eq!(ProcessGroupBy::Exe.key(&with_exe), "/usr/bin/postgres");
+        assert_eq!(ProcessGroupBy::Exe.key(&without_exe), "postgres");
+
+        let in_container = with_exe.clone().with_cgroup(String::from("/docker/4f1c2a9b8e7d6c5b"), String::from("docker:4f1c2a9b8e7d"));
+        assert_eq!(ProcessGroupBy::Cgroup.key(&in_container), "docker:4f1c2a9b8e7d");
+        assert_eq!(ProcessGroupBy::Cgroup.key(&without_exe), "-");
+    }
+
+    #[test]
+    fn test_add() {
+        let mut group = ProcessGroupItem::new(String::from("gunicorn"));
+        group.add(&ProcessItem::new(1, String::from("gunicorn"), 1.5, 100, 0, 0, 0, String::from("Sleeping"), String::from("test/")).with_threads(2));
+        group.add(&ProcessItem::new(2, String::from("gunicorn"), 2.5, 50, 0, 0, 0, String::from("Sleeping"), String::from("test/")).with_threads(1));
+
+        assert_eq!(group.key(), "gunicorn");
+        assert_eq!(group.count(), 2);
+        assert_eq!(group.cpu_usage(), 4.0);
+        assert_eq!(group.memory_usage(), 150);
+        assert_eq!(group.threads(), 3);
+    }
+}
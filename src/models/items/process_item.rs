@@ -1,7 +1,45 @@
 use std::ops::Div;
 
+use serde::{Deserialize, Serialize};
 use crate::{models::{Filterable, Sortable}};
 
+// how process cpu usage is scaled, named after the modes of top
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CpuMode {
+    Irix,                           // percent of a single cpu, a process busy on 4 cpus is at 400%
+    Solaris,                        // percent of all logical cpus, at most 100%
+    #[default]
+    Physical,                       // percent of the physical cores, more than 100% with SMT
+}
+
+impl CpuMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Irix => Self::Solaris,
+            Self::Solaris => Self::Physical,
+            Self::Physical => Self::Irix,
+        }
+    }
+
+    // shown in the cpu column header
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Irix => "irix",
+            Self::Solaris => "solaris",
+            Self::Physical => "phys",
+        }
+    }
+
+    pub fn divisor(self, logical_cpus: usize, physical_cores: usize) -> f32 {
+        match self {
+            Self::Irix => 1_f32,
+            Self::Solaris => logical_cpus.max(1) as f32,
+            Self::Physical => physical_cores.max(1) as f32,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum ProcessItemSortOrder {
     PidInc,
@@ -16,23 +54,65 @@ pub enum ProcessItemSortOrder {
     StatusDec,
     RuntimeInc,
     RuntimeDec,
+    UserInc,
+    UserDec,
+    PpidInc,
+    PpidDec,
+    CmdInc,
+    CmdDec,
+    MemoryPercentInc,
+    MemoryPercentDec,
+    VirtualMemoryInc,
+    VirtualMemoryDec,
+    StartTimeInc,
+    StartTimeDec,
+    CpuTimeInc,
+    CpuTimeDec,
+    ReadRateInc,
+    ReadRateDec,
+    WriteRateInc,
+    WriteRateDec,
+    ThreadsInc,
+    ThreadsDec,
+    NiceInc,
+    NiceDec,
+    CgroupInc,
+    CgroupDec,
+    MinorFaultsInc,
+    MinorFaultsDec,
+    MajorFaultsInc,
+    MajorFaultsDec,
 }
 
 #[derive(Default, Clone)]
 pub struct ProcessItem {
     pid:                    u32,
     name:                   String,
-    cpu_usage:              f32,
+    cpu_usage:              f32,            // in the cpu mode set, see set_cpu_mode
+    raw_cpu_usage:          f32,            // percent of a single cpu
+    logical_cpus:           usize,
+    physical_cores:         usize,
     memory_usage:           u64,
-    //read_bytes:             u64,
-    //written_bytes:          u64,
-    //total_read_bytes:       u64,
-    //total_written_bytes:    u64,
     start_time:             u64,
     run_time:               u64,
     accumulated_cpu_time:   u64,
     status:                 String,
     path:                   String,
+    user:                   String,
+    ppid:                   u32,
+    cmd:                    String,
+    memory_percent:         f32,
+    virtual_memory:         u64,
+    read_bytes_per_s:       u64,
+    written_bytes_per_s:    u64,
+    total_read_bytes:       u64,
+    total_written_bytes:    u64,
+    threads:                u32,
+    nice:                   i32,
+    minor_faults:           u64,
+    major_faults:           u64,
+    cgroup:                 String,
+    cgroup_name:            String,
 }
 
 impl ProcessItem {
@@ -41,10 +121,6 @@ impl ProcessItem {
         name:                   String,
         cpu_usage:              f32,
         memory_usage:           u64,
-        //read_bytes:             u64,
-        //written_bytes:          u64,
-        //total_read_bytes:       u64,
-        //total_written_bytes:    u64,
         start_time:             u64,
         run_time:               u64,
         accumulated_cpu_time:   u64,
@@ -55,19 +131,91 @@ impl ProcessItem {
             pid,
             name,
             cpu_usage,
+            raw_cpu_usage: cpu_usage,
             memory_usage,
-            //read_bytes,
-            //written_bytes,
-            //total_read_bytes,
-            //total_written_bytes,
             start_time,
             run_time,
             accumulated_cpu_time,
             status,
             path,
+            ..Default::default()
         }
     }
 
+    // SETTERS
+    pub fn with_cpu_counts(mut self, logical_cpus: usize, physical_cores: usize) -> Self {
+        self.logical_cpus = logical_cpus;
+        self.physical_cores = physical_cores;
+        self
+    }
+
+    // scales the cpu usage, the usage is per single cpu (irix) until a mode is set
+    pub fn set_cpu_mode(&mut self, mode: CpuMode) {
+        self.cpu_usage = self.raw_cpu_usage / mode.divisor(self.logical_cpus, self.physical_cores);
+    }
+
+    pub fn with_user(mut self, user: String) -> Self {
+        self.user = user;
+        self
+    }
+
+    pub fn with_ppid(mut self, ppid: u32) -> Self {
+        self.ppid = ppid;
+        self
+    }
+
+    pub fn with_cmd(mut self, cmd: String) -> Self {
+        self.cmd = cmd;
+        self
+    }
+
+    pub fn with_memory_percent(mut self, memory_percent: f32) -> Self {
+        self.memory_percent = memory_percent;
+        self
+    }
+
+    pub fn with_virtual_memory(mut self, virtual_memory: u64) -> Self {
+        self.virtual_memory = virtual_memory;
+        self
+    }
+
+    pub fn with_disk_usage(
+        mut self,
+        read_bytes_per_s: u64,
+        written_bytes_per_s: u64,
+        total_read_bytes: u64,
+        total_written_bytes: u64,
+    ) -> Self {
+        self.read_bytes_per_s = read_bytes_per_s;
+        self.written_bytes_per_s = written_bytes_per_s;
+        self.total_read_bytes = total_read_bytes;
+        self.total_written_bytes = total_written_bytes;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: u32) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    pub fn with_nice(mut self, nice: i32) -> Self {
+        self.nice = nice;
+        self
+    }
+
+    pub fn with_faults(mut self, minor_faults: u64, major_faults: u64) -> Self {
+        self.minor_faults = minor_faults;
+        self.major_faults = major_faults;
+        self
+    }
+
+    // cgroup path and its decoded short name, e.g. sshd.service or docker:4f1c2a9b8e7d
+    pub fn with_cgroup(mut self, cgroup: String, cgroup_name: String) -> Self {
+        self.cgroup = cgroup;
+        self.cgroup_name = cgroup_name;
+        self
+    }
+
     // GETTERS
     pub fn pid(&self) -> u32 {
         self.pid
@@ -85,22 +233,6 @@ impl ProcessItem {
         self.memory_usage
     }
 
-    /*pub fn read_bytes(&self) -> u64 {
-        self.read_bytes
-    }
-
-    pub fn written_bytes(&self) -> u64 {
-        self.written_bytes
-    }
-
-    pub fn total_read_bytes(&self) -> u64 {
-        self.total_read_bytes
-    }
-
-    pub fn total_written_bytes(&self) -> u64 {
-        self.total_written_bytes
-    }*/
-
     pub fn start_time(&self) -> u64 {
         self.start_time
     }
@@ -131,6 +263,66 @@ impl ProcessItem {
     pub fn path(&self) -> &str {
         &self.path
     }
+
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    pub fn ppid(&self) -> u32 {
+        self.ppid
+    }
+
+    pub fn cmd(&self) -> &str {
+        &self.cmd
+    }
+
+    pub fn memory_percent(&self) -> f32 {
+        self.memory_percent
+    }
+
+    pub fn virtual_memory(&self) -> u64 {
+        self.virtual_memory
+    }
+
+    pub fn read_bytes_per_s(&self) -> u64 {
+        self.read_bytes_per_s
+    }
+
+    pub fn written_bytes_per_s(&self) -> u64 {
+        self.written_bytes_per_s
+    }
+
+    pub fn total_read_bytes(&self) -> u64 {
+        self.total_read_bytes
+    }
+
+    pub fn total_written_bytes(&self) -> u64 {
+        self.total_written_bytes
+    }
+
+    pub fn threads(&self) -> u32 {
+        self.threads
+    }
+
+    pub fn nice(&self) -> i32 {
+        self.nice
+    }
+
+    pub fn minor_faults(&self) -> u64 {
+        self.minor_faults
+    }
+
+    pub fn major_faults(&self) -> u64 {
+        self.major_faults
+    }
+
+    pub fn cgroup(&self) -> &str {
+        &self.cgroup
+    }
+
+    pub fn cgroup_name(&self) -> &str {
+        &self.cgroup_name
+    }
 }
 
 // PartialEq is needed for comparison, e.g., calling contains
@@ -187,6 +379,19 @@ impl Filterable for ProcessItem {
             }
         }
 
+        // by user
+        if let Some(user_str) = filter.strip_prefix("user=") {
+            return self.user() == user_str.trim();
+        }
+
+        // by cgroup: short name, cgroup path or a parent of the cgroup path
+        if let Some(cgroup_str) = filter.strip_prefix("cgroup=") {
+            let cgroup_str = cgroup_str.trim();
+            return self.cgroup_name() == cgroup_str
+                || self.cgroup() == cgroup_str
+                || self.cgroup().starts_with(&format!("{}/", cgroup_str.trim_end_matches('/')));
+        }
+
         // by name
         self.name.to_lowercase().contains(&filter.to_lowercase()) 
     }
@@ -207,6 +412,34 @@ impl Sortable<ProcessItemSortOrder> for ProcessItem {
             ProcessItemSortOrder::StatusDec =>          other.status.cmp(&self.status),
             ProcessItemSortOrder::RuntimeInc =>         self.run_time.cmp(&other.run_time),
             ProcessItemSortOrder::RuntimeDec =>         other.run_time.cmp(&self.run_time),
+            ProcessItemSortOrder::UserInc =>            self.user.cmp(&other.user),
+            ProcessItemSortOrder::UserDec =>            other.user.cmp(&self.user),
+            ProcessItemSortOrder::PpidInc =>            self.ppid.cmp(&other.ppid),
+            ProcessItemSortOrder::PpidDec =>            other.ppid.cmp(&self.ppid),
+            ProcessItemSortOrder::CmdInc =>             self.cmd.cmp(&other.cmd),
+            ProcessItemSortOrder::CmdDec =>             other.cmd.cmp(&self.cmd),
+            ProcessItemSortOrder::MemoryPercentInc =>   self.memory_percent.partial_cmp(&other.memory_percent).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::MemoryPercentDec =>   other.memory_percent.partial_cmp(&self.memory_percent).unwrap_or(std::cmp::Ordering::Equal),
+            ProcessItemSortOrder::VirtualMemoryInc =>   self.virtual_memory.cmp(&other.virtual_memory),
+            ProcessItemSortOrder::VirtualMemoryDec =>   other.virtual_memory.cmp(&self.virtual_memory),
+            ProcessItemSortOrder::StartTimeInc =>       self.start_time.cmp(&other.start_time),
+            ProcessItemSortOrder::StartTimeDec =>       other.start_time.cmp(&self.start_time),
+            ProcessItemSortOrder::CpuTimeInc =>         self.accumulated_cpu_time.cmp(&other.accumulated_cpu_time),
+            ProcessItemSortOrder::CpuTimeDec =>         other.accumulated_cpu_time.cmp(&self.accumulated_cpu_time),
+            ProcessItemSortOrder::ReadRateInc =>        self.read_bytes_per_s.cmp(&other.read_bytes_per_s),
+            ProcessItemSortOrder::ReadRateDec =>        other.read_bytes_per_s.cmp(&self.read_bytes_per_s),
+            ProcessItemSortOrder::WriteRateInc =>       self.written_bytes_per_s.cmp(&other.written_bytes_per_s),
+            ProcessItemSortOrder::WriteRateDec =>       other.written_bytes_per_s.cmp(&self.written_bytes_per_s),
+            ProcessItemSortOrder::ThreadsInc =>         self.threads.cmp(&other.threads),
+            ProcessItemSortOrder::ThreadsDec =>         other.threads.cmp(&self.threads),
+            ProcessItemSortOrder::NiceInc =>            self.nice.cmp(&other.nice),
+            ProcessItemSortOrder::NiceDec =>            other.nice.cmp(&self.nice),
+            ProcessItemSortOrder::CgroupInc =>          self.cgroup_name.cmp(&other.cgroup_name),
+            ProcessItemSortOrder::CgroupDec =>          other.cgroup_name.cmp(&self.cgroup_name),
+            ProcessItemSortOrder::MinorFaultsInc =>     self.minor_faults.cmp(&other.minor_faults),
+            ProcessItemSortOrder::MinorFaultsDec =>     other.minor_faults.cmp(&self.minor_faults),
+            ProcessItemSortOrder::MajorFaultsInc =>     self.major_faults.cmp(&other.major_faults),
+            ProcessItemSortOrder::MajorFaultsDec =>     other.major_faults.cmp(&self.major_faults),
         }
     }
 }
@@ -215,7 +448,7 @@ impl Sortable<ProcessItemSortOrder> for ProcessItem {
 pub mod test {
     use crate::models::Filterable;
 
-    use super::ProcessItem;
+    use super::{CpuMode, ProcessItem};
 
     #[test]
     fn test_constructors() {
@@ -270,4 +503,47 @@ pub mod test {
         assert_eq!(instance_1.matches_filter("aa"), false);
         assert_eq!(instance_1.matches_filter(&format!("pid={}", &instance_1.pid.to_string())), true);
     }
+
+    #[test]
+    fn test_cpu_mode() {
+        // busy on 3 of 8 logical cpus, 4 physical cores
+        let mut instance = ProcessItem::new(1, String::from("a"), 300.0, 1, 0, 10, 10, String::from("test"), String::from("test"))
+            .with_cpu_counts(8, 4);
+        assert_eq!(instance.cpu_usage(), 300.0);
+
+        instance.set_cpu_mode(CpuMode::Solaris);
+        assert_eq!(instance.cpu_usage(), 37.5);
+        assert!(instance.matches_filter("cpu>30"));
+
+        instance.set_cpu_mode(CpuMode::Physical);
+        assert_eq!(instance.cpu_usage(), 75.0);
+        instance.set_cpu_mode(CpuMode::Irix);
+        assert_eq!(instance.cpu_usage(), 300.0);
+        assert!(!instance.matches_filter("cpu<100"));
+    }
+
+    #[test]
+    fn test_user_filter() {
+        let instance = ProcessItem::new(1, String::from("a"), 1.0, 1, 0, 10, 10, String::from("test"), String::from("test"))
+            .with_user(String::from("alice"));
+
+        assert_eq!(instance.user(), "alice");
+        assert!(instance.matches_filter("user=alice"));
+        assert!(instance.matches_filter("user= alice"));
+        assert!(!instance.matches_filter("user=bob"));
+        assert!(!ProcessItem::default().matches_filter("user=alice"));
+    }
+
+    #[test]
+    fn test_cgroup_filter() {
+        let instance = ProcessItem::default()
+            .with_cgroup(String::from("/system.slice/sshd.service"), String::from("sshd.service"));
+
+        assert!(instance.matches_filter("cgroup=sshd.service"));
+        assert!(instance.matches_filter("cgroup=/system.slice/sshd.service"));
+        assert!(instance.matches_filter("cgroup=/system.slice"));
+        assert!(instance.matches_filter("cgroup=/"));
+        assert!(!instance.matches_filter("cgroup=/system.sl"));
+        assert!(!instance.matches_filter("cgroup=cron.service"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,68 @@
+// an open socket, e.g. a tcp connection or a listening udp socket
+#[derive(Clone, Default, Debug)]
+pub struct SocketItem {
+    protocol: String,           // tcp, tcp6, udp, udp6, or u_str, u_dgr, u_seq for unix sockets
+    local: String,              // address:port, or the path of a unix socket
+    remote: String,
+    state: String,              // as printed by `ss`, e.g. LISTEN, ESTAB
+    inode: u64,
+}
+
+impl SocketItem {
+    pub fn new(protocol: String, local: String, remote: String, state: String, inode: u64) -> Self {
+        Self {
+            protocol,
+            local,
+            remote,
+            state,
+            inode,
+        }
+    }
+
+    // GETTERS
+    pub fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    pub fn remote(&self) -> &str {
+        &self.remote
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+
+    pub fn is_unix(&self) -> bool {
+        self.protocol.starts_with("u_")
+    }
+
+    pub fn local_port(&self) -> Option<u16> {
+        self.port(&self.local)
+    }
+
+    pub fn remote_port(&self) -> Option<u16> {
+        self.port(&self.remote)
+    }
+
+    // port of an address:port, None for unix sockets and unspecified ports (*)
+    fn port(&self, address: &str) -> Option<u16> {
+        if self.is_unix() {
+            return None;
+        }
+        address.rsplit_once(':')?.1.parse().ok()
+    }
+}
+
+impl PartialEq for SocketItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.inode.eq(&other.inode)
+    }
+}
@@ -0,0 +1,133 @@
+// load average of the last 1, 5 and 15 minutes
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+// processes by state, kernel threads count as processes and user threads only towards threads
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct TaskCounts {
+    pub processes: usize,
+    pub threads: usize,
+    pub running: usize,
+    pub sleeping: usize,
+    pub zombie: usize,
+}
+
+// overview of the system for the summary header
+#[derive(Clone, Default, Debug)]
+pub struct SummaryItem {
+    host_name: String,
+    kernel_version: String,
+    os_version: String,
+    uptime_s: u64,
+    cpu_count: usize,
+    load_average: LoadAverage,
+    tasks: TaskCounts,
+    context_switches_per_s: f64,    // 0 before the second refresh
+    interrupts_per_s: f64,
+}
+
+impl SummaryItem {
+    pub fn new(host_name: String, kernel_version: String, os_version: String, uptime_s: u64, cpu_count: usize) -> Self {
+        Self {
+            host_name,
+            kernel_version,
+            os_version,
+            uptime_s,
+            cpu_count,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_load_average(mut self, load_average: LoadAverage) -> Self {
+        self.load_average = load_average;
+        self
+    }
+
+    pub fn with_tasks(mut self, tasks: TaskCounts) -> Self {
+        self.tasks = tasks;
+        self
+    }
+
+    pub fn with_rates(mut self, context_switches_per_s: f64, interrupts_per_s: f64) -> Self {
+        self.context_switches_per_s = context_switches_per_s;
+        self.interrupts_per_s = interrupts_per_s;
+        self
+    }
+
+    // more runnable tasks over the last minute than cpus to run them on
+    pub fn is_overloaded(&self) -> bool {
+        self.cpu_count > 0 && self.load_average.one > self.cpu_count as f64
+    }
+
+    // uptime like `uptime -p`, e.g. "3d 4h 12m"
+    pub fn uptime(&self) -> String {
+        let days = self.uptime_s / 86_400;
+        let hours = self.uptime_s % 86_400 / 3_600;
+        let minutes = self.uptime_s % 3_600 / 60;
+
+        if days > 0 {
+            format!("{}d {}h {}m", days, hours, minutes)
+        }
+        else if hours > 0 {
+            format!("{}h {}m", hours, minutes)
+        }
+        else {
+            format!("{}m", minutes)
+        }
+    }
+
+    // GETTERS
+    pub fn host_name(&self) -> &str {
+        &self.host_name
+    }
+
+    pub fn kernel_version(&self) -> &str {
+        &self.kernel_version
+    }
+
+    pub fn os_version(&self) -> &str {
+        &self.os_version
+    }
+
+    pub fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
+    pub fn load_average(&self) -> LoadAverage {
+        self.load_average
+    }
+
+    pub fn tasks(&self) -> TaskCounts {
+        self.tasks
+    }
+
+    pub fn context_switches_per_s(&self) -> f64 {
+        self.context_switches_per_s
+    }
+
+    pub fn interrupts_per_s(&self) -> f64 {
+        self.interrupts_per_s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let summary = SummaryItem::new(String::from("db1"), String::from("6.8.0"), String::from("Linux 24.04 Ubuntu"), 273_720, 4);
+        assert_eq!(summary.uptime(), "3d 4h 2m");
+        assert!(!summary.is_overloaded());
+
+        let summary = summary.with_load_average(LoadAverage { one: 4.5, five: 3.0, fifteen: 2.0 });
+        assert!(summary.is_overloaded());
+
+        assert_eq!(SummaryItem::new(String::new(), String::new(), String::new(), 3_659, 0).uptime(), "1h 0m");
+        assert_eq!(SummaryItem::new(String::new(), String::new(), String::new(), 59, 0).uptime(), "0m");
+    }
+}
@@ -1,35 +1,91 @@
+// degrees below critical_temp from which a sensor is considered high or critical
+pub const TEMP_WARNING_MARGIN: f32 = 20_f32;
+pub const TEMP_CRITICAL_MARGIN: f32 = 5_f32;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TempLevel {
+    Normal,
+    High,                           // within TEMP_WARNING_MARGIN of critical_temp
+    Critical,                       // within TEMP_CRITICAL_MARGIN of or above critical_temp
+}
+
+// reading of a temperature sensor in degrees celsius, None if the sensor does not report it
 #[derive(Clone, Default, Debug)]
 pub struct TempItem {
-    //TODO
-    temp: f32,
-    max_temp: f32,
-    critical_temp: f32,
+    temp: Option<f32>,
+    peak_temp: Option<f32>,         // highest temperature since the sensor was first read, not a threshold
+    critical_temp: Option<f32>,     // threshold from which the hardware throttles or shuts down
     label: String,
 }
 
 impl TempItem {
-    pub fn new(temp: f32, max_temp: f32, critical_temp: f32, label: String) -> Self {
+    pub fn new(temp: Option<f32>, peak_temp: Option<f32>, critical_temp: Option<f32>, label: String) -> Self {
+        // sysinfo reports NaN when it fails to read a value
+        let reading = |value: Option<f32>| value.filter(|value| !value.is_nan());
+
         Self {
-            temp,
-            max_temp,
-            critical_temp,
+            temp: reading(temp),
+            peak_temp: reading(peak_temp),
+            critical_temp: reading(critical_temp),
             label
         }
     }
 
-    pub fn temp(&self) -> f32 {
+    pub fn temp(&self) -> Option<f32> {
         self.temp
     }
 
-    pub fn max_temp(&self) -> f32 {
-        self.max_temp
+    pub fn peak_temp(&self) -> Option<f32> {
+        self.peak_temp
     }
 
-    pub fn critical_temp(&self) -> f32 {
+    pub fn critical_temp(&self) -> Option<f32> {
         self.critical_temp
     }
 
     pub fn label(&self) -> &str {
         &self.label
     }
-}
\ No newline at end of file
+
+    // sensors without a critical temperature are always normal, the peak is only a running maximum
+    pub fn level(&self) -> TempLevel {
+        // sensors without thresholds report 0
+        let (Some(temp), Some(critical_temp)) = (self.temp, self.critical_temp.filter(|critical_temp| *critical_temp > 0_f32))
+        else {
+            return TempLevel::Normal;
+        };
+
+        if temp >= critical_temp - TEMP_CRITICAL_MARGIN {
+            TempLevel::Critical
+        }
+        else if temp >= critical_temp - TEMP_WARNING_MARGIN {
+            TempLevel::High
+        }
+        else {
+            TempLevel::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        let label = String::from("coretemp Package id 0");
+
+        assert_eq!(TempItem::new(Some(45_f32), Some(80_f32), Some(100_f32), label.clone()).level(), TempLevel::Normal);
+        assert_eq!(TempItem::new(Some(85_f32), Some(85_f32), Some(100_f32), label.clone()).level(), TempLevel::High);
+        assert_eq!(TempItem::new(Some(95_f32), Some(95_f32), Some(100_f32), label.clone()).level(), TempLevel::Critical);
+
+        // at its peak without a critical temperature, e.g. steady state right after start
+        assert_eq!(TempItem::new(Some(60_f32), Some(60_f32), None, label.clone()).level(), TempLevel::Normal);
+
+        // missing readings and thresholds
+        let item = TempItem::new(Some(f32::NAN), None, Some(0_f32), label.clone());
+        assert_eq!(item.temp(), None);
+        assert_eq!(item.level(), TempLevel::Normal);
+        assert_eq!(TempItem::new(Some(60_f32), None, Some(0_f32), label).level(), TempLevel::Normal);
+    }
+}
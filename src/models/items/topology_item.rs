@@ -0,0 +1,138 @@
+use std::collections::BTreeSet;
+
+// placement of a logical cpu: its socket, core and NUMA node
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CpuPlacementItem {
+    pub cpu: usize,
+    pub package: u32,
+    pub core: u32,
+    pub node: Option<usize>,
+    pub siblings: Vec<usize>,       // logical cpus sharing the core, including cpu
+}
+
+impl CpuPlacementItem {
+    // an additional SMT thread of its core, the first (lowest numbered) thread is the primary
+    pub fn is_smt_sibling(&self) -> bool {
+        self.siblings.iter().min().is_some_and(|primary| *primary != self.cpu)
+    }
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CacheItem {
+    pub level: u8,
+    pub kind: String,               // Data, Instruction or Unified
+    pub size: u64,                  // bytes
+    pub shared_cpus: Vec<usize>,
+}
+
+impl CacheItem {
+    // e.g. L1d, L1i, L2
+    pub fn name(&self) -> String {
+        match self.kind.as_str() {
+            "Data" => format!("L{}d", self.level),
+            "Instruction" => format!("L{}i", self.level),
+            _ => format!("L{}", self.level),
+        }
+    }
+}
+
+// cpu topology from sysfs with the brand and vendor from sysinfo, empty where sysfs is not available
+#[derive(Clone, Default, Debug)]
+pub struct TopologyItem {
+    brand: String,
+    vendor: String,
+    cpus: Vec<CpuPlacementItem>,
+    caches: Vec<CacheItem>,
+}
+
+impl TopologyItem {
+    pub fn new(brand: String, vendor: String, cpus: Vec<CpuPlacementItem>, caches: Vec<CacheItem>) -> Self {
+        Self {
+            brand,
+            vendor,
+            cpus,
+            caches,
+        }
+    }
+
+    pub fn brand(&self) -> &str {
+        &self.brand
+    }
+
+    pub fn vendor(&self) -> &str {
+        &self.vendor
+    }
+
+    pub fn cpus(&self) -> &[CpuPlacementItem] {
+        &self.cpus
+    }
+
+    pub fn caches(&self) -> &[CacheItem] {
+        &self.caches
+    }
+
+    pub fn placement(&self, cpu: usize) -> Option<&CpuPlacementItem> {
+        self.cpus.iter().find(|placement| placement.cpu == cpu)
+    }
+
+    pub fn packages(&self) -> BTreeSet<u32> {
+        self.cpus.iter().map(|placement| placement.package).collect()
+    }
+
+    pub fn nodes(&self) -> BTreeSet<usize> {
+        self.cpus.iter().filter_map(|placement| placement.node).collect()
+    }
+
+    pub fn physical_core_count(&self) -> usize {
+        self.cpus
+            .iter()
+            .map(|placement| (placement.package, placement.core))
+            .collect::<BTreeSet<(u32, u32)>>()
+            .len()
+    }
+
+    // more than one socket or NUMA node, the cpus are worth grouping
+    pub fn is_grouped(&self) -> bool {
+        self.packages().len() > 1 || self.nodes().len() > 1
+    }
+
+    // cpus ordered by socket, NUMA node and core, so SMT siblings follow their primary thread
+    pub fn ordered_cpus(&self) -> Vec<usize> {
+        let mut cpus: Vec<&CpuPlacementItem> = self.cpus.iter().collect();
+        cpus.sort_by_key(|placement| (placement.package, placement.node, placement.core, placement.cpu));
+        cpus.into_iter().map(|placement| placement.cpu).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn placement(cpu: usize, package: u32, core: u32, siblings: Vec<usize>) -> CpuPlacementItem {
+        CpuPlacementItem { cpu, package, core, node: Some(package as usize), siblings }
+    }
+
+    #[test]
+    fn test_topology() {
+        let topology = TopologyItem::new(
+            String::from("AMD EPYC 7543"),
+            String::from("AuthenticAMD"),
+            vec![
+                placement(0, 0, 0, vec![0, 2]),
+                placement(1, 1, 0, vec![1, 3]),
+                placement(2, 0, 0, vec![0, 2]),
+                placement(3, 1, 0, vec![1, 3]),
+            ],
+            Vec::new(),
+        );
+
+        assert_eq!(topology.physical_core_count(), 2);
+        assert!(topology.is_grouped());
+        assert_eq!(topology.ordered_cpus(), vec![0, 2, 1, 3]);
+        assert!(!topology.placement(1).unwrap().is_smt_sibling());
+        assert!(topology.placement(3).unwrap().is_smt_sibling());
+
+        let cache = CacheItem { level: 1, kind: String::from("Data"), size: 48 * 1024, shared_cpus: vec![0, 2] };
+        assert_eq!(cache.name(), "L1d");
+    }
+}
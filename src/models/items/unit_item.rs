@@ -0,0 +1,146 @@
+use crate::models::{Filterable, Sortable};
+use crate::models::items::process_item::CpuMode;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UnitItemSortOrder {
+    NameInc,
+    NameDec,
+    CpuUsageInc,
+    CpuUsageDec,
+    MemoryUsageInc,
+    MemoryUsageDec,
+    PidsInc,
+    PidsDec,
+    ReadRateInc,
+    ReadRateDec,
+    WriteRateInc,
+    WriteRateDec,
+    PressureInc,
+    PressureDec,
+}
+
+// resource usage of a systemd unit, aggregated over its cgroup. fields are None when the
+// corresponding cgroup controller is not enabled or, for rates, before the second refresh
+#[derive(Clone, Default, Debug)]
+pub struct UnitItem {
+    name: String,
+    cgroup: String,
+    cpu_usage: Option<f32>,                 // in the cpu mode set, see set_cpu_mode
+    raw_cpu_usage: Option<f32>,             // percent of a single cpu
+    logical_cpus: usize,
+    physical_cores: usize,
+    memory_usage: Option<u64>,
+    pids: Option<u64>,
+    read_bytes_per_s: Option<u64>,
+    written_bytes_per_s: Option<u64>,
+    pressure: Option<f32>,                  // highest "some" avg10 stall over cpu, memory and io
+}
+
+impl UnitItem {
+    pub fn new(
+        name: String,
+        cgroup: String,
+        cpu_usage: Option<f32>,
+        memory_usage: Option<u64>,
+        pids: Option<u64>,
+        read_bytes_per_s: Option<u64>,
+        written_bytes_per_s: Option<u64>,
+    ) -> Self {
+        Self {
+            name,
+            cgroup,
+            cpu_usage,
+            raw_cpu_usage: cpu_usage,
+            memory_usage,
+            pids,
+            read_bytes_per_s,
+            written_bytes_per_s,
+            ..Default::default()
+        }
+    }
+
+    // SETTERS
+    pub fn with_cpu_counts(mut self, logical_cpus: usize, physical_cores: usize) -> Self {
+        self.logical_cpus = logical_cpus;
+        self.physical_cores = physical_cores;
+        self
+    }
+
+    // scales the cpu usage like a process's, the usage is per single cpu (irix) until a mode is set
+    pub fn set_cpu_mode(&mut self, mode: CpuMode) {
+        let divisor = mode.divisor(self.logical_cpus, self.physical_cores);
+        self.cpu_usage = self.raw_cpu_usage.map(|cpu_usage| cpu_usage / divisor);
+    }
+
+    pub fn with_pressure(mut self, pressure: Option<f32>) -> Self {
+        self.pressure = pressure;
+        self
+    }
+
+    // GETTERS
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cgroup(&self) -> &str {
+        &self.cgroup
+    }
+
+    pub fn cpu_usage(&self) -> Option<f32> {
+        self.cpu_usage
+    }
+
+    pub fn memory_usage(&self) -> Option<u64> {
+        self.memory_usage
+    }
+
+    pub fn pids(&self) -> Option<u64> {
+        self.pids
+    }
+
+    pub fn read_bytes_per_s(&self) -> Option<u64> {
+        self.read_bytes_per_s
+    }
+
+    pub fn written_bytes_per_s(&self) -> Option<u64> {
+        self.written_bytes_per_s
+    }
+
+    pub fn pressure(&self) -> Option<f32> {
+        self.pressure
+    }
+}
+
+impl PartialEq for UnitItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cgroup.eq(&other.cgroup)
+    }
+}
+
+impl Filterable for UnitItem {
+    fn matches_filter(&self, filter: &str) -> bool {
+        self.name.to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+// units with unknown values sort below units with known values in decreasing order
+impl Sortable<UnitItemSortOrder> for UnitItem {
+    fn cmp_with(&self, other: &Self, sort: &UnitItemSortOrder) -> std::cmp::Ordering {
+        match sort {
+            UnitItemSortOrder::NameInc =>           self.name.cmp(&other.name),
+            UnitItemSortOrder::NameDec =>           other.name.cmp(&self.name),
+            UnitItemSortOrder::CpuUsageInc =>       self.cpu_usage.partial_cmp(&other.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            UnitItemSortOrder::CpuUsageDec =>       other.cpu_usage.partial_cmp(&self.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            UnitItemSortOrder::MemoryUsageInc =>    self.memory_usage.cmp(&other.memory_usage),
+            UnitItemSortOrder::MemoryUsageDec =>    other.memory_usage.cmp(&self.memory_usage),
+            UnitItemSortOrder::PidsInc =>           self.pids.cmp(&other.pids),
+            UnitItemSortOrder::PidsDec =>           other.pids.cmp(&self.pids),
+            UnitItemSortOrder::ReadRateInc =>       self.read_bytes_per_s.cmp(&other.read_bytes_per_s),
+            UnitItemSortOrder::ReadRateDec =>       other.read_bytes_per_s.cmp(&self.read_bytes_per_s),
+            UnitItemSortOrder::WriteRateInc =>      self.written_bytes_per_s.cmp(&other.written_bytes_per_s),
+            UnitItemSortOrder::WriteRateDec =>      other.written_bytes_per_s.cmp(&self.written_bytes_per_s),
+            UnitItemSortOrder::PressureInc =>       self.pressure.partial_cmp(&other.pressure).unwrap_or(std::cmp::Ordering::Equal),
+            UnitItemSortOrder::PressureDec =>       other.pressure.partial_cmp(&self.pressure).unwrap_or(std::cmp::Ordering::Equal),
+        }
+    }
+}
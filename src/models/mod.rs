@@ -1,6 +1,8 @@
 pub mod items;
 pub mod bounded_queue_model;
 pub mod vec_model;
+pub mod units;
+pub mod process_column;
 
 pub trait Filterable {
     fn matches_filter(&self, filter: &str) -> bool;
@@ -8,4 +10,17 @@ pub trait Filterable {
 
 pub trait Sortable<S> {
     fn cmp_with(&self, other: &Self, sort: &S) -> std::cmp::Ordering;
+}
+
+// multi-key sort: items are compared by each sort key in order, later keys break ties of earlier keys
+impl<T, S> Sortable<Vec<S>> for T
+where
+    T: Sortable<S>
+{
+    fn cmp_with(&self, other: &Self, sort: &Vec<S>) -> std::cmp::Ordering {
+        sort.iter()
+            .map(|key| self.cmp_with(other, key))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    }
 }
\ No newline at end of file
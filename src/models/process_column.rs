@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+use crate::models::items::process_item::{ProcessItem, ProcessItemSortOrder};
+use crate::models::items::process_group_item::ProcessGroupItem;
+use crate::models::units::{format_bytes, format_data_rate, DataUnit, UnitSystem};
+
+pub const MIN_COLUMN_WIDTH: u16 = 1;
+pub const MAX_COLUMN_WIDTH: u16 = 50;
+
+// registry of the columns that can be displayed in the process list, one per ProcessItem field
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessColumn {
+    Pid,
+    Ppid,
+    User,
+    Name,
+    Cmd,
+    Cpu,
+    Mem,
+    MemPercent,
+    Virt,
+    Status,
+    Runtime,
+    StartTime,
+    CpuTime,
+    ReadRate,
+    WriteRate,
+    Threads,
+    Nice,
+    Cgroup,
+    MinorFaults,
+    MajorFaults,
+}
+
+impl ProcessColumn {
+    pub const ALL: [ProcessColumn; 20] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Ppid,
+        ProcessColumn::User,
+        ProcessColumn::Name,
+        ProcessColumn::Cmd,
+        ProcessColumn::Cpu,
+        ProcessColumn::Mem,
+        ProcessColumn::MemPercent,
+        ProcessColumn::Virt,
+        ProcessColumn::Status,
+        ProcessColumn::Runtime,
+        ProcessColumn::StartTime,
+        ProcessColumn::CpuTime,
+        ProcessColumn::ReadRate,
+        ProcessColumn::WriteRate,
+        ProcessColumn::Threads,
+        ProcessColumn::Nice,
+        ProcessColumn::Cgroup,
+        ProcessColumn::MinorFaults,
+        ProcessColumn::MajorFaults,
+    ];
+
+    // name used in the column chooser
+    pub fn title(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Ppid => "PPID",
+            ProcessColumn::User => "User",
+            ProcessColumn::Name => "Name",
+            ProcessColumn::Cmd => "Command",
+            ProcessColumn::Cpu => "CPU usage",
+            ProcessColumn::Mem => "Memory",
+            ProcessColumn::MemPercent => "Memory %",
+            ProcessColumn::Virt => "Virtual memory",
+            ProcessColumn::Status => "Status",
+            ProcessColumn::Runtime => "Runtime",
+            ProcessColumn::StartTime => "Start time",
+            ProcessColumn::CpuTime => "CPU time",
+            ProcessColumn::ReadRate => "Disk read rate",
+            ProcessColumn::WriteRate => "Disk write rate",
+            ProcessColumn::Threads => "Threads",
+            ProcessColumn::Nice => "Nice",
+            ProcessColumn::Cgroup => "Cgroup/Container",
+            ProcessColumn::MinorFaults => "Minor page faults",
+            ProcessColumn::MajorFaults => "Major page faults",
+        }
+    }
+
+    // label used in the process list header
+    pub fn header(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID(p)",
+            ProcessColumn::Ppid => "PPID",
+            ProcessColumn::User => "USER(u)",
+            ProcessColumn::Name => "NAME(n)",
+            ProcessColumn::Cmd => "CMD",
+            ProcessColumn::Cpu => "CPU(c)(%)",
+            ProcessColumn::Mem => "MEM(m)",
+            ProcessColumn::MemPercent => "MEM(%)",
+            ProcessColumn::Virt => "VIRT",
+            ProcessColumn::Status => "STATUS",
+            ProcessColumn::Runtime => "RUNTIME",
+            ProcessColumn::StartTime => "START",
+            ProcessColumn::CpuTime => "TIME",
+            ProcessColumn::ReadRate => "READ",
+            ProcessColumn::WriteRate => "WRITE",
+            ProcessColumn::Threads => "THR",
+            ProcessColumn::Nice => "NI",
+            ProcessColumn::Cgroup => "CGROUP",
+            ProcessColumn::MinorFaults => "MINFLT",
+            ProcessColumn::MajorFaults => "MAJFLT",
+        }
+    }
+
+    pub fn cell(&self, item: &ProcessItem, unit_system: UnitSystem) -> String {
+        match self {
+            ProcessColumn::Pid => item.pid().to_string(),
+            ProcessColumn::Ppid => item.ppid().to_string(),
+            ProcessColumn::User => format!("{:.12}", item.user()),
+            ProcessColumn::Name => format!("{:.40}", item.name()),
+            ProcessColumn::Cmd => item.cmd().to_string(),
+            ProcessColumn::Cpu => format!("{:.2}", item.cpu_usage()),
+            ProcessColumn::Mem => format_bytes(item.memory_usage(), unit_system),
+            ProcessColumn::MemPercent => format!("{:.1}", item.memory_percent()),
+            ProcessColumn::Virt => format_bytes(item.virtual_memory(), unit_system),
+            ProcessColumn::Status => item.status().to_string(),
+            ProcessColumn::Runtime => item.run_time_dd_hh_mm_ss(),
+            ProcessColumn::StartTime => format_start_time(item.start_time()),
+            ProcessColumn::CpuTime => format_cpu_time(item.accumulated_cpu_time()),
+            ProcessColumn::ReadRate => format_data_rate(item.read_bytes_per_s() as f64, unit_system, DataUnit::Bytes),
+            ProcessColumn::WriteRate => format_data_rate(item.written_bytes_per_s() as f64, unit_system, DataUnit::Bytes),
+            ProcessColumn::Threads => item.threads().to_string(),
+            ProcessColumn::Nice => item.nice().to_string(),
+            ProcessColumn::Cgroup => item.cgroup_name().to_string(),
+            ProcessColumn::MinorFaults => item.minor_faults().to_string(),
+            ProcessColumn::MajorFaults => item.major_faults().to_string(),
+        }
+    }
+
+    // cell of a group row in the grouped process list, fields that are not aggregated are left empty
+    pub fn group_cell(&self, group: &ProcessGroupItem, expanded: bool, unit_system: UnitSystem) -> String {
+        match self {
+            ProcessColumn::Name => format!("{} {} ({})", if expanded { "▾" } else { "▸" }, group.key(), group.count()),
+            ProcessColumn::Cpu => format!("{:.2}", group.cpu_usage()),
+            ProcessColumn::Mem => format_bytes(group.memory_usage(), unit_system),
+            ProcessColumn::MemPercent => format!("{:.1}", group.memory_percent()),
+            ProcessColumn::Virt => format_bytes(group.virtual_memory(), unit_system),
+            ProcessColumn::Threads => group.threads().to_string(),
+            _ => String::new(),
+        }
+    }
+
+    // (increasing, decreasing) sort orders of the column
+    pub fn sort_orders(&self) -> (ProcessItemSortOrder, ProcessItemSortOrder) {
+        match self {
+            ProcessColumn::Pid => (ProcessItemSortOrder::PidInc, ProcessItemSortOrder::PidDec),
+            ProcessColumn::Ppid => (ProcessItemSortOrder::PpidInc, ProcessItemSortOrder::PpidDec),
+            ProcessColumn::User => (ProcessItemSortOrder::UserInc, ProcessItemSortOrder::UserDec),
+            ProcessColumn::Name => (ProcessItemSortOrder::NameInc, ProcessItemSortOrder::NameDec),
+            ProcessColumn::Cmd => (ProcessItemSortOrder::CmdInc, ProcessItemSortOrder::CmdDec),
+            ProcessColumn::Cpu => (ProcessItemSortOrder::CpuUsageInc, ProcessItemSortOrder::CpuUsageDec),
+            ProcessColumn::Mem => (ProcessItemSortOrder::MemoryUsageInc, ProcessItemSortOrder::MemoryUsageDec),
+            ProcessColumn::MemPercent => (ProcessItemSortOrder::MemoryPercentInc, ProcessItemSortOrder::MemoryPercentDec),
+            ProcessColumn::Virt => (ProcessItemSortOrder::VirtualMemoryInc, ProcessItemSortOrder::VirtualMemoryDec),
+            ProcessColumn::Status => (ProcessItemSortOrder::StatusInc, ProcessItemSortOrder::StatusDec),
+            ProcessColumn::Runtime => (ProcessItemSortOrder::RuntimeInc, ProcessItemSortOrder::RuntimeDec),
+            ProcessColumn::StartTime => (ProcessItemSortOrder::StartTimeInc, ProcessItemSortOrder::StartTimeDec),
+            ProcessColumn::CpuTime => (ProcessItemSortOrder::CpuTimeInc, ProcessItemSortOrder::CpuTimeDec),
+            ProcessColumn::ReadRate => (ProcessItemSortOrder::ReadRateInc, ProcessItemSortOrder::ReadRateDec),
+            ProcessColumn::WriteRate => (ProcessItemSortOrder::WriteRateInc, ProcessItemSortOrder::WriteRateDec),
+            ProcessColumn::Threads => (ProcessItemSortOrder::ThreadsInc, ProcessItemSortOrder::ThreadsDec),
+            ProcessColumn::Nice => (ProcessItemSortOrder::NiceInc, ProcessItemSortOrder::NiceDec),
+            ProcessColumn::Cgroup => (ProcessItemSortOrder::CgroupInc, ProcessItemSortOrder::CgroupDec),
+            ProcessColumn::MinorFaults => (ProcessItemSortOrder::MinorFaultsInc, ProcessItemSortOrder::MinorFaultsDec),
+            ProcessColumn::MajorFaults => (ProcessItemSortOrder::MajorFaultsInc, ProcessItemSortOrder::MajorFaultsDec),
+        }
+    }
+
+    // relative width, see ProcessColumnConfig
+    pub fn default_width(&self) -> u16 {
+        match self {
+            ProcessColumn::Pid => 10,
+            ProcessColumn::User => 10,
+            ProcessColumn::Name => 25,
+            ProcessColumn::Cmd => 40,
+            ProcessColumn::Cpu => 12,
+            ProcessColumn::Mem => 13,
+            ProcessColumn::Status => 13,
+            ProcessColumn::Runtime => 17,
+            ProcessColumn::StartTime => 12,
+            ProcessColumn::Cgroup => 20,
+            ProcessColumn::MinorFaults => 10,
+            _ => 8,
+        }
+    }
+
+    pub fn default_visible(&self) -> bool {
+        matches!(
+            self,
+            ProcessColumn::Pid |
+            ProcessColumn::User |
+            ProcessColumn::Name |
+            ProcessColumn::Cpu |
+            ProcessColumn::Mem |
+            ProcessColumn::Status |
+            ProcessColumn::Runtime
+        )
+    }
+}
+
+// a column's entry in the process list layout. `width` is a relative weight
+// distributed over the table width, i.e., Constraint::Fill(width)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ProcessColumnConfig {
+    pub column: ProcessColumn,
+    pub visible: bool,
+    pub width: u16,
+}
+
+impl ProcessColumnConfig {
+    pub fn new(column: ProcessColumn) -> Self {
+        Self {
+            column,
+            visible: column.default_visible(),
+            width: column.default_width(),
+        }
+    }
+}
+
+pub fn default_process_columns() -> Vec<ProcessColumnConfig> {
+    ProcessColumn::ALL
+        .iter()
+        .map(|column| ProcessColumnConfig::new(*column))
+        .collect()
+}
+
+// makes a (possibly hand edited) column layout usable: drops duplicates,
+// clamps widths and appends columns missing from the layout as hidden
+pub fn normalize_process_columns(columns: Vec<ProcessColumnConfig>) -> Vec<ProcessColumnConfig> {
+    let mut res: Vec<ProcessColumnConfig> = Vec::new();
+
+    for mut config in columns {
+        if res.iter().any(|c| c.column == config.column) {
+            continue;
+        }
+        config.width = config.width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        res.push(config);
+    }
+
+    for column in ProcessColumn::ALL {
+        if !res.iter().any(|c| c.column == column) {
+            let mut config = ProcessColumnConfig::new(column);
+            config.visible = false;
+            res.push(config);
+        }
+    }
+
+    res
+}
+
+// formats a process start time (seconds since epoch) as local "MM-DD HH:MM"
+#[cfg(unix)]
+fn format_start_time(start_time: u64) -> String {
+    let time = start_time as libc::time_t;
+    // SAFETY: localtime_r only writes to the provided tm struct
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let res = unsafe { libc::localtime_r(&time, &mut tm) };
+    if res.is_null() {
+        return start_time.to_string();
+    }
+
+    format!("{:0>2}-{:0>2} {:0>2}:{:0>2}", tm.tm_mon + 1, tm.tm_mday, tm.tm_hour, tm.tm_min)
+}
+
+#[cfg(not(unix))]
+fn format_start_time(start_time: u64) -> String {
+    start_time.to_string()
+}
+
+// formats accumulated cpu time (ms) as "HH:MM:SS"
+fn format_cpu_time(cpu_time: u64) -> String {
+    let time_in_s = cpu_time / 1000;
+
+    let ss = time_in_s % 60;
+    let mm = (time_in_s / 60) % 60;
+    let hh = time_in_s / 3600;
+
+    format!("{:0>2}:{:0>2}:{:0>2}", hh, mm, ss)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_process_columns() {
+        let columns = vec![
+            ProcessColumnConfig { column: ProcessColumn::Name, visible: true, width: 500 },
+            ProcessColumnConfig { column: ProcessColumn::Pid, visible: true, width: 0 },
+            ProcessColumnConfig { column: ProcessColumn::Name, visible: false, width: 10 },
+        ];
+        let columns = normalize_process_columns(columns);
+
+        // every column is present exactly once, user ordering first
+        assert_eq!(columns.len(), ProcessColumn::ALL.len());
+        assert_eq!(columns[0].column, ProcessColumn::Name);
+        assert!(columns[0].visible);
+        assert_eq!(columns[0].width, MAX_COLUMN_WIDTH);
+        assert_eq!(columns[1].column, ProcessColumn::Pid);
+        assert_eq!(columns[1].width, MIN_COLUMN_WIDTH);
+        // missing columns are appended hidden
+        assert!(columns[2..].iter().all(|c| !c.visible));
+    }
+
+    #[test]
+    fn test_format_cpu_time() {
+        assert_eq!(format_cpu_time(0), "00:00:00");
+        assert_eq!(format_cpu_time(3_723_000), "01:02:03");
+        assert_eq!(format_cpu_time(360_000_000), "100:00:00");
+    }
+}
@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+
+// prefixes of the unit systems, up to peta/pebi
+static SI_PREFIXES: [&str; 6] = ["", "k", "M", "G", "T", "P"];
+static IEC_PREFIXES: [&str; 6] = ["", "Ki", "Mi", "Gi", "Ti", "Pi"];
+
+// SI (powers of 1000, e.g. MB) or IEC (powers of 1024, e.g. MiB) prefixes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitSystem {
+    Si,
+    #[default]
+    Iec,
+}
+
+impl UnitSystem {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Si => Self::Iec,
+            Self::Iec => Self::Si,
+        }
+    }
+
+    pub fn base(self) -> f64 {
+        match self {
+            Self::Si => 1000_f64,
+            Self::Iec => 1024_f64,
+        }
+    }
+
+    fn prefixes(self) -> &'static [&'static str; 6] {
+        match self {
+            Self::Si => &SI_PREFIXES,
+            Self::Iec => &IEC_PREFIXES,
+        }
+    }
+}
+
+// network rates in bytes (e.g. MiB/s) or bits (e.g. Mbit/s)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataUnit {
+    #[default]
+    Bytes,
+    Bits,
+}
+
+impl DataUnit {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Bytes => Self::Bits,
+            Self::Bits => Self::Bytes,
+        }
+    }
+
+    // converts bytes to the unit
+    pub fn from_bytes(self, bytes: f64) -> f64 {
+        match self {
+            Self::Bytes => bytes,
+            Self::Bits => bytes * 8_f64,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Bytes => "B",
+            Self::Bits => "bit",
+        }
+    }
+}
+
+// counter delta normalized by the time it was measured over
+pub fn per_second(delta: u64, elapsed_s: f64) -> f64 {
+    if elapsed_s > 0_f64 {
+        delta as f64 / elapsed_s
+    }
+    else {
+        0_f64
+    }
+}
+
+// index of the largest prefix keeping value >= 1, e.g. 1536 -> 1 (Ki)
+fn prefix_index(value: f64, system: UnitSystem) -> usize {
+    let mut idx = 0;
+    let mut value = value.abs();
+
+    while value >= system.base() && idx < system.prefixes().len() - 1 {
+        value /= system.base();
+        idx += 1;
+    }
+
+    idx
+}
+
+// whole numbers without decimals, everything else with one
+fn format_number(value: f64) -> String {
+    if value.fract() == 0_f64 || value >= 100_f64 {
+        format!("{:.0}", value)
+    }
+    else {
+        format!("{:.1}", value)
+    }
+}
+
+// value with the prefix fitting its magnitude, e.g. (1536, Iec, "B") -> "1.5 KiB"
+pub fn format_value(value: f64, system: UnitSystem, unit: &str) -> String {
+    let idx = prefix_index(value, system);
+    let scaled = value / system.base().powi(idx as i32);
+
+    format!("{} {}{}", format_number(scaled), system.prefixes()[idx], unit)
+}
+
+pub fn format_bytes(bytes: u64, system: UnitSystem) -> String {
+    format_value(bytes as f64, system, "B")
+}
+
+pub fn format_data_rate(bytes_per_s: f64, system: UnitSystem, data_unit: DataUnit) -> String {
+    format_value(data_unit.from_bytes(bytes_per_s), system, &format!("{}/s", data_unit.symbol()))
+}
+
+// y-axis of a chart: the upper bound and the labels of the evenly spaced ticks from 0 to it
+pub struct AxisScale {
+    pub upper: f64,
+    pub labels: Vec<String>,
+}
+
+// rounds up to 1, 2 or 5 times a power of ten
+fn nice_step(value: f64) -> f64 {
+    let magnitude = 10_f64.powf(value.log10().floor());
+
+    match value / magnitude {
+        fraction if fraction <= 1_f64 => magnitude,
+        fraction if fraction <= 2_f64 => 2_f64 * magnitude,
+        fraction if fraction <= 5_f64 => 5_f64 * magnitude,
+        _ => 10_f64 * magnitude,
+    }
+}
+
+// scales the axis to fit max on round ticks, all labels share the prefix of the upper bound,
+// e.g. max = 3.4 MiB -> 0, 2, 4 MiB
+pub fn nice_axis(max: f64, system: UnitSystem, unit: &str, tick_count: usize) -> AxisScale {
+    let intervals = tick_count.max(2) - 1;
+    let idx = prefix_index(max, system);
+    let divisor = system.base().powi(idx as i32);
+
+    let step = if max > 0_f64 {
+        nice_step(max / divisor / intervals as f64)
+    }
+    else {
+        1_f64
+    };
+
+    let labels = (0..=intervals)
+        .map(|tick| {
+            let value = format_number(step * tick as f64);
+            if tick == intervals {
+                format!("{} {}{}", value, system.prefixes()[idx], unit)
+            }
+            else {
+                value
+            }
+        })
+        .collect();
+
+    AxisScale {
+        upper: step * intervals as f64 * divisor,
+        labels,
+    }
+}
+
+// axis with a fixed upper bound, e.g. 0 to 100%
+pub fn fixed_axis(upper: f64, unit: &str, tick_count: usize) -> AxisScale {
+    let intervals = tick_count.max(2) - 1;
+
+    let labels = (0..=intervals)
+        .map(|tick| {
+            let value = format_number(upper / intervals as f64 * tick as f64);
+            if tick == intervals {
+                format!("{} {}", value, unit)
+            }
+            else {
+                value
+            }
+        })
+        .collect();
+
+    AxisScale {
+        upper,
+        labels,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format_bytes(512, UnitSystem::Iec), "512 B");
+        assert_eq!(format_bytes(1536, UnitSystem::Iec), "1.5 KiB");
+        assert_eq!(format_bytes(1_500_000_000, UnitSystem::Si), "1.5 GB");
+        assert_eq!(format_data_rate(125_000_f64, UnitSystem::Si, DataUnit::Bits), "1 Mbit/s");
+        assert_eq!(per_second(3000, 2_f64), 1500_f64);
+        assert_eq!(per_second(3000, 0_f64), 0_f64);
+    }
+
+    #[test]
+    fn test_nice_axis() {
+        let axis = nice_axis(3.4 * 1024_f64 * 1024_f64, UnitSystem::Iec, "B/s", 3);
+        assert_eq!(axis.upper, 4_f64 * 1024_f64 * 1024_f64);
+        assert_eq!(axis.labels, vec!["0", "2", "4 MiB/s"]);
+
+        let axis = nice_axis(730_f64, UnitSystem::Si, "pkt/s", 3);
+        assert_eq!(axis.upper, 1000_f64);
+        assert_eq!(axis.labels, vec!["0", "500", "1000 pkt/s"]);
+
+        // idle interfaces still get an axis
+        let axis = nice_axis(0_f64, UnitSystem::Si, "pkt/s", 3);
+        assert_eq!(axis.upper, 2_f64);
+
+        let axis = fixed_axis(105_f64, "°C", 3);
+        assert_eq!(axis.upper, 105_f64);
+        assert_eq!(axis.labels, vec!["0", "52.5", "105 °C"]);
+    }
+}
@@ -1,4 +1,17 @@
 pub mod sysinfo_service;
+pub mod procfs;
+#[cfg(unix)]
+pub mod statvfs;
+
+// statvfs is not available on other platforms, the disks panel shows N/A for inodes
+#[cfg(not(unix))]
+pub mod statvfs {
+    use std::path::Path;
+
+    pub fn read_inodes(_path: &Path) -> Option<(u64, u64)> {
+        None
+    }
+}
 
 // trait VecProvider<T> details:
 //
@@ -13,4 +26,14 @@ pub trait VecProvider<T> {
 
 pub trait ItemProvider<T> {
     fn fetch_item(&self) -> T;
-}
\ No newline at end of file
+}
+
+// trait PidVecProvider<T> details:
+//
+// PidVecProvider<T> serves items belonging to a single process (e.g., its open
+// files). Unlike VecProvider<T>, fetching can fail, e.g., with permission denied
+// when inspecting processes of other users.
+//
+pub trait PidVecProvider<T> {
+    fn fetch_pid_items(&self, pid: u32) -> std::io::Result<Vec<T>>;
+}
@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::services::procfs::read_file;
+use crate::services::procfs::pressure::{parse_pressure, Pressure};
+
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+// systemd unit types that own a cgroup, see `man 5 systemd.unit`
+const UNIT_SUFFIXES: [&str; 6] = [".service", ".scope", ".slice", ".socket", ".mount", ".swap"];
+
+// counters of a unit's cgroup, see https://docs.kernel.org/admin-guide/cgroup-v2.html.
+// fields are None if the controller is not enabled for the cgroup
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CgroupStat {
+    pub path: String,                       // relative to the cgroup root, e.g. /system.slice/sshd.service
+    pub usage_usec: Option<u64>,            // cpu.stat
+    pub memory_current: Option<u64>,        // memory.current, in bytes
+    pub pids_current: Option<u64>,          // pids.current
+    pub io_read_bytes: Option<u64>,         // io.stat, summed over devices
+    pub io_write_bytes: Option<u64>,
+    pub memory_max: Option<u64>,            // memory.max, None if unlimited
+    pub memory_high: Option<u64>,           // memory.high, None if unlimited
+    pub cpu_max: Option<(u64, u64)>,        // cpu.max (quota, period) in usec, None if unlimited
+    pub nr_periods: Option<u64>,            // cpu.stat, enforcement periods elapsed
+    pub nr_throttled: Option<u64>,          // cpu.stat, periods the cgroup was throttled in
+    pub throttled_usec: Option<u64>,        // cpu.stat, time the cgroup was throttled for
+    pub oom: Option<u64>,                   // memory.events, times the cgroup ran out of memory
+    pub oom_kill: Option<u64>,              // memory.events, processes killed by the oom killer
+    pub cpu_pressure: Option<Pressure>,     // cpu.pressure, None without psi
+    pub memory_pressure: Option<Pressure>,  // memory.pressure
+    pub io_pressure: Option<Pressure>,      // io.pressure
+}
+
+// per second rates between two samples of the same cgroup
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CgroupRates {
+    pub cpu_usage: Option<f32>,             // percent of a single cpu, like process cpu usage
+    pub read_bytes_per_s: Option<u64>,
+    pub written_bytes_per_s: Option<u64>,
+    pub throttled_percent: Option<f32>,     // percent of enforcement periods the cgroup was throttled in
+    pub throttled_ms_per_s: Option<f32>,    // time throttled, in ms per second
+    pub ooms: Option<u64>,                  // oom events since the previous sample
+    pub oom_kills: Option<u64>,             // oom kills since the previous sample
+}
+
+impl CgroupStat {
+    pub fn rates_since(&self, prev: &CgroupStat, elapsed_s: f64) -> CgroupRates {
+        if elapsed_s <= 0_f64 {
+            return CgroupRates::default();
+        }
+
+        // counters are reset when a unit restarts, the sample is skipped when they decrease
+        let rate = |curr: Option<u64>, prev: Option<u64>| {
+            curr.zip(prev)
+                .and_then(|(curr, prev)| curr.checked_sub(prev))
+                .map(|delta| delta as f64 / elapsed_s)
+        };
+
+        let delta = |curr: Option<u64>, prev: Option<u64>| {
+            curr.zip(prev).and_then(|(curr, prev)| curr.checked_sub(prev))
+        };
+        let throttled_percent = match (delta(self.nr_throttled, prev.nr_throttled), delta(self.nr_periods, prev.nr_periods)) {
+            (Some(throttled), Some(periods)) if periods > 0 => Some(throttled as f32 / periods as f32 * 100_f32),
+            (Some(_), Some(_)) => Some(0_f32),
+            _ => None,
+        };
+
+        CgroupRates {
+            cpu_usage: rate(self.usage_usec, prev.usage_usec).map(|usec| (usec / 1_000_000_f64 * 100_f64) as f32),
+            read_bytes_per_s: rate(self.io_read_bytes, prev.io_read_bytes).map(|bytes| bytes as u64),
+            written_bytes_per_s: rate(self.io_write_bytes, prev.io_write_bytes).map(|bytes| bytes as u64),
+            throttled_percent,
+            throttled_ms_per_s: rate(self.throttled_usec, prev.throttled_usec).map(|usec| (usec / 1000_f64) as f32),
+            ooms: delta(self.oom, prev.oom),
+            oom_kills: delta(self.oom_kill, prev.oom_kill),
+        }
+    }
+}
+
+pub fn is_unit_name(name: &str) -> bool {
+    UNIT_SUFFIXES.iter().any(|suffix| name.len() > suffix.len() && name.ends_with(suffix))
+}
+
+// the cgroup v2 hierarchy is mounted at the root on unified hosts and under unified/ on hybrid hosts
+pub fn cgroup_root() -> Option<PathBuf> {
+    [PathBuf::from(CGROUP_ROOT), Path::new(CGROUP_ROOT).join("unified")]
+        .into_iter()
+        .find(|root| root.join("cgroup.controllers").exists())
+}
+
+// returns the value of `key` in a flat keyed file, e.g. cpu.stat
+pub fn parse_keyed_value(contents: &str, key: &str) -> Option<u64> {
+    contents.lines().find_map(|line| {
+        let (k, v) = line.split_once(' ')?;
+        if k == key { v.trim().parse().ok() } else { None }
+    })
+}
+
+// parses single value files, e.g. memory.current. "max" (no limit) is None
+pub fn parse_single_value(contents: &str) -> Option<u64> {
+    contents.trim().parse().ok()
+}
+
+// parses cpu.max, "<quota> <period>" with a quota of "max" when unlimited
+pub fn parse_cpu_max(contents: &str) -> Option<(u64, u64)> {
+    let (quota, period) = contents.trim().split_once(' ')?;
+    Some((quota.parse().ok()?, period.parse().ok()?))
+}
+
+// parses io.stat, returns (read bytes, written bytes) summed over all devices
+pub fn parse_io_stat(contents: &str) -> (u64, u64) {
+    let mut read_bytes = 0;
+    let mut written_bytes = 0;
+
+    for field in contents.split_whitespace() {
+        if let Some(value) = field.strip_prefix("rbytes=") {
+            read_bytes += value.parse::<u64>().unwrap_or(0);
+        }
+        else if let Some(value) = field.strip_prefix("wbytes=") {
+            written_bytes += value.parse::<u64>().unwrap_or(0);
+        }
+    }
+
+    (read_bytes, written_bytes)
+}
+
+pub fn read_cgroup_stat(root: &Path, path: &str) -> CgroupStat {
+    let dir = root.join(path.trim_start_matches('/'));
+    let io = read_file(dir.join("io.stat")).map(|contents| parse_io_stat(&contents));
+    let cpu_stat = read_file(dir.join("cpu.stat")).unwrap_or_default();
+    let memory_events = read_file(dir.join("memory.events"));
+
+    CgroupStat {
+        path: path.to_string(),
+        usage_usec: parse_keyed_value(&cpu_stat, "usage_usec"),
+        memory_current: read_file(dir.join("memory.current")).and_then(|contents| parse_single_value(&contents)),
+        pids_current: read_file(dir.join("pids.current")).and_then(|contents| parse_single_value(&contents)),
+        io_read_bytes: io.map(|(read_bytes, _)| read_bytes),
+        io_write_bytes: io.map(|(_, written_bytes)| written_bytes),
+        memory_max: read_file(dir.join("memory.max")).and_then(|contents| parse_single_value(&contents)),
+        memory_high: read_file(dir.join("memory.high")).and_then(|contents| parse_single_value(&contents)),
+        cpu_max: read_file(dir.join("cpu.max")).and_then(|contents| parse_cpu_max(&contents)),
+        nr_periods: parse_keyed_value(&cpu_stat, "nr_periods"),
+        nr_throttled: parse_keyed_value(&cpu_stat, "nr_throttled"),
+        throttled_usec: parse_keyed_value(&cpu_stat, "throttled_usec"),
+        oom: memory_events.as_deref().and_then(|contents| parse_keyed_value(contents, "oom")),
+        oom_kill: memory_events.as_deref().and_then(|contents| parse_keyed_value(contents, "oom_kill")),
+        cpu_pressure: read_file(dir.join("cpu.pressure")).and_then(|contents| parse_pressure(&contents)),
+        memory_pressure: read_file(dir.join("memory.pressure")).and_then(|contents| parse_pressure(&contents)),
+        io_pressure: read_file(dir.join("io.pressure")).and_then(|contents| parse_pressure(&contents)),
+    }
+}
+
+// walks the cgroup tree below root and reads the stats of every systemd unit. only slices and
+// units are descended into, cgroups a unit creates for itself (e.g. container internals) are skipped
+pub fn read_unit_cgroups(root: &Path) -> Vec<CgroupStat> {
+    let mut stats: Vec<CgroupStat> = Vec::new();
+    let mut pending: Vec<String> = vec![String::new()];
+
+    while let Some(path) = pending.pop() {
+        let Ok(entries) = fs::read_dir(root.join(path.trim_start_matches('/')))
+        else {
+            continue;
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().map(|file_type| file_type.is_dir()).unwrap_or(false))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| is_unit_name(name))
+            .collect();
+        names.sort();
+
+        for name in names {
+            let unit_path = format!("{}/{}", path, name);
+            stats.push(read_cgroup_stat(root, &unit_path));
+            pending.push(unit_path);
+        }
+    }
+
+    stats.sort_by(|a, b| a.path.cmp(&b.path));
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture_root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/sys/fs/cgroup")
+    }
+
+    #[test]
+    fn test_parsers() {
+        assert_eq!(parse_keyed_value("usage_usec 1500\nuser_usec 1000\n", "user_usec"), Some(1000));
+        assert_eq!(parse_keyed_value("usage_usec 1500\n", "nr_throttled"), None);
+        // keys are matched whole, oom does not match oom_kill
+        assert_eq!(parse_keyed_value("oom_kill 2\noom 3\n", "oom"), Some(3));
+        assert_eq!(parse_single_value("8388608\n"), Some(8388608));
+        assert_eq!(parse_single_value("max\n"), None);
+        assert_eq!(parse_cpu_max("50000 100000\n"), Some((50000, 100000)));
+        assert_eq!(parse_cpu_max("max 100000\n"), None);
+        assert_eq!(parse_io_stat("8:0 rbytes=10 wbytes=1 rios=1\n259:0 rbytes=5 wbytes=0\n"), (15, 1));
+        assert!(is_unit_name("sshd.service"));
+        assert!(!is_unit_name(".service"));
+        assert!(!is_unit_name("not-a-unit"));
+    }
+
+    #[test]
+    fn test_read_unit_cgroups() {
+        let stats = read_unit_cgroups(&fixture_root());
+        let paths: Vec<&str> = stats.iter().map(|stat| stat.path.as_str()).collect();
+
+        assert_eq!(paths, vec![
+            "/system.slice",
+            "/system.slice/docker-4f1c2a9b8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a3928170f6e5d4c.scope",
+            "/system.slice/sshd.service",
+            "/user.slice",
+            "/user.slice/user-1000.slice",
+            "/user.slice/user-1000.slice/session-2.scope",
+        ]);
+
+        let sshd = &stats[2];
+        assert_eq!(sshd.usage_usec, Some(1500000));
+        assert_eq!(sshd.memory_current, Some(8388608));
+        assert_eq!(sshd.pids_current, Some(3));
+        assert_eq!(sshd.io_read_bytes, Some(3145728));
+        assert_eq!(sshd.io_write_bytes, Some(4096));
+        assert_eq!(sshd.memory_max, None);
+        assert_eq!(sshd.cpu_max, None);
+        assert_eq!(sshd.memory_pressure.unwrap().some.avg10, 22.5);
+        assert_eq!(sshd.io_pressure.unwrap().full.unwrap().avg10, 0.3);
+
+        // limited container
+        let container = &stats[1];
+        assert_eq!(container.memory_max, Some(536870912));
+        assert_eq!(container.memory_high, Some(402653184));
+        assert_eq!(container.cpu_max, Some((50000, 100000)));
+        assert_eq!(container.nr_periods, Some(12000));
+        assert_eq!(container.nr_throttled, Some(3100));
+        assert_eq!(container.throttled_usec, Some(95000000));
+        assert_eq!(container.oom, Some(3));
+        assert_eq!(container.oom_kill, Some(2));
+
+        // io controller not enabled, pids without a value
+        let session = &stats[5];
+        assert_eq!(session.io_read_bytes, None);
+        assert_eq!(session.pids_current, None);
+        assert_eq!(session.cpu_pressure, None);
+    }
+
+    #[test]
+    fn test_rates_since() {
+        let prev = CgroupStat {
+            usage_usec: Some(1_000_000), io_read_bytes: Some(0), io_write_bytes: Some(4096),
+            nr_periods: Some(100), nr_throttled: Some(10), throttled_usec: Some(500_000), oom: Some(1), oom_kill: Some(1),
+            ..Default::default()
+        };
+        let curr = CgroupStat {
+            usage_usec: Some(2_000_000), io_read_bytes: Some(2048), io_write_bytes: Some(0),
+            nr_periods: Some(120), nr_throttled: Some(15), throttled_usec: Some(700_000), oom: Some(2), oom_kill: Some(1),
+            ..Default::default()
+        };
+
+        let rates = curr.rates_since(&prev, 2_f64);
+        assert_eq!(rates.cpu_usage, Some(50_f32));
+        assert_eq!(rates.read_bytes_per_s, Some(1024));
+        // counter reset
+        assert_eq!(rates.written_bytes_per_s, None);
+        assert_eq!(rates.throttled_percent, Some(25_f32));
+        assert_eq!(rates.throttled_ms_per_s, Some(100_f32));
+        assert_eq!(rates.ooms, Some(1));
+        assert_eq!(rates.oom_kills, Some(0));
+
+        assert_eq!(curr.rates_since(&prev, 0_f64), CgroupRates::default());
+    }
+}
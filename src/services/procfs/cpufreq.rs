@@ -0,0 +1,48 @@
+use std::path::Path;
+use crate::services::procfs::read_file;
+use crate::services::procfs::cgroup::parse_single_value;
+
+pub const SYS_CPU_ROOT: &str = "/sys/devices/system/cpu";
+
+// frequency scaling of a cpu from its cpufreq directory, see
+// https://docs.kernel.org/admin-guide/pm/cpufreq.html. None where the cpu has no cpufreq driver (e.g. most VMs)
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CpuFreq {
+    pub min_khz: u64,                       // scaling_min_freq, the lowest frequency the governor may pick
+    pub max_khz: u64,                       // scaling_max_freq, lowered by thermal and power limits
+    pub governor: String,                   // scaling_governor, e.g. powersave, performance, schedutil
+}
+
+// cpu is the name of the cpu's directory below root, e.g. cpu0
+pub fn read_cpufreq_in(root: &Path, cpu: &str) -> Option<CpuFreq> {
+    let dir = root.join(cpu).join("cpufreq");
+
+    Some(CpuFreq {
+        min_khz: parse_single_value(&read_file(dir.join("scaling_min_freq"))?)?,
+        max_khz: parse_single_value(&read_file(dir.join("scaling_max_freq"))?)?,
+        governor: read_file(dir.join("scaling_governor"))?.trim().to_string(),
+    })
+}
+
+pub fn read_cpufreq(cpu: &str) -> Option<CpuFreq> {
+    read_cpufreq_in(Path::new(SYS_CPU_ROOT), cpu)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_cpufreq() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/sys/devices/system/cpu");
+
+        let freq = read_cpufreq_in(&root, "cpu0").unwrap();
+        assert_eq!(freq.min_khz, 400000);
+        assert_eq!(freq.max_khz, 4800000);
+        assert_eq!(freq.governor, "powersave");
+
+        assert_eq!(read_cpufreq_in(&root, "cpu1").unwrap().governor, "performance");
+        // no cpufreq driver
+        assert_eq!(read_cpufreq_in(&root, "cpu2"), None);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+pub const SYS_BLOCK_ROOT: &str = "/sys/block";
+
+// diskstats counts in 512 byte sectors regardless of the device's sector size
+const SECTOR_SIZE: u64 = 512;
+
+// counters of a block device in /proc/diskstats, see https://docs.kernel.org/admin-guide/iostats.html
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct DiskStat {
+    pub device: String,
+    pub reads_completed: u64,
+    pub sectors_read: u64,
+    pub writes_completed: u64,
+    pub sectors_written: u64,
+    pub in_flight: u64,                     // requests currently issued to the device
+    pub io_time_ms: u64,                    // time the device had requests in flight
+}
+
+// per second rates between two samples of the same device
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct DiskIoRates {
+    pub read_bytes_per_s: f64,
+    pub written_bytes_per_s: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub utilization: f32,                   // percent of the time the device was busy, like iostat %util
+}
+
+impl DiskStat {
+    // None if a counter decreased, e.g. the device was detached and reattached under the same name
+    pub fn rates_since(&self, prev: &DiskStat, elapsed_s: f64) -> Option<DiskIoRates> {
+        if elapsed_s <= 0_f64 {
+            return None;
+        }
+
+        let rate = |curr: u64, prev: u64| curr.checked_sub(prev).map(|delta| delta as f64 / elapsed_s);
+
+        Some(DiskIoRates {
+            read_bytes_per_s: rate(self.sectors_read, prev.sectors_read)? * SECTOR_SIZE as f64,
+            written_bytes_per_s: rate(self.sectors_written, prev.sectors_written)? * SECTOR_SIZE as f64,
+            read_iops: rate(self.reads_completed, prev.reads_completed)?,
+            write_iops: rate(self.writes_completed, prev.writes_completed)?,
+            utilization: (rate(self.io_time_ms, prev.io_time_ms)? / 1000_f64 * 100_f64).min(100_f64) as f32,
+        })
+    }
+}
+
+pub fn parse_diskstats(contents: &str) -> Vec<DiskStat> {
+    contents
+        .lines()
+        .filter_map(parse_diskstats_line)
+        .collect()
+}
+
+fn parse_diskstats_line(line: &str) -> Option<DiskStat> {
+    // major minor device, then at least 11 counters (more on newer kernels)
+    let mut fields = line.split_whitespace().skip(2);
+    let device = fields.next()?.to_string();
+    let counters: Vec<u64> = fields
+        .map(|counter| counter.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    Some(DiskStat {
+        device,
+        reads_completed: *counters.first()?,
+        sectors_read: *counters.get(2)?,
+        writes_completed: *counters.get(4)?,
+        sectors_written: *counters.get(6)?,
+        in_flight: *counters.get(8)?,
+        io_time_ms: *counters.get(9)?,
+    })
+}
+
+pub fn read_diskstats() -> Vec<DiskStat> {
+    read_file(format!("{}/diskstats", PROC_ROOT))
+        .map(|contents| parse_diskstats(&contents))
+        .unwrap_or_default()
+}
+
+// names of the whole block devices (no partitions) listed in a /sys/block directory
+pub fn read_block_devices_in(root: &Path) -> HashSet<String> {
+    let Ok(entries) = fs::read_dir(root)
+    else {
+        return HashSet::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect()
+}
+
+pub fn read_block_devices() -> HashSet<String> {
+    read_block_devices_in(Path::new(SYS_BLOCK_ROOT))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_diskstats() {
+        let stats = parse_diskstats(include_str!("../../../resources/fixtures/proc/diskstats"));
+        assert_eq!(stats.len(), 6);
+
+        assert_eq!(stats[1].device, "nvme0n1");
+        assert_eq!(stats[1].reads_completed, 100000);
+        assert_eq!(stats[1].sectors_read, 4000000);
+        assert_eq!(stats[1].writes_completed, 50000);
+        assert_eq!(stats[1].sectors_written, 2000000);
+        assert_eq!(stats[1].in_flight, 3);
+        assert_eq!(stats[1].io_time_ms, 800000);
+
+        // older kernels without discard and flush counters
+        assert_eq!(parse_diskstats("8 0 sda 1 2 3 4 5 6 7 8 9 10 11").len(), 1);
+        assert!(parse_diskstats("8 0 sda 1 2 3").is_empty());
+    }
+
+    #[test]
+    fn test_rates_since() {
+        let prev = parse_diskstats(include_str!("../../../resources/fixtures/proc/diskstats"));
+        let curr = parse_diskstats(include_str!("../../../resources/fixtures/proc/diskstats.next"));
+
+        // samples 2s apart
+        let rates = curr[1].rates_since(&prev[1], 2_f64).unwrap();
+        assert_eq!(rates.read_bytes_per_s, 2_f64 * 1024_f64 * 1024_f64);
+        assert_eq!(rates.written_bytes_per_s, 512_f64 * 1024_f64);
+        assert_eq!(rates.read_iops, 200_f64);
+        assert_eq!(rates.write_iops, 50_f64);
+        assert_eq!(rates.utilization, 50_f32);
+
+        // idle device
+        let rates = curr[4].rates_since(&prev[4], 2_f64).unwrap();
+        assert_eq!(rates, DiskIoRates::default());
+
+        // counter reset
+        assert_eq!(prev[1].rates_since(&curr[1], 2_f64), None);
+    }
+
+    #[test]
+    fn test_read_block_devices() {
+        let devices = read_block_devices_in(&Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/sys/block"));
+
+        assert_eq!(devices.len(), 3);
+        assert!(devices.contains("nvme0n1"));
+        assert!(!devices.contains("nvme0n1p1"));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// where the memory in use goes according to /proc/meminfo, in bytes,
+// see https://docs.kernel.org/filesystems/proc.html#meminfo
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct MemInfo {
+    pub buffers: u64,                       // block device buffers
+    pub cached: u64,                        // page cache, includes shared memory
+    pub shared: u64,                        // Shmem, tmpfs and shared anonymous memory
+    pub sreclaimable: u64,                  // slab the kernel can reclaim under pressure
+    pub dirty: u64,                         // waiting to be written back to disk
+    pub writeback: u64,                     // actively being written back to disk
+}
+
+// None if one of the fields is missing, e.g. on kernels older than 2.6.19 without SReclaimable
+pub fn parse_meminfo(contents: &str) -> Option<MemInfo> {
+    // "<key>:   <value> kB"
+    let field = |key: &str| {
+        contents
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                if name != key {
+                    return None;
+                }
+                let kib: u64 = value.split_whitespace().next()?.parse().ok()?;
+                Some(kib * 1024)
+            })
+    };
+
+    Some(MemInfo {
+        buffers: field("Buffers")?,
+        cached: field("Cached")?,
+        shared: field("Shmem")?,
+        sreclaimable: field("SReclaimable")?,
+        dirty: field("Dirty")?,
+        writeback: field("Writeback")?,
+    })
+}
+
+pub fn read_meminfo() -> Option<MemInfo> {
+    read_file(format!("{}/meminfo", PROC_ROOT)).and_then(|contents| parse_meminfo(&contents))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = parse_meminfo(include_str!("../../../resources/fixtures/proc/meminfo")).unwrap();
+
+        assert_eq!(meminfo.buffers, 524288 * 1024);
+        assert_eq!(meminfo.cached, 7340032 * 1024);
+        // SwapCached is not mistaken for Cached
+        assert_ne!(meminfo.cached, 12288 * 1024);
+        assert_eq!(meminfo.shared, 786432 * 1024);
+        assert_eq!(meminfo.sreclaimable, 655360 * 1024);
+        assert_eq!(meminfo.dirty, 2048 * 1024);
+        assert_eq!(meminfo.writeback, 256 * 1024);
+
+        assert_eq!(parse_meminfo("Buffers: 4 kB\nCached: 8 kB"), None);
+    }
+}
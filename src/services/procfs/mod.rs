@@ -0,0 +1,31 @@
+use std::fs;
+use std::path::Path;
+
+pub mod pid_stat;
+pub mod pid_cgroup;
+pub mod cgroup;
+pub mod net;
+pub mod pid_fd;
+pub mod net_dev;
+pub mod diskstats;
+pub mod stat;
+pub mod cpufreq;
+pub mod topology;
+pub mod meminfo;
+pub mod pressure;
+pub mod vmstat;
+
+// module procfs details:
+//
+// procfs contains parsers for the Linux /proc and /sys pseudo filesystems
+// covering data the sysinfo crate does not expose. Each parser is split into
+// a `parse_*` function operating on file contents (unit-tested against the
+// fixtures in resources/fixtures/) and a `read_*` function that reads the
+// file from the live system. Readers return None/empty on platforms or
+// systems where the files are not present.
+//
+pub const PROC_ROOT: &str = "/proc";
+
+pub fn read_file<P: AsRef<Path>>(path: P) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
@@ -0,0 +1,244 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NetProtocol {
+    Tcp,
+    Tcp6,
+    Udp,
+    Udp6,
+}
+
+impl NetProtocol {
+    pub const ALL: [NetProtocol; 4] = [NetProtocol::Tcp, NetProtocol::Tcp6, NetProtocol::Udp, NetProtocol::Udp6];
+
+    // file name in /proc/net
+    pub fn name(&self) -> &'static str {
+        match self {
+            NetProtocol::Tcp => "tcp",
+            NetProtocol::Tcp6 => "tcp6",
+            NetProtocol::Udp => "udp",
+            NetProtocol::Udp6 => "udp6",
+        }
+    }
+
+    pub fn is_tcp(&self) -> bool {
+        matches!(self, NetProtocol::Tcp | NetProtocol::Tcp6)
+    }
+}
+
+// a row of /proc/net/{tcp,tcp6,udp,udp6}, see `man 5 proc`
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetSocket {
+    pub protocol: NetProtocol,
+    pub local: SocketAddr,
+    pub remote: SocketAddr,
+    pub state: u8,
+    pub uid: u32,
+    pub inode: u64,
+}
+
+impl NetSocket {
+    // state names as printed by `ss`, see include/net/tcp_states.h
+    pub fn state_name(&self) -> &'static str {
+        match (self.protocol.is_tcp(), self.state) {
+            (_, 0x01) => "ESTAB",
+            (true, 0x02) => "SYN-SENT",
+            (true, 0x03) => "SYN-RECV",
+            (true, 0x04) => "FIN-WAIT-1",
+            (true, 0x05) => "FIN-WAIT-2",
+            (true, 0x06) => "TIME-WAIT",
+            (true, 0x07) => "CLOSE",
+            (false, 0x07) => "UNCONN",
+            (true, 0x08) => "CLOSE-WAIT",
+            (true, 0x09) => "LAST-ACK",
+            (true, 0x0A) => "LISTEN",
+            (true, 0x0B) => "CLOSING",
+            (true, 0x0C) => "NEW-SYN-RECV",
+            _ => "UNKNOWN",
+        }
+    }
+}
+
+pub fn parse_net_sockets(contents: &str, protocol: NetProtocol) -> Vec<NetSocket> {
+    contents
+        .lines()
+        .skip(1)                        // header
+        .filter_map(|line| parse_net_socket(line, protocol))
+        .collect()
+}
+
+fn parse_net_socket(line: &str, protocol: NetProtocol) -> Option<NetSocket> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    Some(NetSocket {
+        protocol,
+        local: parse_address(fields.get(1)?)?,
+        remote: parse_address(fields.get(2)?)?,
+        state: u8::from_str_radix(fields.get(3)?, 16).ok()?,
+        uid: fields.get(7)?.parse().ok()?,
+        inode: fields.get(9)?.parse().ok()?,
+    })
+}
+
+// addresses are hex encoded in network order, but printed as host order 32 bit words
+// (little endian on common hosts), ports are hex in host order
+fn parse_address(field: &str) -> Option<SocketAddr> {
+    let (address, port) = field.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+
+    let ip = match address.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(u32::from_str_radix(address, 16).ok()?.to_le_bytes())),
+        32 => {
+            let mut octets = [0_u8; 16];
+            for (idx, word) in octets.chunks_mut(4).enumerate() {
+                let value = u32::from_str_radix(&address[idx * 8..idx * 8 + 8], 16).ok()?;
+                word.copy_from_slice(&value.to_le_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None,
+    };
+
+    Some(SocketAddr::new(ip, port))
+}
+
+// reads all inet sockets of the network namespace of this process
+pub fn read_net_sockets() -> Vec<NetSocket> {
+    NetProtocol::ALL
+        .iter()
+        .filter_map(|protocol| {
+            read_file(format!("{}/net/{}", PROC_ROOT, protocol.name()))
+                .map(|contents| parse_net_sockets(&contents, *protocol))
+        })
+        .flatten()
+        .collect()
+}
+
+// a row of /proc/net/unix
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnixSocket {
+    pub kind: u16,                  // SOCK_STREAM, SOCK_DGRAM or SOCK_SEQPACKET
+    pub state: u8,
+    pub listening: bool,            // __SO_ACCEPTCON flag
+    pub inode: u64,
+    pub path: Option<String>,       // None if unnamed, abstract names start with @
+}
+
+impl UnixSocket {
+    // netid as printed by `ss`
+    pub fn netid(&self) -> &'static str {
+        match self.kind {
+            0x0001 => "u_str",
+            0x0002 => "u_dgr",
+            0x0005 => "u_seq",
+            _ => "unix",
+        }
+    }
+
+    // state names as printed by `ss`, see include/uapi/linux/net.h
+    pub fn state_name(&self) -> &'static str {
+        if self.listening {
+            return "LISTEN";
+        }
+
+        match self.state {
+            0x01 => "UNCONN",
+            0x02 => "SYN-SENT",
+            0x03 => "ESTAB",
+            0x04 => "CLOSING",
+            _ => "UNKNOWN",
+        }
+    }
+}
+
+const SO_ACCEPTCON: u32 = 0x0001_0000;
+
+pub fn parse_unix_sockets(contents: &str) -> Vec<UnixSocket> {
+    contents
+        .lines()
+        .skip(1)                        // header
+        .filter_map(parse_unix_socket)
+        .collect()
+}
+
+fn parse_unix_socket(line: &str) -> Option<UnixSocket> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let path = fields.get(7..).filter(|path| !path.is_empty()).map(|path| path.join(" "));
+
+    Some(UnixSocket {
+        kind: u16::from_str_radix(fields.get(4)?, 16).ok()?,
+        state: u8::from_str_radix(fields.get(5)?, 16).ok()?,
+        listening: u32::from_str_radix(fields.get(3)?, 16).ok()? & SO_ACCEPTCON != 0,
+        inode: fields.get(6)?.parse().ok()?,
+        path,
+    })
+}
+
+pub fn read_unix_sockets() -> Vec<UnixSocket> {
+    read_file(format!("{}/net/unix", PROC_ROOT))
+        .map(|contents| parse_unix_sockets(&contents))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tcp() {
+        let sockets = parse_net_sockets(include_str!("../../../resources/fixtures/proc/net/tcp"), NetProtocol::Tcp);
+        assert_eq!(sockets.len(), 4);
+
+        assert_eq!(sockets[0].local, "0.0.0.0:22".parse().unwrap());
+        assert_eq!(sockets[0].state_name(), "LISTEN");
+        assert_eq!(sockets[0].inode, 18342);
+
+        assert_eq!(sockets[2].local, "10.0.2.15:22".parse().unwrap());
+        assert_eq!(sockets[2].remote, "10.0.2.2:54178".parse().unwrap());
+        assert_eq!(sockets[2].state_name(), "ESTAB");
+
+        assert_eq!(sockets[3].remote, "192.168.11.45:443".parse().unwrap());
+        assert_eq!(sockets[3].state_name(), "CLOSE-WAIT");
+        assert_eq!(sockets[3].uid, 1000);
+    }
+
+    #[test]
+    fn test_parse_tcp6_udp() {
+        let tcp6 = parse_net_sockets(include_str!("../../../resources/fixtures/proc/net/tcp6"), NetProtocol::Tcp6);
+        assert_eq!(tcp6[0].local, "[::]:22".parse().unwrap());
+        assert_eq!(tcp6[1].local, "[::1]:8080".parse().unwrap());
+        assert_eq!(tcp6[1].inode, 52114);
+
+        let udp = parse_net_sockets(include_str!("../../../resources/fixtures/proc/net/udp"), NetProtocol::Udp);
+        assert_eq!(udp[0].local, "127.0.0.53:53".parse().unwrap());
+        assert_eq!(udp[0].state_name(), "UNCONN");
+
+        let udp6 = parse_net_sockets(include_str!("../../../resources/fixtures/proc/net/udp6"), NetProtocol::Udp6);
+        assert_eq!(udp6[0].local, "[fe80::5054:ff:fe1d:adb6]:546".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_unix() {
+        let sockets = parse_unix_sockets(include_str!("../../../resources/fixtures/proc/net/unix"));
+        assert_eq!(sockets.len(), 4);
+
+        assert_eq!(sockets[0].path.as_deref(), Some("/run/systemd/private"));
+        assert_eq!(sockets[0].netid(), "u_str");
+        assert_eq!(sockets[0].state_name(), "LISTEN");
+        assert_eq!(sockets[0].inode, 17823);
+
+        assert_eq!(sockets[1].state_name(), "ESTAB");
+        assert_eq!(sockets[2].netid(), "u_dgr");
+        assert_eq!(sockets[2].state_name(), "UNCONN");
+        assert_eq!(sockets[2].path.as_deref(), Some("@/org/freedesktop/systemd1/notify"));
+        assert_eq!(sockets[3].path, None);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert!(parse_net_sockets("header\n   0: 0100007F:ZZZZ 00000000:0000 0A", NetProtocol::Tcp).is_empty());
+        assert!(parse_net_sockets("", NetProtocol::Tcp).is_empty());
+        assert!(parse_unix_sockets("header\n0000000000000000: 00000002 00000000").is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// counters of a network interface in /proc/net/dev, see `man 5 proc`
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct NetDevStat {
+    pub interface: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+}
+
+pub fn parse_net_dev(contents: &str) -> Vec<NetDevStat> {
+    contents
+        .lines()
+        .skip(2)                        // headers
+        .filter_map(parse_net_dev_line)
+        .collect()
+}
+
+fn parse_net_dev_line(line: &str) -> Option<NetDevStat> {
+    // long counters can run into the interface name, e.g. "eth0:1843275514"
+    let (interface, counters) = line.split_once(':')?;
+    let counters: Vec<u64> = counters
+        .split_whitespace()
+        .map(|counter| counter.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    Some(NetDevStat {
+        interface: interface.trim().to_string(),
+        rx_bytes: *counters.first()?,
+        rx_packets: *counters.get(1)?,
+        rx_errors: *counters.get(2)?,
+        rx_drops: *counters.get(3)?,
+        tx_bytes: *counters.get(8)?,
+        tx_packets: *counters.get(9)?,
+        tx_errors: *counters.get(10)?,
+        tx_drops: *counters.get(11)?,
+    })
+}
+
+pub fn read_net_dev() -> Vec<NetDevStat> {
+    read_file(format!("{}/net/dev", PROC_ROOT))
+        .map(|contents| parse_net_dev(&contents))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_net_dev() {
+        let stats = parse_net_dev(include_str!("../../../resources/fixtures/proc/net/dev"));
+        assert_eq!(stats.len(), 3);
+
+        assert_eq!(stats[0].interface, "lo");
+        assert_eq!(stats[1].interface, "eth0");
+        assert_eq!(stats[1].rx_bytes, 1843275514);
+        assert_eq!(stats[1].rx_errors, 12);
+        assert_eq!(stats[1].rx_drops, 318);
+        assert_eq!(stats[1].tx_packets, 612044);
+        assert_eq!(stats[1].tx_drops, 3);
+        assert_eq!(stats[2].interface, "docker0");
+
+        assert!(parse_net_dev("header\nheader\n  eth0: 1 2 3").is_empty());
+    }
+}
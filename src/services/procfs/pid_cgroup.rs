@@ -0,0 +1,145 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+use crate::services::procfs::cgroup::is_unit_name;
+
+// length container ids are shortened to, matching `docker ps`
+const SHORT_ID_LEN: usize = 12;
+
+// returns the cgroup path of a process from the contents of /proc/<pid>/cgroup, see `man 7 cgroups`.
+// the cgroup v2 unified hierarchy ("0::<path>") is preferred, on v1 only hosts the systemd
+// named hierarchy is used since it follows the same unit layout
+pub fn parse_pid_cgroup(contents: &str) -> Option<String> {
+    let mut systemd_path: Option<&str> = None;
+
+    for line in contents.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+
+        if id == "0" && controllers.is_empty() {
+            return Some(path.to_string());
+        }
+        if controllers == "name=systemd" {
+            systemd_path = Some(path);
+        }
+    }
+
+    systemd_path.map(|path| path.to_string())
+}
+
+pub fn read_pid_cgroup(pid: u32) -> Option<String> {
+    let contents = read_file(format!("{}/{}/cgroup", PROC_ROOT, pid))?;
+    parse_pid_cgroup(&contents)
+}
+
+/* decodes a cgroup path to a short name:
+    - docker containers:    docker:<short id>
+    - podman containers:    podman:<short id>
+    - k8s containers:       k8s:<short pod uid>/<short id>, k8s pods: k8s:<short pod uid>
+    - systemd units:        the unit name, e.g. sshd.service
+   paths that do not end in any of the above are returned as is
+*/
+pub fn cgroup_name(path: &str) -> String {
+    let components: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+    let Some(last) = components.last()
+    else {
+        return String::from("/");
+    };
+
+    // kubernetes: kubepods[-<qos>]-pod<uid>.slice (systemd driver) or kubepods/<qos>/pod<uid> (cgroupfs driver)
+    let pod = components
+        .iter()
+        .filter(|c| c.starts_with("kubepods"))
+        .chain(components.iter().filter(|c| c.starts_with("pod")))
+        .find_map(|c| pod_uid(c));
+    if let Some(pod) = pod {
+        let container = container_id(last, &["cri-containerd-", "crio-", "docker-"])
+            .or_else(|| if is_container_id(last) { Some(last) } else { None });
+        return match container {
+            Some(id) => format!("k8s:{}/{}", pod, short_id(id)),
+            None => format!("k8s:{}", pod),
+        };
+    }
+
+    if let Some(id) = container_id(last, &["docker-"]) {
+        return format!("docker:{}", short_id(id));
+    }
+    if let Some(id) = container_id(last, &["libpod-conmon-", "libpod-"]) {
+        return format!("podman:{}", short_id(id));
+    }
+    // cgroupfs driver: /docker/<id>
+    if components.len() >= 2 && components[components.len() - 2] == "docker" && is_container_id(last) {
+        return format!("docker:{}", short_id(last));
+    }
+
+    if is_unit_name(last) {
+        return last.to_string();
+    }
+
+    path.to_string()
+}
+
+// returns the id of <prefix><id>.scope
+fn container_id<'a>(component: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    let id = component.strip_suffix(".scope").unwrap_or(component);
+    prefixes
+        .iter()
+        .find_map(|prefix| id.strip_prefix(prefix))
+        .filter(|id| is_container_id(id))
+}
+
+fn pod_uid(component: &str) -> Option<String> {
+    let component = component.strip_suffix(".slice").unwrap_or(component);
+    let (_, uid) = component.rsplit_once("pod")?;
+    if uid.is_empty() || !uid.chars().all(|c| c.is_ascii_hexdigit() || c == '_' || c == '-') {
+        return None;
+    }
+    // the systemd driver escapes the '-' of pod uids as '_'
+    Some(uid.chars().filter(|c| c.is_ascii_hexdigit()).take(8).collect())
+}
+
+fn is_container_id(id: &str) -> bool {
+    id.len() >= SHORT_ID_LEN && id.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn short_id(id: &str) -> &str {
+    &id[..SHORT_ID_LEN.min(id.len())]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pid_cgroup() {
+        let v2 = include_str!("../../../resources/fixtures/proc/1234/cgroup");
+        let v1 = include_str!("../../../resources/fixtures/proc/2345/cgroup");
+
+        assert_eq!(
+            parse_pid_cgroup(v2).as_deref(),
+            Some("/system.slice/docker-4f1c2a9b8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a3928170f6e5d4c.scope")
+        );
+        assert_eq!(parse_pid_cgroup(v1).as_deref(), Some("/user.slice/user-1000.slice/session-2.scope"));
+        assert!(parse_pid_cgroup("").is_none());
+    }
+
+    #[test]
+    fn test_cgroup_name() {
+        let id = "4f1c2a9b8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a3928170f6e5d4c";
+
+        assert_eq!(cgroup_name("/"), "/");
+        assert_eq!(cgroup_name("/system.slice/sshd.service"), "sshd.service");
+        assert_eq!(cgroup_name("/user.slice/user-1000.slice/session-2.scope"), "session-2.scope");
+        assert_eq!(cgroup_name(&format!("/system.slice/docker-{}.scope", id)), "docker:4f1c2a9b8e7d");
+        assert_eq!(cgroup_name(&format!("/docker/{}", id)), "docker:4f1c2a9b8e7d");
+        assert_eq!(cgroup_name(&format!("/machine.slice/libpod-{}.scope", id)), "podman:4f1c2a9b8e7d");
+        assert_eq!(
+            cgroup_name(&format!("/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod5d2e1c3b_8a7f_4e6d_9c0b_1a2b3c4d5e6f.slice/cri-containerd-{}.scope", id)),
+            "k8s:5d2e1c3b/4f1c2a9b8e7d"
+        );
+        assert_eq!(cgroup_name(&format!("/kubepods/besteffort/pod5d2e1c3b-8a7f-4e6d-9c0b-1a2b3c4d5e6f/{}", id)), "k8s:5d2e1c3b/4f1c2a9b8e7d");
+        assert_eq!(cgroup_name("/kubepods.slice/kubepods-pod5d2e1c3b_8a7f_4e6d_9c0b_1a2b3c4d5e6f.slice"), "k8s:5d2e1c3b");
+        assert_eq!(cgroup_name("/custom/group"), "/custom/group");
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::services::procfs::PROC_ROOT;
+
+// what a file descriptor refers to, classified by its /proc/<pid>/fd link target
+#[derive(Clone, Debug, PartialEq)]
+pub enum FdTarget {
+    File(String),
+    Device(String),
+    Socket(u64),                // inode, resolved through /proc/net
+    Pipe(u64),
+    AnonInode(String),          // e.g. eventfd, inotify, [eventpoll]
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PidFd {
+    pub fd: u32,
+    pub target: Option<FdTarget>,   // None if the link could not be read, e.g. the fd was closed
+}
+
+pub fn parse_fd_target(link: &str) -> FdTarget {
+    let inode = |target: &str| target.trim_start_matches('[').trim_end_matches(']').parse::<u64>().ok();
+
+    if let Some(target) = link.strip_prefix("socket:") {
+        if let Some(inode) = inode(target) {
+            return FdTarget::Socket(inode);
+        }
+    }
+    if let Some(target) = link.strip_prefix("pipe:") {
+        if let Some(inode) = inode(target) {
+            return FdTarget::Pipe(inode);
+        }
+    }
+    if let Some(target) = link.strip_prefix("anon_inode:") {
+        return FdTarget::AnonInode(target.trim_start_matches('[').trim_end_matches(']').to_string());
+    }
+    if link.starts_with("/dev/") {
+        return FdTarget::Device(link.to_string());
+    }
+
+    FdTarget::File(link.to_string())
+}
+
+// reads the file descriptors in a /proc/<pid>/fd directory. listing the directory fails with
+// io::ErrorKind::PermissionDenied for processes of other users unless running as root
+pub fn read_fds(fd_dir: &Path) -> io::Result<Vec<PidFd>> {
+    let mut fds: Vec<PidFd> = fs::read_dir(fd_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let fd = entry.file_name().to_str()?.parse::<u32>().ok()?;
+            let target = fs::read_link(entry.path())
+                .ok()
+                .map(|link| parse_fd_target(&link.to_string_lossy()));
+
+            Some(PidFd { fd, target })
+        })
+        .collect();
+
+    fds.sort_by_key(|fd| fd.fd);
+    Ok(fds)
+}
+
+pub fn read_pid_fds(pid: u32) -> io::Result<Vec<PidFd>> {
+    read_fds(&Path::new(PROC_ROOT).join(pid.to_string()).join("fd"))
+}
+
+// maps socket inodes to the pid of the process owning them by scanning the fds of all processes
+// under proc_root. sockets shared by several processes (e.g. inherited on fork) map to the lowest
+// pid. the fds of processes of other users are only readable as root
+pub fn read_socket_owners_in(proc_root: &Path) -> HashMap<u64, u32> {
+    let Ok(entries) = fs::read_dir(proc_root)
+    else {
+        return HashMap::new();
+    };
+
+    let mut pids: Vec<u32> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .collect();
+    pids.sort();
+
+    let mut owners = HashMap::new();
+    for pid in pids {
+        let Ok(fds) = read_fds(&proc_root.join(pid.to_string()).join("fd"))
+        else {
+            continue;
+        };
+
+        for fd in fds {
+            if let Some(FdTarget::Socket(inode)) = fd.target {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+
+    owners
+}
+
+pub fn read_socket_owners() -> HashMap<u64, u32> {
+    read_socket_owners_in(Path::new(PROC_ROOT))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_fd_target() {
+        assert_eq!(parse_fd_target("socket:[40771]"), FdTarget::Socket(40771));
+        assert_eq!(parse_fd_target("pipe:[13607]"), FdTarget::Pipe(13607));
+        assert_eq!(parse_fd_target("anon_inode:[eventfd]"), FdTarget::AnonInode(String::from("eventfd")));
+        assert_eq!(parse_fd_target("anon_inode:inotify"), FdTarget::AnonInode(String::from("inotify")));
+        assert_eq!(parse_fd_target("/dev/pts/0"), FdTarget::Device(String::from("/dev/pts/0")));
+        assert_eq!(parse_fd_target("/var/log/syslog (deleted)"), FdTarget::File(String::from("/var/log/syslog (deleted)")));
+    }
+
+    #[test]
+    fn test_read_fds() {
+        let fd_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/proc/1234/fd");
+        let fds = read_fds(&fd_dir).unwrap();
+
+        assert_eq!(fds.iter().map(|fd| fd.fd).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(fds[0].target, Some(FdTarget::Device(String::from("/dev/null"))));
+        assert_eq!(fds[2].target, Some(FdTarget::File(String::from("/var/log/gunicorn/error.log"))));
+        assert_eq!(fds[3].target, Some(FdTarget::Socket(40771)));
+
+        assert_eq!(read_fds(&fd_dir.join("missing")).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_read_socket_owners() {
+        let proc_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/proc");
+        let owners = read_socket_owners_in(&proc_root);
+
+        assert_eq!(owners.len(), 3);
+        // shared with 2345, the lowest pid owns it
+        assert_eq!(owners.get(&40771), Some(&1234));
+        assert_eq!(owners.get(&99999), Some(&1234));
+        assert_eq!(owners.get(&18342), Some(&2345));
+
+        assert!(read_socket_owners_in(&proc_root.join("missing")).is_empty());
+    }
+}
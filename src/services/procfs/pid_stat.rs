@@ -0,0 +1,55 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// subset of the fields in /proc/<pid>/stat, see `man 5 proc`
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct PidStat {
+    pub ppid: u32,
+    pub minflt: u64,                        // minor page faults, served without disk i/o
+    pub majflt: u64,                        // major page faults, needed disk i/o
+    pub nice: i32,
+    pub num_threads: u32,
+}
+
+pub fn parse_pid_stat(contents: &str) -> Option<PidStat> {
+    // the command name is wrapped in parentheses and may contain spaces, so
+    // the remaining fields are split after the last ')'
+    let (_, fields) = contents.rsplit_once(')')?;
+    let fields: Vec<&str> = fields.split_whitespace().collect();
+
+    // field indices are relative to the process state (field 3 in `man 5 proc`)
+    Some(PidStat {
+        ppid: fields.get(1)?.parse().ok()?,
+        minflt: fields.get(7)?.parse().ok()?,
+        majflt: fields.get(9)?.parse().ok()?,
+        nice: fields.get(16)?.parse().ok()?,
+        num_threads: fields.get(17)?.parse().ok()?,
+    })
+}
+
+pub fn read_pid_stat(pid: u32) -> Option<PidStat> {
+    let contents = read_file(format!("{}/{}/stat", PROC_ROOT, pid))?;
+    parse_pid_stat(&contents)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pid_stat() {
+        let contents = include_str!("../../../resources/fixtures/proc/1234/stat");
+        let stat = parse_pid_stat(contents).unwrap();
+
+        assert_eq!(stat.ppid, 1200);
+        assert_eq!(stat.minflt, 52718);
+        assert_eq!(stat.majflt, 12);
+        assert_eq!(stat.nice, 5);
+        assert_eq!(stat.num_threads, 4);
+    }
+
+    #[test]
+    fn test_parse_pid_stat_malformed() {
+        assert!(parse_pid_stat("").is_none());
+        assert!(parse_pid_stat("1234 (truncated) S 1").is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// resources with pressure stall information, the files in /proc/pressure and the
+// <resource>.pressure files of a cgroup
+pub const PRESSURE_RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+// percent of wall time tasks were stalled on a resource, averaged over 10s, 60s and 300s
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct StallLine {
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+    pub total_usec: u64,                    // total stall time
+}
+
+// pressure stall information of a resource, see https://docs.kernel.org/accounting/psi.html.
+// "some" is the time at least one task was stalled, "full" the time all non-idle tasks were
+// stalled at once. kernels before 5.13 have no full line for cpu
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Pressure {
+    pub some: StallLine,
+    pub full: Option<StallLine>,
+}
+
+pub fn parse_pressure(contents: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+
+    for line in contents.lines() {
+        match parse_stall_line(line) {
+            Some(("some", stall)) => some = Some(stall),
+            Some(("full", stall)) => full = Some(stall),
+            _ => {}
+        }
+    }
+
+    Some(Pressure {
+        some: some?,
+        full,
+    })
+}
+
+// "<some|full> avg10=<percent> avg60=<percent> avg300=<percent> total=<usec>"
+fn parse_stall_line(line: &str) -> Option<(&str, StallLine)> {
+    let mut fields = line.split_whitespace();
+    let kind = fields.next()?;
+    let mut stall = StallLine::default();
+
+    for field in fields {
+        let (key, value) = field.split_once('=')?;
+        match key {
+            "avg10" => stall.avg10 = value.parse().ok()?,
+            "avg60" => stall.avg60 = value.parse().ok()?,
+            "avg300" => stall.avg300 = value.parse().ok()?,
+            "total" => stall.total_usec = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    Some((kind, stall))
+}
+
+// pressure of a resource in a /proc/pressure directory, None if the kernel is built without
+// CONFIG_PSI or booted with psi=0
+pub fn read_pressure_in(root: &Path, resource: &str) -> Option<Pressure> {
+    read_file(root.join(resource)).and_then(|contents| parse_pressure(&contents))
+}
+
+pub fn read_pressure(resource: &str) -> Option<Pressure> {
+    read_pressure_in(&Path::new(PROC_ROOT).join("pressure"), resource)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_pressure() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/proc/pressure");
+
+        let memory = read_pressure_in(&root, "memory").unwrap();
+        assert_eq!(memory.some.avg10, 12.4);
+        assert_eq!(memory.some.avg60, 6.05);
+        assert_eq!(memory.some.avg300, 2.11);
+        assert_eq!(memory.some.total_usec, 98765432);
+        assert_eq!(memory.full.unwrap().avg10, 8.75);
+
+        assert!(PRESSURE_RESOURCES.iter().all(|resource| read_pressure_in(&root, resource).is_some()));
+        assert_eq!(read_pressure_in(&root, "irq"), None);
+    }
+
+    #[test]
+    fn test_parse_pressure() {
+        // cpu on kernels before 5.13
+        let cpu = parse_pressure("some avg10=0.50 avg60=0.20 avg300=0.10 total=200\n").unwrap();
+        assert_eq!(cpu.some.avg10, 0.5);
+        assert_eq!(cpu.full, None);
+
+        assert_eq!(parse_pressure("full avg10=0.50 avg60=0.20 avg300=0.10 total=200\n"), None);
+        assert_eq!(parse_pressure("some avg10=x avg60=0.20 avg300=0.10 total=200\n"), None);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// time a cpu spent in each state since boot, in USER_HZ (usually 1/100 s), see `man 5 proc`.
+// guest time is also counted in user and guest_nice in nice
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CpuTimes {
+    pub name: String,                       // "cpu" for the sum over all cpus, "cpu0", "cpu1", ..
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+    pub guest: u64,
+    pub guest_nice: u64,
+}
+
+// percent of time spent in each state between two samples of the same cpu
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct CpuTimesPercent {
+    pub user: f32,                          // without guest time
+    pub nice: f32,                          // without guest_nice time
+    pub system: f32,
+    pub idle: f32,
+    pub iowait: f32,
+    pub irq: f32,
+    pub softirq: f32,
+    pub steal: f32,
+    pub guest: f32,                         // guest and guest_nice
+}
+
+impl CpuTimes {
+    // None if a counter decreased (e.g. a cpu was hotplugged) or no time passed
+    pub fn percent_since(&self, prev: &CpuTimes) -> Option<CpuTimesPercent> {
+        let user = self.user.checked_sub(prev.user)?;
+        let nice = self.nice.checked_sub(prev.nice)?;
+        let system = self.system.checked_sub(prev.system)?;
+        let idle = self.idle.checked_sub(prev.idle)?;
+        let iowait = self.iowait.checked_sub(prev.iowait)?;
+        let irq = self.irq.checked_sub(prev.irq)?;
+        let softirq = self.softirq.checked_sub(prev.softirq)?;
+        let steal = self.steal.checked_sub(prev.steal)?;
+        let guest = self.guest.checked_sub(prev.guest)?;
+        let guest_nice = self.guest_nice.checked_sub(prev.guest_nice)?;
+
+        let total = user + nice + system + idle + iowait + irq + softirq + steal;
+        if total == 0 {
+            return None;
+        }
+        let percent = |time: u64| time as f32 / total as f32 * 100_f32;
+
+        Some(CpuTimesPercent {
+            user: percent(user.saturating_sub(guest)),
+            nice: percent(nice.saturating_sub(guest_nice)),
+            system: percent(system),
+            idle: percent(idle),
+            iowait: percent(iowait),
+            irq: percent(irq),
+            softirq: percent(softirq),
+            steal: percent(steal),
+            guest: percent(guest + guest_nice),
+        })
+    }
+}
+
+// /proc/stat, see `man 5 proc`
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ProcStat {
+    pub cpus: Vec<CpuTimes>,                // the sum over all cpus first
+    pub context_switches: u64,              // since boot
+    pub interrupts: u64,                    // since boot, of all kinds
+}
+
+pub fn parse_stat(contents: &str) -> ProcStat {
+    let cpus = contents
+        .lines()
+        .filter(|line| line.starts_with("cpu"))
+        .filter_map(parse_cpu_line)
+        .collect();
+
+    // first number after the key, e.g. "intr 1284731 0 9 .." is the total followed by the count per interrupt
+    let counter = |key: &str| {
+        contents
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.split_whitespace().next()?.parse().ok())
+            .unwrap_or(0)
+    };
+
+    ProcStat {
+        cpus,
+        context_switches: counter("ctxt "),
+        interrupts: counter("intr "),
+    }
+}
+
+fn parse_cpu_line(line: &str) -> Option<CpuTimes> {
+    let mut fields = line.split_whitespace();
+    let name = fields.next()?.to_string();
+    // older kernels have fewer columns, missing ones are 0
+    let times: Vec<u64> = fields
+        .map(|time| time.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    if times.len() < 4 {
+        return None;
+    }
+    let time = |idx: usize| times.get(idx).copied().unwrap_or(0);
+
+    Some(CpuTimes {
+        name,
+        user: time(0),
+        nice: time(1),
+        system: time(2),
+        idle: time(3),
+        iowait: time(4),
+        irq: time(5),
+        softirq: time(6),
+        steal: time(7),
+        guest: time(8),
+        guest_nice: time(9),
+    })
+}
+
+pub fn read_stat() -> Option<ProcStat> {
+    read_file(format!("{}/stat", PROC_ROOT)).map(|contents| parse_stat(&contents))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = parse_stat(include_str!("../../../resources/fixtures/proc/stat"));
+
+        assert_eq!(stat.cpus.len(), 3);
+        assert_eq!(stat.cpus[0].name, "cpu");
+        assert_eq!(stat.cpus[0].user, 10000);
+        assert_eq!(stat.cpus[0].iowait, 1500);
+        assert_eq!(stat.cpus[0].steal, 1000);
+        assert_eq!(stat.cpus[0].guest, 500);
+        assert_eq!(stat.cpus[2].name, "cpu1");
+        assert_eq!(stat.context_switches, 2419483);
+        assert_eq!(stat.interrupts, 1284731);
+
+        // kernels before 2.6.11 have no steal or guest columns
+        let stat = parse_stat("cpu 1 2 3 4 5 6 7");
+        assert_eq!(stat.cpus[0].softirq, 7);
+        assert_eq!(stat.cpus[0].steal, 0);
+    }
+
+    #[test]
+    fn test_percent_since() {
+        let prev = parse_stat(include_str!("../../../resources/fixtures/proc/stat"));
+        let curr = parse_stat(include_str!("../../../resources/fixtures/proc/stat.next"));
+
+        let percent = curr.cpus[0].percent_since(&prev.cpus[0]).unwrap();
+        assert_eq!(percent.user, 20_f32);
+        assert_eq!(percent.system, 10_f32);
+        assert_eq!(percent.idle, 40_f32);
+        assert_eq!(percent.iowait, 10_f32);
+        assert_eq!(percent.steal, 10_f32);
+        assert_eq!(percent.guest, 10_f32);
+
+        let percent = curr.cpus[1].percent_since(&prev.cpus[1]).unwrap();
+        assert_eq!(percent.user, 50_f32);
+        assert_eq!(percent.iowait, 12.5_f32);
+        assert_eq!(percent.steal, 0_f32);
+
+        // counters going backwards
+        assert_eq!(prev.cpus[0].percent_since(&curr.cpus[0]), None);
+        assert_eq!(prev.cpus[0].percent_since(&prev.cpus[0]), None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::path::Path;
+use crate::services::procfs::read_file;
+use crate::services::procfs::cgroup::parse_single_value;
+use crate::services::procfs::cpufreq::SYS_CPU_ROOT;
+
+pub const SYS_NODE_ROOT: &str = "/sys/devices/system/node";
+
+// placement of a logical cpu, see https://docs.kernel.org/admin-guide/cputopology.html
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CpuPlacement {
+    pub cpu: usize,
+    pub package: u32,                       // physical socket
+    pub core: u32,                          // unique within the package only
+    pub siblings: Vec<usize>,               // logical cpus on the same core (SMT threads), including cpu
+    pub node: Option<usize>,                // NUMA node, None without NUMA support
+}
+
+// a cache shared by a set of logical cpus
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Cache {
+    pub level: u8,
+    pub kind: String,                       // Data, Instruction or Unified
+    pub size_bytes: u64,
+    pub shared_cpus: Vec<usize>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Topology {
+    pub cpus: Vec<CpuPlacement>,            // by cpu number
+    pub caches: Vec<Cache>,                 // every cache once, by level
+}
+
+// parses a cpu list, e.g. "0-3,8-11"
+pub fn parse_cpu_list(contents: &str) -> Vec<usize> {
+    contents
+        .trim()
+        .split(',')
+        .filter(|range| !range.is_empty())
+        .filter_map(|range| match range.split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?..=end.parse().ok()?).collect::<Vec<usize>>()),
+            None => Some(vec![range.parse().ok()?]),
+        })
+        .flatten()
+        .collect()
+}
+
+// parses a cache size, e.g. "48K"
+pub fn parse_cache_size(contents: &str) -> Option<u64> {
+    let contents = contents.trim();
+
+    match contents.strip_suffix('K') {
+        Some(size) => size.parse::<u64>().ok().map(|size| size * 1024),
+        None => match contents.strip_suffix('M') {
+            Some(size) => size.parse::<u64>().ok().map(|size| size * 1024 * 1024),
+            None => contents.parse().ok(),
+        },
+    }
+}
+
+// numbers of the entries of a directory with the prefix followed by a number, e.g. cpu0, cpu1 for "cpu"
+fn numbered_entries(root: &Path, prefix: &str) -> Vec<usize> {
+    let Ok(entries) = fs::read_dir(root)
+    else {
+        return Vec::new();
+    };
+
+    let mut numbers: Vec<usize> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.strip_prefix(prefix)?.parse().ok())
+        .collect();
+    numbers.sort();
+    numbers
+}
+
+fn read_caches(dir: &Path) -> Vec<Cache> {
+    numbered_entries(dir, "index")
+        .into_iter()
+        .filter_map(|index| {
+            let dir = dir.join(format!("index{}", index));
+
+            Some(Cache {
+                level: parse_single_value(&read_file(dir.join("level"))?)? as u8,
+                kind: read_file(dir.join("type"))?.trim().to_string(),
+                size_bytes: parse_cache_size(&read_file(dir.join("size"))?)?,
+                shared_cpus: parse_cpu_list(&read_file(dir.join("shared_cpu_list"))?),
+            })
+        })
+        .collect()
+}
+
+// cpu_root is /sys/devices/system/cpu and node_root /sys/devices/system/node
+pub fn read_topology_in(cpu_root: &Path, node_root: &Path) -> Topology {
+    let nodes: Vec<(usize, Vec<usize>)> = numbered_entries(node_root, "node")
+        .into_iter()
+        .filter_map(|node| Some((node, parse_cpu_list(&read_file(node_root.join(format!("node{}", node)).join("cpulist"))?))))
+        .collect();
+
+    let mut topology = Topology::default();
+
+    for cpu in numbered_entries(cpu_root, "cpu") {
+        let dir = cpu_root.join(format!("cpu{}", cpu));
+        let topology_value = |name: &str| read_file(dir.join("topology").join(name)).and_then(|contents| parse_single_value(&contents));
+
+        // offline cpus have no topology
+        let (Some(package), Some(core)) = (topology_value("physical_package_id"), topology_value("core_id"))
+        else {
+            continue;
+        };
+
+        topology.cpus.push(CpuPlacement {
+            cpu,
+            package: package as u32,
+            core: core as u32,
+            siblings: read_file(dir.join("topology").join("thread_siblings_list"))
+                .map_or_else(|| vec![cpu], |contents| parse_cpu_list(&contents)),
+            node: nodes.iter().find(|(_, cpus)| cpus.contains(&cpu)).map(|(node, _)| *node),
+        });
+
+        // every cpu lists the caches it shares with others
+        for cache in read_caches(&dir.join("cache")) {
+            if !topology.caches.contains(&cache) {
+                topology.caches.push(cache);
+            }
+        }
+    }
+
+    topology.caches.sort_by_key(|cache| cache.level);
+    topology
+}
+
+pub fn read_topology() -> Topology {
+    read_topology_in(Path::new(SYS_CPU_ROOT), Path::new(SYS_NODE_ROOT))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_cpu_list("0-2,8,10-11\n"), vec![0, 1, 2, 8, 10, 11]);
+        assert!(parse_cpu_list("\n").is_empty());
+
+        assert_eq!(parse_cache_size("48K\n"), Some(48 * 1024));
+        assert_eq!(parse_cache_size("32M"), Some(32 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_read_topology() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/fixtures/sys/devices/system");
+        let topology = read_topology_in(&root.join("cpu"), &root.join("node"));
+
+        // 2 sockets with 2 cores of 2 threads each
+        assert_eq!(topology.cpus.len(), 8);
+        assert_eq!(topology.cpus[2], CpuPlacement { cpu: 2, package: 1, core: 0, siblings: vec![2, 6], node: Some(1) });
+        assert_eq!(topology.cpus[4].siblings, vec![0, 4]);
+
+        // L1d, L1i and L2 per core, L3 per socket
+        assert_eq!(topology.caches.len(), 14);
+        assert_eq!(topology.caches.iter().filter(|cache| cache.level == 3).count(), 2);
+        assert_eq!(topology.caches.last().unwrap().size_bytes, 24 * 1024 * 1024);
+    }
+}
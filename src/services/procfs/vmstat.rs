@@ -0,0 +1,93 @@
+use crate::services::procfs::{read_file, PROC_ROOT};
+
+// paging counters of /proc/vmstat since boot, see https://docs.kernel.org/admin-guide/mm/concepts.html
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct VmStat {
+    pub pswpin: u64,                        // pages swapped in
+    pub pswpout: u64,                       // pages swapped out
+    pub pgfault: u64,                       // page faults, minor and major
+    pub pgmajfault: u64,                    // page faults that needed disk i/o
+}
+
+// per second rates between two samples, swap in pages
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct VmStatRates {
+    pub swap_in_pages_per_s: f64,
+    pub swap_out_pages_per_s: f64,
+    pub faults_per_s: f64,
+    pub major_faults_per_s: f64,
+}
+
+impl VmStat {
+    // None if a counter decreased or no time passed
+    pub fn rates_since(&self, prev: &VmStat, elapsed_s: f64) -> Option<VmStatRates> {
+        if elapsed_s <= 0_f64 {
+            return None;
+        }
+
+        let rate = |curr: u64, prev: u64| curr.checked_sub(prev).map(|delta| delta as f64 / elapsed_s);
+
+        Some(VmStatRates {
+            swap_in_pages_per_s: rate(self.pswpin, prev.pswpin)?,
+            swap_out_pages_per_s: rate(self.pswpout, prev.pswpout)?,
+            faults_per_s: rate(self.pgfault, prev.pgfault)?,
+            major_faults_per_s: rate(self.pgmajfault, prev.pgmajfault)?,
+        })
+    }
+}
+
+// "<counter> <value>" lines, None if one of the counters is missing
+pub fn parse_vmstat(contents: &str) -> Option<VmStat> {
+    let counter = |key: &str| {
+        contents
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(' ')?;
+                if name == key { value.trim().parse().ok() } else { None }
+            })
+    };
+
+    Some(VmStat {
+        pswpin: counter("pswpin")?,
+        pswpout: counter("pswpout")?,
+        pgfault: counter("pgfault")?,
+        pgmajfault: counter("pgmajfault")?,
+    })
+}
+
+pub fn read_vmstat() -> Option<VmStat> {
+    read_file(format!("{}/vmstat", PROC_ROOT)).and_then(|contents| parse_vmstat(&contents))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vmstat() {
+        let vmstat = parse_vmstat(include_str!("../../../resources/fixtures/proc/vmstat")).unwrap();
+        assert_eq!(vmstat.pswpin, 12000);
+        assert_eq!(vmstat.pswpout, 30000);
+        assert_eq!(vmstat.pgfault, 456789000);
+        assert_eq!(vmstat.pgmajfault, 98000);
+
+        assert_eq!(parse_vmstat("pswpin 1\npswpout 2\n"), None);
+    }
+
+    #[test]
+    fn test_rates_since() {
+        let prev = parse_vmstat(include_str!("../../../resources/fixtures/proc/vmstat")).unwrap();
+        let curr = parse_vmstat(include_str!("../../../resources/fixtures/proc/vmstat.next")).unwrap();
+
+        // samples 2s apart
+        let rates = curr.rates_since(&prev, 2_f64).unwrap();
+        assert_eq!(rates.swap_in_pages_per_s, 100_f64);
+        assert_eq!(rates.swap_out_pages_per_s, 500_f64);
+        assert_eq!(rates.faults_per_s, 20000_f64);
+        assert_eq!(rates.major_faults_per_s, 25_f64);
+
+        // counter reset
+        assert_eq!(prev.rates_since(&curr, 2_f64), None);
+        assert_eq!(curr.rates_since(&prev, 0_f64), None);
+    }
+}
@@ -0,0 +1,31 @@
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+// (total, free) inodes of the filesystem mounted at path, see `man 3 statvfs`.
+// None when the call fails or the filesystem has no fixed inode count (e.g. btrfs, vfat)
+pub fn read_inodes(path: &Path) -> Option<(u64, u64)> {
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    let res = unsafe { libc::statvfs(path.as_ptr(), &mut stat) };
+
+    if res != 0 || stat.f_files == 0 {
+        return None;
+    }
+
+    Some((stat.f_files as u64, stat.f_ffree as u64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_inodes() {
+        assert_eq!(read_inodes(Path::new("/nonexistent/mount")), None);
+
+        if let Some((total, free)) = read_inodes(Path::new(env!("CARGO_MANIFEST_DIR"))) {
+            assert!(free <= total);
+        }
+    }
+}
@@ -1,8 +1,35 @@
-use sysinfo::{Components, Networks, Pid, System};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+use sysinfo::{Components, Disks, Networks, Pid, ProcessStatus, System, ThreadKind, Users};
 use crate::models::items::network_item::NetworkItem;
-use crate::models::items::{memory_item::MemoryItem, temp_item::TempItem, cpu_item::CpuItem, process_item::ProcessItem};
+use crate::models::items::{memory_item::{MemoryActivity, MemoryBreakdown, MemoryItem}, temp_item::TempItem, cpu_item::{CpuBreakdown, CpuItem, CpuScaling}, process_item::ProcessItem};
+use crate::models::items::unit_item::UnitItem;
+use crate::models::items::limit_item::LimitItem;
+use crate::models::items::file_item::{FileItem, FileKind};
+use crate::models::items::socket_item::SocketItem;
+use crate::models::items::connection_item::ConnectionItem;
+use crate::models::items::disk_item::DiskItem;
+use crate::models::items::disk_io_item::DiskIoItem;
+use crate::models::items::summary_item::{LoadAverage, SummaryItem, TaskCounts};
+use crate::models::items::pressure_item::{PressureItem, ResourcePressure, StallAverages};
+use crate::models::items::topology_item::{CacheItem, CpuPlacementItem, TopologyItem};
 use crate::config::Config;
-use crate::services::{ItemProvider, VecProvider};
+use crate::services::{ItemProvider, PidVecProvider, VecProvider};
+use crate::services::procfs::pid_stat::read_pid_stat;
+use crate::services::procfs::pid_cgroup::{cgroup_name, read_pid_cgroup};
+use crate::services::procfs::cgroup::{cgroup_root, read_unit_cgroups, CgroupStat};
+use crate::services::procfs::net::{read_net_sockets, read_unix_sockets, NetSocket, UnixSocket};
+use crate::services::procfs::pid_fd::{read_pid_fds, read_socket_owners, FdTarget};
+use crate::services::procfs::net_dev::{read_net_dev, NetDevStat};
+use crate::services::procfs::diskstats::{read_block_devices, read_diskstats, DiskStat};
+use crate::services::procfs::stat::{read_stat, CpuTimes, ProcStat};
+use crate::services::procfs::cpufreq::read_cpufreq;
+use crate::services::procfs::topology::read_topology;
+use crate::services::procfs::meminfo::read_meminfo;
+use crate::services::procfs::pressure::{read_pressure, Pressure};
+use crate::services::procfs::vmstat::{read_vmstat, VmStat};
+use crate::services::statvfs::read_inodes;
+use crate::models::units::per_second;
 
 // See here for refreshing system: https://crates.io/crates/sysinfo#:~:text=use%20sysinfo%3A%3ASystem,(sysinfo%3A%3AMINIMUM_CPU_UPDATE_INTERVAL)%3B%0A%7D
 // note: sysinfo::MINIMUM_CPU_UPDATE_INTERVAL = 200 ms
@@ -10,40 +37,397 @@ pub struct SysInfoService {
     system: System,
     components: Components,
     networks: Networks,
+    disks: Disks,
+    users: Users,
+    units: Vec<UnitItem>,
+    limits: Vec<LimitItem>,
+    unit_stats: HashMap<String, CgroupStat>,        // previous cgroup counters by path, to compute rates
+    unit_stats_time: Option<Instant>,
+    sockets: Vec<SocketItem>,
+    socket_owners: HashMap<u64, u32>,               // pid owning each socket inode, rebuilt only when sockets were opened
+    net_dev: HashMap<String, NetDevStat>,           // /proc/net/dev counters by interface
+    net_dev_prev: HashMap<String, NetDevStat>,      // counters of the previous refresh, to compute drops
+    system_time: Instant,
+    system_interval_s: f64,                         // time between the last two system refreshes, process disk usage is reported over it
+    networks_time: Instant,
+    networks_interval_s: f64,                       // time between the last two network refreshes
+    disk_stats: HashMap<String, DiskStat>,          // previous /proc/diskstats counters by device, to compute rates
+    disk_stats_time: Option<Instant>,
+    disk_io: Vec<DiskIoItem>,
+    cpu_times: HashMap<String, CpuTimes>,          // previous /proc/stat times by cpu ("cpu", "cpu0", ..)
+    cpu_breakdowns: HashMap<String, CpuBreakdown>,
+    stat: Option<ProcStat>,                         // previous /proc/stat counters, to compute rates
+    stat_time: Option<Instant>,
+    context_switches_per_s: f64,
+    interrupts_per_s: f64,
+    cpu_scaling: HashMap<String, CpuScaling>,      // cpufreq limits and governor by cpu ("cpu0", ..)
+    topology: TopologyItem,                         // read once, cpus are rarely hotplugged
+    vmstat: Option<VmStat>,                         // previous /proc/vmstat counters, to compute rates
+    vmstat_time: Option<Instant>,
+    memory_activity: MemoryActivity,
     pub _config: Config
 }
 
 impl SysInfoService {
     pub fn new(config: Config) -> Self  {
+        let system = System::new_all();
+        let topology = Self::read_topology(&system);
+
         Self {
-            system: System::new_all(),
+            system,
             components: Components::new_with_refreshed_list(),
             networks: Networks::new_with_refreshed_list(),
+            disks: Disks::new_with_refreshed_list(),
+            users: Users::new_with_refreshed_list(),
+            units: Vec::new(),
+            limits: Vec::new(),
+            unit_stats: HashMap::new(),
+            unit_stats_time: None,
+            sockets: Vec::new(),
+            socket_owners: HashMap::new(),
+            net_dev: HashMap::new(),
+            net_dev_prev: HashMap::new(),
+            system_time: Instant::now(),
+            system_interval_s: 0_f64,
+            networks_time: Instant::now(),
+            networks_interval_s: 0_f64,
+            disk_stats: HashMap::new(),
+            disk_stats_time: None,
+            disk_io: Vec::new(),
+            cpu_times: HashMap::new(),
+            cpu_breakdowns: HashMap::new(),
+            stat: None,
+            stat_time: None,
+            context_switches_per_s: 0_f64,
+            interrupts_per_s: 0_f64,
+            cpu_scaling: HashMap::new(),
+            topology,
+            vmstat: None,
+            vmstat_time: None,
+            memory_activity: MemoryActivity::default(),
             _config: config
         }
     }
 
     pub fn refresh_all(&mut self) {
+        let now = Instant::now();
         self.system.refresh_all();
+        self.system_interval_s = now.duration_since(self.system_time).as_secs_f64();
+        self.system_time = now;
+        self.refresh_stat();
+        self.refresh_vmstat();
+        self.refresh_cpu_scaling();
         self.components.refresh(false);
+        self.refresh_networks();
+        self.disks.refresh(true);
+        self.refresh_disk_io();
+        self.users.refresh();
+        self.refresh_units();
+        self.refresh_net_dev();
+        self.refresh_sockets();
+    }
+
+    // breaks the cpu time since the previous refresh down by state (user, system, iowait, steal, ..)
+    // and samples the context switch and interrupt rates
+    fn refresh_stat(&mut self) {
+        let Some(stat) = read_stat()
+        else {
+            return;
+        };
+        let now = Instant::now();
+
+        self.cpu_breakdowns = stat.cpus
+            .iter()
+            .filter_map(|times| {
+                let percent = times.percent_since(self.cpu_times.get(&times.name)?)?;
+
+                Some((times.name.clone(), CpuBreakdown {
+                    user: percent.user,
+                    nice: percent.nice,
+                    system: percent.system,
+                    idle: percent.idle,
+                    iowait: percent.iowait,
+                    irq: percent.irq,
+                    softirq: percent.softirq,
+                    steal: percent.steal,
+                    guest: percent.guest,
+                }))
+            })
+            .collect();
+
+        if let (Some(prev), Some(time)) = (&self.stat, self.stat_time) {
+            let elapsed_s = now.duration_since(time).as_secs_f64();
+            self.context_switches_per_s = per_second(stat.context_switches.saturating_sub(prev.context_switches), elapsed_s);
+            self.interrupts_per_s = per_second(stat.interrupts.saturating_sub(prev.interrupts), elapsed_s);
+        }
+
+        self.cpu_times = stat.cpus
+            .iter()
+            .map(|times| (times.name.clone(), times.clone()))
+            .collect();
+        self.stat = Some(stat);
+        self.stat_time = Some(now);
+    }
+
+    // samples the swap and page fault rates since the previous refresh
+    fn refresh_vmstat(&mut self) {
+        let Some(vmstat) = read_vmstat()
+        else {
+            return;
+        };
+
+        let now = Instant::now();
+        if let (Some(prev), Some(time)) = (&self.vmstat, self.vmstat_time) {
+            let page_size = page_size() as f64;
+            self.memory_activity = vmstat
+                .rates_since(prev, now.duration_since(time).as_secs_f64())
+                .map(|rates| MemoryActivity {
+                    swap_in_bytes_per_s: rates.swap_in_pages_per_s * page_size,
+                    swap_out_bytes_per_s: rates.swap_out_pages_per_s * page_size,
+                    faults_per_s: rates.faults_per_s,
+                    major_faults_per_s: rates.major_faults_per_s,
+                })
+                .unwrap_or_default();
+        }
+
+        self.vmstat = Some(vmstat);
+        self.vmstat_time = Some(now);
+    }
+
+    // maps the stall percentages of the system or a cgroup, the totals are not shown
+    fn pressure_item(cpu: Option<Pressure>, memory: Option<Pressure>, io: Option<Pressure>) -> PressureItem {
+        let resource = |pressure: Pressure| ResourcePressure {
+            some: StallAverages { avg10: pressure.some.avg10, avg60: pressure.some.avg60, avg300: pressure.some.avg300 },
+            full: pressure.full.map(|full| StallAverages { avg10: full.avg10, avg60: full.avg60, avg300: full.avg300 }),
+        };
+
+        PressureItem::new(cpu.map(resource), memory.map(resource), io.map(resource))
+    }
+
+    fn read_topology(system: &System) -> TopologyItem {
+        let topology = read_topology();
+        let (brand, vendor) = system.cpus()
+            .first()
+            .map(|cpu| (cpu.brand().to_string(), cpu.vendor_id().to_string()))
+            .unwrap_or_default();
+
+        let cpus = topology.cpus
+            .into_iter()
+            .map(|placement| CpuPlacementItem {
+                cpu: placement.cpu,
+                package: placement.package,
+                core: placement.core,
+                node: placement.node,
+                siblings: placement.siblings,
+            })
+            .collect();
+        let caches = topology.caches
+            .into_iter()
+            .map(|cache| CacheItem {
+                level: cache.level,
+                kind: cache.kind,
+                size: cache.size_bytes,
+                shared_cpus: cache.shared_cpus,
+            })
+            .collect();
+
+        TopologyItem::new(brand, vendor, cpus, caches)
+    }
+
+    pub fn get_topology(&self) -> &TopologyItem {
+        &self.topology
+    }
+
+    // counted from the topology where available, sysinfo falls back to the logical cpus on some systems
+    pub fn physical_core_count(&self) -> usize {
+        match self.topology.physical_core_count() {
+            0 => System::physical_core_count().unwrap_or(self.system.cpus().len()),
+            count => count,
+        }
+    }
+
+    // the governor and limits change at runtime, e.g. on thermal throttling or a power profile switch
+    fn refresh_cpu_scaling(&mut self) {
+        self.cpu_scaling = self.system.cpus()
+            .iter()
+            .filter_map(|cpu| {
+                let freq = read_cpufreq(cpu.name())?;
+
+                Some((cpu.name().to_string(), CpuScaling {
+                    min: freq.min_khz / 1000,
+                    max: freq.max_khz / 1000,
+                    governor: freq.governor,
+                }))
+            })
+            .collect();
+    }
+
+    // scaling of all cpus for the global item: the widest range, and the governor if they share one
+    fn global_cpu_scaling(&self) -> Option<CpuScaling> {
+        let mut scalings = self.cpu_scaling.values();
+        let first = scalings.next()?.clone();
+
+        Some(scalings.fold(first, |global, scaling| CpuScaling {
+            min: global.min.min(scaling.min),
+            max: global.max.max(scaling.max),
+            governor: if global.governor == scaling.governor { global.governor } else { String::from("mixed") },
+        }))
+    }
+
+    // network deltas are measured over the actual time between refreshes, which drifts from the refresh rate
+    fn refresh_networks(&mut self) {
+        let now = Instant::now();
         self.networks.refresh(true);
+        self.networks_interval_s = now.duration_since(self.networks_time).as_secs_f64();
+        self.networks_time = now;
+    }
+
+    fn refresh_net_dev(&mut self) {
+        let net_dev = read_net_dev()
+            .into_iter()
+            .map(|stat| (stat.interface.clone(), stat))
+            .collect();
+        self.net_dev_prev = std::mem::replace(&mut self.net_dev, net_dev);
+    }
+
+    // (tx, rx) packets dropped by the interface since the previous refresh
+    fn net_drops(&self, interface: &str) -> (u64, u64) {
+        match (self.net_dev.get(interface), self.net_dev_prev.get(interface)) {
+            (Some(stat), Some(prev)) => (stat.tx_drops.saturating_sub(prev.tx_drops), stat.rx_drops.saturating_sub(prev.rx_drops)),
+            _ => (0, 0),
+        }
+    }
+
+    // samples the i/o counters of the whole block devices, partitions are left out as their i/o is
+    // included in the device's. loop and ram devices are left out unless they have seen i/o
+    fn refresh_disk_io(&mut self) {
+        let now = Instant::now();
+        let elapsed_s = self.disk_stats_time.map_or(0_f64, |time| now.duration_since(time).as_secs_f64());
+        let block_devices = read_block_devices();
+
+        let stats: Vec<DiskStat> = read_diskstats()
+            .into_iter()
+            .filter(|stat| block_devices.contains(&stat.device))
+            .filter(|stat| {
+                !(stat.device.starts_with("loop") || stat.device.starts_with("ram")) ||
+                stat.reads_completed + stat.writes_completed > 0
+            })
+            .collect();
+
+        self.disk_io = stats
+            .iter()
+            .map(|stat| {
+                let item = DiskIoItem::new(stat.device.clone(), stat.in_flight);
+
+                match self.disk_stats.get(&stat.device).and_then(|prev| stat.rates_since(prev, elapsed_s)) {
+                    Some(rates) => item
+                        .with_throughput(rates.read_bytes_per_s, rates.written_bytes_per_s)
+                        .with_iops(rates.read_iops, rates.write_iops)
+                        .with_utilization(rates.utilization),
+                    None => item,
+                }
+            })
+            .collect();
+
+        self.disk_stats = stats
+            .into_iter()
+            .map(|stat| (stat.device.clone(), stat))
+            .collect();
+        self.disk_stats_time = Some(now);
+    }
+
+    // samples the cgroup of every systemd unit, cpu and io are rates since the previous sample.
+    // cgroups with a cpu or memory limit, or that ran out of memory, are also served as limits
+    fn refresh_units(&mut self) {
+        let Some(root) = cgroup_root()
+        else {
+            return;
+        };
+
+        let now = Instant::now();
+        let elapsed_s = self.unit_stats_time.map_or(0_f64, |time| now.duration_since(time).as_secs_f64());
+        let logical_cpus = self.system.cpus().len();
+        let physical_cores = self.physical_core_count();
+        let stats = read_unit_cgroups(&root);
+
+        self.units.clear();
+        self.limits.clear();
+
+        for stat in &stats {
+            let rates = self.unit_stats
+                .get(&stat.path)
+                .map(|prev| stat.rates_since(prev, elapsed_s))
+                .unwrap_or_default();
+            let name = stat.path.rsplit('/').next().unwrap_or_default().to_string();
+
+            self.units.push(UnitItem::new(
+                name,
+                stat.path.clone(),
+                rates.cpu_usage,
+                stat.memory_current,
+                stat.pids_current,
+                rates.read_bytes_per_s,
+                rates.written_bytes_per_s,
+            )
+            .with_cpu_counts(logical_cpus, physical_cores)
+            .with_pressure(Self::pressure_item(stat.cpu_pressure, stat.memory_pressure, stat.io_pressure).worst_some_avg10()));
+
+            // quota in percent of a single cpu, compared against the un-normalized usage
+            let cpu_quota = stat.cpu_max.map(|(quota, period)| quota as f32 / period.max(1) as f32 * 100_f32);
+            let limit = LimitItem::new(cgroup_name(&stat.path), stat.path.clone())
+                .with_cpu(rates.cpu_usage, cpu_quota, rates.throttled_percent)
+                .with_throttled_time(rates.throttled_ms_per_s)
+                .with_memory(stat.memory_current, stat.memory_max, stat.memory_high)
+                .with_ooms(stat.oom.unwrap_or(0), rates.ooms.unwrap_or(0))
+                .with_oom_kills(stat.oom_kill.unwrap_or(0), rates.oom_kills.unwrap_or(0));
+
+            if limit.is_limited() || limit.ooms() > 0 || limit.oom_kills() > 0 {
+                self.limits.push(limit);
+            }
+        }
+
+        self.unit_stats = stats
+            .into_iter()
+            .map(|stat| (stat.path.clone(), stat))
+            .collect();
+        self.unit_stats_time = Some(now);
+    }
+
+    // finding the owner of a socket means reading the fds of every process, so the owners are only
+    // looked up again when sockets were opened since the previous refresh. closed sockets keep their
+    // entry until then, they are not listed anyway
+    fn refresh_sockets(&mut self) {
+        let sockets = read_sockets();
+        let known: HashSet<u64> = self.sockets.iter().map(|socket| socket.inode()).collect();
+
+        if sockets.iter().any(|socket| !known.contains(&socket.inode())) {
+            self.socket_owners = read_socket_owners();
+        }
+        self.sockets = sockets;
     }
 
     pub fn get_cpus(&self) -> Vec<CpuItem> {
         let mut cpus: Vec<CpuItem> = Vec::new();
 
+        // the global frequency is the average over the cpus
+        let frequency = self.system.cpus().iter().map(|cpu| cpu.frequency()).sum::<u64>() / self.system.cpus().len().max(1) as u64;
+
         cpus.push(CpuItem::new(                // dummy item for global cpu usage
             0,
             self.system.global_cpu_usage(),
-            0,
-        ));
+            frequency,
+        )
+            .with_breakdown(self.cpu_breakdowns.get("cpu").copied())
+            .with_scaling(self.global_cpu_scaling()));
 
         for (id, cpu) in self.system.cpus().iter().enumerate() {
             let cpu_item = CpuItem::new(
                 id + 1,                         // id=0 reserved for global cpu usage                   
                 cpu.cpu_usage(),
                 cpu.frequency(),
-            );
+            )
+                .with_breakdown(self.cpu_breakdowns.get(cpu.name()).copied())
+                .with_scaling(self.cpu_scaling.get(cpu.name()).cloned());
 
             cpus.push(cpu_item);
         }
@@ -69,15 +453,93 @@ impl ItemProvider<NetworkItem> for SysInfoService {
         let mut rx = 0;
         let mut total_tx = 0;
         let mut total_rx = 0;
+        let (mut packets_tx, mut packets_rx) = (0, 0);
+        let (mut errors_tx, mut errors_rx) = (0, 0);
+        let (mut drops_tx, mut drops_rx) = (0, 0);
 
-        for (_interface_name, network) in &self.networks {
+        for (interface_name, network) in &self.networks {
             tx += network.transmitted();
             rx += network.received();
             total_tx += network.total_transmitted();
             total_rx += network.total_received();
+            packets_tx += network.packets_transmitted();
+            packets_rx += network.packets_received();
+            errors_tx += network.errors_on_transmitted();
+            errors_rx += network.errors_on_received();
+
+            let (interface_drops_tx, interface_drops_rx) = self.net_drops(interface_name);
+            drops_tx += interface_drops_tx;
+            drops_rx += interface_drops_rx;
         }
 
-        NetworkItem::new(tx, rx, total_tx, total_rx)
+        NetworkItem::new(String::from("All"), tx, rx, total_tx, total_rx)
+            .with_packets(packets_tx, packets_rx)
+            .with_errors(errors_tx, errors_rx)
+            .with_drops(drops_tx, drops_rx)
+            .with_interval(self.networks_interval_s)
+    }
+}
+
+impl VecProvider<NetworkItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<NetworkItem> {
+        let mut networks: Vec<NetworkItem> = self.networks
+            .iter()
+            .map(|(interface_name, network)| {
+                let (drops_tx, drops_rx) = self.net_drops(interface_name);
+                let item = NetworkItem::new(
+                    interface_name.clone(),
+                    network.transmitted(),
+                    network.received(),
+                    network.total_transmitted(),
+                    network.total_received(),
+                )
+                .with_packets(network.packets_transmitted(), network.packets_received())
+                .with_errors(network.errors_on_transmitted(), network.errors_on_received())
+                .with_drops(drops_tx, drops_rx)
+                .with_interval(self.networks_interval_s)
+                .with_ip_addresses(network.ip_networks().iter().map(|ip_network| ip_network.to_string()).collect())
+                .with_mtu(network.mtu());
+
+                // loopback and tunnel interfaces have no hardware address
+                if network.mac_address().is_unspecified() {
+                    item
+                }
+                else {
+                    item.with_mac(network.mac_address().to_string())
+                }
+            })
+            .collect();
+
+        networks.sort_by(|a, b| a.interface().cmp(b.interface()));
+        networks
+    }
+}
+
+impl VecProvider<DiskItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<DiskItem> {
+        self.disks
+            .iter()
+            .map(|disk| {
+                let item = DiskItem::new(
+                    disk.mount_point().to_string_lossy().to_string(),
+                    disk.name().to_string_lossy().to_string(),
+                    disk.file_system().to_string_lossy().to_string(),
+                    disk.total_space(),
+                    disk.available_space(),
+                );
+
+                match read_inodes(disk.mount_point()) {
+                    Some((inodes_total, inodes_free)) => item.with_inodes(inodes_total, inodes_free),
+                    None => item,
+                }
+            })
+            .collect()
+    }
+}
+
+impl VecProvider<DiskIoItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<DiskIoItem> {
+        self.disk_io.clone()
     }
 }
 
@@ -88,51 +550,194 @@ impl ItemProvider<MemoryItem> for SysInfoService {
         let total_swap = self.system.total_swap();
         let used_swap = self.system.used_swap();
 
+        let breakdown = read_meminfo().map(|meminfo| MemoryBreakdown {
+            buffers: meminfo.buffers,
+            cached: meminfo.cached,
+            shared: meminfo.shared,
+            sreclaimable: meminfo.sreclaimable,
+            dirty: meminfo.dirty,
+            writeback: meminfo.writeback,
+        });
+
         MemoryItem::new(total_memory, used_memory, total_swap, used_swap)
+            .with_available(self.system.available_memory(), self.system.free_memory())
+            .with_breakdown(breakdown)
+            .with_activity(self.memory_activity)
     }
 }
 
-impl VecProvider<TempItem> for SysInfoService {
-    fn fetch_items(&self) -> Vec<TempItem> {
-        let mut temps: Vec<TempItem> = Vec::new();
+impl ItemProvider<PressureItem> for SysInfoService {
+    fn fetch_item(&self) -> PressureItem {
+        Self::pressure_item(read_pressure("cpu"), read_pressure("memory"), read_pressure("io"))
+    }
+}
 
-        for component in &self.components {
-            let temp = if let Some(temp) = component.temperature() {
-                temp
+// user threads are listed as processes by sysinfo, they are counted towards threads only
+impl ItemProvider<SummaryItem> for SysInfoService {
+    fn fetch_item(&self) -> SummaryItem {
+        let load_average = System::load_average();
+        let mut tasks = TaskCounts {
+            threads: self.system.processes().len(),
+            ..Default::default()
+        };
+
+        for process in self.system.processes().values() {
+            if process.thread_kind() == Some(ThreadKind::Userland) {
+                continue;
             }
-            else {
-                0_f32
-            };
 
-            let max_temp = if let Some(max_temp) = component.max() {
-                max_temp
+            tasks.processes += 1;
+            match process.status() {
+                ProcessStatus::Run => tasks.running += 1,
+                ProcessStatus::Sleep | ProcessStatus::Idle | ProcessStatus::UninterruptibleDiskSleep => tasks.sleeping += 1,
+                ProcessStatus::Zombie => tasks.zombie += 1,
+                _ => {}
             }
-            else {
-                0_f32
-            };
+        }
 
-            let critical_temp = if let Some(critical_temp) = component.critical() {
-                critical_temp
-            }
-            else {
-                0_f32
-            };
+        SummaryItem::new(
+            System::host_name().unwrap_or_default(),
+            System::kernel_version().unwrap_or_default(),
+            System::long_os_version().unwrap_or_default(),
+            System::uptime(),
+            self.system.cpus().len(),
+        )
+            .with_load_average(LoadAverage {
+                one: load_average.one,
+                five: load_average.five,
+                fifteen: load_average.fifteen,
+            })
+            .with_tasks(tasks)
+            .with_rates(self.context_switches_per_s, self.interrupts_per_s)
+    }
+}
+
+impl VecProvider<TempItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<TempItem> {
+        self.components
+            .iter()
+            .map(|component| TempItem::new(
+                component.temperature(),
+                component.max(),
+                component.critical(),
+                component.label().to_string(),
+            ))
+            .collect()
+    }
+}
+
+impl VecProvider<UnitItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<UnitItem> {
+        self.units.clone()
+    }
+}
 
-            let label = component.label().to_string();
+impl VecProvider<LimitItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<LimitItem> {
+        self.limits.clone()
+    }
+}
 
+impl VecProvider<ConnectionItem> for SysInfoService {
+    fn fetch_items(&self) -> Vec<ConnectionItem> {
+        self.sockets
+            .iter()
+            .cloned()
+            .map(|socket| {
+                let pid = self.socket_owners.get(&socket.inode()).copied();
+                let name = pid
+                    .and_then(|pid| self.system.process(Pid::from_u32(pid)))
+                    .map(|process| process.name().to_string_lossy().to_string());
+
+                ConnectionItem::new(socket, pid, name)
+            })
+            .collect()
+    }
+}
 
-            let item = TempItem::new(temp, max_temp, critical_temp, label);
+impl PidVecProvider<FileItem> for SysInfoService {
+    fn fetch_pid_items(&self, pid: u32) -> std::io::Result<Vec<FileItem>> {
+        let fds = read_pid_fds(pid)?;
 
-            temps.push(item);
+        // resolving socket inodes, /proc/net is only read if the process has sockets open
+        let sockets: HashMap<u64, SocketItem> = if fds.iter().any(|fd| matches!(fd.target, Some(FdTarget::Socket(_)))) {
+            read_sockets()
+                .into_iter()
+                .map(|socket| (socket.inode(), socket))
+                .collect()
         }
+        else {
+            HashMap::new()
+        };
+
+        let files = fds
+            .into_iter()
+            .map(|fd| match fd.target {
+                Some(FdTarget::File(path)) => FileItem::new(fd.fd, FileKind::File, path),
+                Some(FdTarget::Device(path)) => FileItem::new(fd.fd, FileKind::Device, path),
+                Some(FdTarget::Pipe(inode)) => FileItem::new(fd.fd, FileKind::Pipe, format!("pipe:[{}]", inode)),
+                Some(FdTarget::AnonInode(name)) => FileItem::new(fd.fd, FileKind::AnonInode, name),
+                Some(FdTarget::Socket(inode)) => {
+                    // sockets not found in /proc/net belong to another network namespace or protocol, e.g. netlink
+                    let item = FileItem::new(fd.fd, FileKind::Socket, format!("socket:[{}]", inode));
+                    match sockets.get(&inode) {
+                        Some(socket) => item.with_socket(socket.clone()),
+                        None => item,
+                    }
+                }
+                // the link could not be read, e.g. the fd was closed while listing
+                None => FileItem::new(fd.fd, FileKind::Unknown, String::from("unreadable")),
+            })
+            .collect();
+
+        Ok(files)
+    }
+}
+
+// helper function for reading the inet and unix sockets of the host
+fn read_sockets() -> Vec<SocketItem> {
+    read_net_sockets()
+        .iter()
+        .map(socket_item)
+        .chain(read_unix_sockets().iter().map(unix_socket_item))
+        .collect()
+}
 
-        temps
+// helper function for converting a /proc/net socket, unspecified remote addresses are printed like `ss`
+fn socket_item(socket: &NetSocket) -> SocketItem {
+    let remote = if socket.remote.ip().is_unspecified() && socket.remote.port() == 0 {
+        String::from("*:*")
     }
+    else {
+        socket.remote.to_string()
+    };
+
+    SocketItem::new(
+        socket.protocol.name().to_string(),
+        socket.local.to_string(),
+        remote,
+        socket.state_name().to_string(),
+        socket.inode,
+    )
+}
+
+// helper function for converting a /proc/net/unix socket, unnamed sockets and the peer are printed like `ss`
+fn unix_socket_item(socket: &UnixSocket) -> SocketItem {
+    SocketItem::new(
+        socket.netid().to_string(),
+        socket.path.clone().unwrap_or_else(|| String::from("*")),
+        String::from("*"),
+        socket.state_name().to_string(),
+        socket.inode,
+    )
 }
 
 impl VecProvider<ProcessItem> for SysInfoService {
     fn fetch_items(&self) -> Vec<ProcessItem> {
         let mut processes: Vec<ProcessItem> = Vec::new();
+        let total_memory = self.system.total_memory();
+        let logical_cpus = self.system.cpus().len();
+        let physical_cores = self.physical_core_count();
 
         for (pid, process) in self.system.processes() {
             let name = if let Some(name) = process.name().to_str() {
@@ -141,12 +746,8 @@ impl VecProvider<ProcessItem> for SysInfoService {
             else {
                 String::from("No name")
             };
-            let cpu_usage = if let Some(core_count) = sysinfo::System::physical_core_count() {
-                process.cpu_usage() / core_count as f32 // normalizing process cpu usage by the number of cores
-            }
-            else {
-                process.cpu_usage()
-            };
+            // percent of a single cpu, scaled to the configured cpu mode by the process list
+            let cpu_usage = process.cpu_usage();
 
             let memory_usage = process.memory();
 
@@ -170,6 +771,56 @@ impl VecProvider<ProcessItem> for SysInfoService {
                 String::from("Permission Denied")
             };
 
+            // resolving user id to a username through the users list
+            let user = if let Some(user_id) = process.user_id() {
+                if let Some(user) = self.users.get_user_by_id(user_id) {
+                    user.name().to_string()
+                }
+                else {
+                    String::from("Unknown")
+                }
+            }
+            else {
+                String::from("Unknown")
+            };
+
+            let cmd = process.cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let memory_percent = if total_memory > 0 {
+                (memory_usage as f64 / total_memory as f64 * 100_f64) as f32
+            }
+            else {
+                0_f32
+            };
+
+            // disk usage is reported in bytes since the last refresh
+            let disk_usage = process.disk_usage();
+            let read_bytes_per_s = per_second(disk_usage.read_bytes, self.system_interval_s) as u64;
+            let written_bytes_per_s = per_second(disk_usage.written_bytes, self.system_interval_s) as u64;
+
+            // nice, thread count and page faults are not exposed by sysinfo, read them from procfs when available
+            let pid_stat = read_pid_stat(pid.as_u32());
+            let ppid = if let Some(parent) = process.parent() {
+                parent.as_u32()
+            }
+            else {
+                pid_stat.as_ref().map_or(0, |stat| stat.ppid)
+            };
+            let threads = if let Some(stat) = &pid_stat {
+                stat.num_threads
+            }
+            else {
+                process.tasks().map_or(0, |tasks| tasks.len() as u32)
+            };
+            let nice = pid_stat.as_ref().map_or(0, |stat| stat.nice);
+            let (minor_faults, major_faults) = pid_stat.as_ref().map_or((0, 0), |stat| (stat.minflt, stat.majflt));
+            let cgroup = read_pid_cgroup(pid.as_u32()).unwrap_or_default();
+            let cgroup_short = if cgroup.is_empty() { String::new() } else { cgroup_name(&cgroup) };
+
             let item = ProcessItem::new(
                 pid.as_u32(),
                 name,
@@ -180,11 +831,40 @@ impl VecProvider<ProcessItem> for SysInfoService {
                 accumulated_cpu_time,
                 status,
                 path,
-            );
+            )
+            .with_user(user)
+            .with_ppid(ppid)
+            .with_cmd(cmd)
+            .with_memory_percent(memory_percent)
+            .with_virtual_memory(process.virtual_memory())
+            .with_disk_usage(
+                read_bytes_per_s,
+                written_bytes_per_s,
+                disk_usage.total_read_bytes,
+                disk_usage.total_written_bytes,
+            )
+            .with_threads(threads)
+            .with_nice(nice)
+            .with_faults(minor_faults, major_faults)
+            .with_cgroup(cgroup, cgroup_short)
+            .with_cpu_counts(logical_cpus, physical_cores);
 
             processes.push(item);
         }
 
         return processes;
     }
-}
\ No newline at end of file
+}
+
+// size of a memory page in bytes, /proc/vmstat counts swapped pages
+#[cfg(unix)]
+fn page_size() -> u64 {
+    // SAFETY: sysconf only reads a system constant
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 { size as u64 } else { 4096 }
+}
+
+#[cfg(not(unix))]
+fn page_size() -> u64 {
+    4096
+}
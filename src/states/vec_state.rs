@@ -6,6 +6,7 @@ pub struct VecState<T, S> {
     selection: Option<usize>,
     sort: Option<S>,
     filter: Option<String>,  
+    scopes: Vec<String>,        // filters applied on top of `filter`, e.g., "user=alice" when only showing own processes
 }
 
 impl <T, S> VecState<T, S> {
@@ -17,6 +18,7 @@ impl <T, S> VecState<T, S> {
             selection,
             sort,
             filter,
+            scopes: Vec::new(),
         }
     }
 
@@ -38,6 +40,10 @@ impl <T, S> VecState<T, S> {
         };
     }
 
+    pub fn set_scopes(&mut self, scopes: Vec<String>) {
+        self.scopes = scopes;
+    }
+
     // ACCESS TO MODEL MUTATORS
     pub fn push(&mut self, item: T) {
         self.model.push(item);
@@ -79,6 +85,10 @@ impl <T, S> VecState<T, S> {
     pub fn filter(&self) -> Option<&str> {
         self.filter.as_deref()
     }
+
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
 }
 
 impl <T, S> VecState<T, S>
@@ -93,6 +103,10 @@ where
             indices.retain(|&i| self.model.items()[i].matches_filter(filter));
         }
 
+        for scope in &self.scopes {
+            indices.retain(|&i| self.model.items()[i].matches_filter(scope));
+        }
+
         if let Some(sort) = &self.sort {
             indices.sort_by(|&i, &j| self.model.items()[i].cmp_with(&self.model.items()[j], sort));
         }